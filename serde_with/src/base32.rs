@@ -0,0 +1,297 @@
+//! De/Serialization of base32 encoded bytes
+//!
+//! This modules is only available when using the `base32` feature of the crate.
+//!
+//! Please check the documentation on the [`Base32`] type for details.
+
+use crate::prelude::*;
+use ::data_encoding::{Encoding, Specification};
+
+/// Serialize bytes with base32
+///
+/// The type serializes a sequence of bytes as a base32 string.
+/// It works on any type implementing `AsRef<[u8]>` for serialization and `TryFrom<Vec<u8>>` for deserialization.
+///
+/// The type allows customizing the character set and the padding behavior.
+/// The `ALPHABET` is a type implementing [`Alphabet`].
+/// `PADDING` specifies if serializing should emit padding.
+/// Deserialization always supports padded and unpadded formats.
+/// [`formats::Padded`] emits padding and [`formats::Unpadded`] leaves it off.
+/// The [`Crockford`] and [`ZBase32`] alphabets do not define padding and never emit it.
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::serde_as;
+/// use serde_with::base32::{Base32, Crockford, ExtendedHex, Standard, ZBase32};
+/// use serde_with::formats::Unpadded;
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct B32 {
+///     // The default is the same as Standard character set with padding
+///     #[serde_as(as = "Base32")]
+///     default: Vec<u8>,
+///     // Only change the character set, implies padding
+///     #[serde_as(as = "Base32<ExtendedHex>")]
+///     charset_hex: Vec<u8>,
+///
+///     #[serde_as(as = "Base32<Standard, Unpadded>")]
+///     no_padding: Vec<u8>,
+///     #[serde_as(as = "Base32<Crockford>")]
+///     crockford: Vec<u8>,
+///     #[serde_as(as = "Base32<ZBase32>")]
+///     zbase32: Vec<u8>,
+/// }
+///
+/// let b32 = B32 {
+///     default: b"Hello World".to_vec(),
+///     charset_hex: b"Hello World".to_vec(),
+///     no_padding: b"Hello World".to_vec(),
+///     crockford: b"Hello World".to_vec(),
+///     zbase32: b"Hello World".to_vec(),
+/// };
+/// let json = serde_json::json!({
+///     "default": "JBSWY3DPEBLW64TMMQ======",
+///     "charset_hex": "91IMOR3F41BMUSJCCG======",
+///     "no_padding": "JBSWY3DPEBLW64TMMQ",
+///     "crockford": "91JPRV3F41BPYWKCCG",
+///     "zbase32": "jb1sa5dxrbms6hucco",
+/// });
+///
+/// // Test serialization and deserialization
+/// assert_eq!(json, serde_json::to_value(&b32).unwrap());
+/// assert_eq!(b32, serde_json::from_value(json).unwrap());
+///
+/// // Crockford is case-insensitive and maps ambiguous characters during deserialization
+/// let json = serde_json::json!({
+///     "default": "JBSWY3DPEBLW64TMMQ",
+///     "charset_hex": "91IMOR3F41BMUSJCCG",
+///     "no_padding": "JBSWY3DPEBLW64TMMQ======",
+///     "crockford": "9ljprv3f4IbpywkccG",
+///     "zbase32": "jb1sa5dxrbms6hucco",
+/// });
+/// assert_eq!(b32, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+// The padding might be better as `const PADDING: bool = true`
+// https://blog.rust-lang.org/inside-rust/2021/09/06/Splitting-const-generics.html#featureconst_generics_default/
+pub struct Base32<ALPHABET: Alphabet = Standard, PADDING: formats::Format = formats::Padded>(
+    PhantomData<(ALPHABET, PADDING)>,
+);
+
+/// Return the [`Encoding`] for an alphabet
+///
+/// With the `std` feature the encoding is built only once and cached afterwards.
+fn encoding<ALPHABET: Alphabet>(padding: bool) -> Cow<'static, Encoding> {
+    #[cfg(feature = "std")]
+    {
+        Cow::Borrowed(
+            ALPHABET::cache()[usize::from(padding)]
+                .get_or_init(|| build_encoding::<ALPHABET>(padding)),
+        )
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        Cow::Owned(build_encoding::<ALPHABET>(padding))
+    }
+}
+
+/// Build the [`Encoding`] for an alphabet
+fn build_encoding<ALPHABET: Alphabet>(padding: bool) -> Encoding {
+    let mut spec = ALPHABET::specification();
+    if padding && ALPHABET::SUPPORTS_PADDING {
+        spec.padding = Some('=');
+    }
+    spec.encoding()
+        .expect("base32 alphabets always have a valid specification")
+}
+
+impl<T, ALPHABET> SerializeAs<T> for Base32<ALPHABET, formats::Padded>
+where
+    T: AsRef<[u8]>,
+    ALPHABET: Alphabet,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        encoding::<ALPHABET>(true)
+            .encode(source.as_ref())
+            .serialize(serializer)
+    }
+}
+
+impl<T, ALPHABET> SerializeAs<T> for Base32<ALPHABET, formats::Unpadded>
+where
+    T: AsRef<[u8]>,
+    ALPHABET: Alphabet,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        encoding::<ALPHABET>(false)
+            .encode(source.as_ref())
+            .serialize(serializer)
+    }
+}
+
+impl<'de, T, ALPHABET, FORMAT> DeserializeAs<'de, T> for Base32<ALPHABET, FORMAT>
+where
+    T: TryFrom<Vec<u8>>,
+    ALPHABET: Alphabet,
+    FORMAT: formats::Format,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<T, ALPHABET>(PhantomData<(T, ALPHABET)>);
+
+        impl<T, ALPHABET> Visitor<'_> for Helper<T, ALPHABET>
+        where
+            T: TryFrom<Vec<u8>>,
+            ALPHABET: Alphabet,
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a base32 encoded string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                // Our decoders uniformly do not care about padding.
+                let value = if ALPHABET::SUPPORTS_PADDING {
+                    value.trim_end_matches('=')
+                } else {
+                    value
+                };
+                let bytes = encoding::<ALPHABET>(false)
+                    .decode(value.as_bytes())
+                    .map_err(DeError::custom)?;
+
+                let length = bytes.len();
+                bytes.try_into().map_err(|_e: T::Error| {
+                    DeError::custom(format_args!(
+                        "Can't convert a Byte Vector of length {length} to the output type."
+                    ))
+                })
+            }
+        }
+
+        deserializer.deserialize_str(Helper::<T, ALPHABET>(PhantomData))
+    }
+}
+
+mod sealed {
+    #[cfg(feature = "std")]
+    use ::data_encoding::Encoding;
+    #[cfg(feature = "std")]
+    use std::sync::OnceLock;
+
+    pub trait Sealed {
+        /// The cached encodings without and with padding
+        #[cfg(feature = "std")]
+        fn cache() -> &'static [OnceLock<Encoding>; 2];
+    }
+
+    macro_rules! sealed {
+        ($($alphabet:ident),*) => {$(
+            impl Sealed for super::$alphabet {
+                #[cfg(feature = "std")]
+                fn cache() -> &'static [OnceLock<Encoding>; 2] {
+                    static CACHE: [OnceLock<Encoding>; 2] = [OnceLock::new(), OnceLock::new()];
+                    &CACHE
+                }
+            }
+        )*};
+    }
+    sealed!(Standard, ExtendedHex, Crockford, ZBase32);
+}
+
+/// A base32 alphabet
+pub trait Alphabet: sealed::Sealed {
+    /// Whether the alphabet defines padding with `=`.
+    const SUPPORTS_PADDING: bool;
+
+    /// Return the specification of the alphabet, without padding.
+    fn specification() -> Specification;
+}
+
+/// The standard character set (uses `A-Z` and `2-7`).
+///
+/// Deserialization also accepts lowercase characters.
+///
+/// See [RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648#section-6).
+pub struct Standard;
+impl Alphabet for Standard {
+    const SUPPORTS_PADDING: bool = true;
+
+    fn specification() -> Specification {
+        let mut spec = Specification::new();
+        spec.symbols.push_str("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+        spec.translate.from.push_str("abcdefghijklmnopqrstuvwxyz");
+        spec.translate.to.push_str("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        spec
+    }
+}
+
+/// The "Extended Hex" character set (uses `0-9` and `A-V`).
+///
+/// The encoding preserves the sort order of the encoded data.
+/// Deserialization also accepts lowercase characters.
+///
+/// See [RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648#section-7).
+pub struct ExtendedHex;
+impl Alphabet for ExtendedHex {
+    const SUPPORTS_PADDING: bool = true;
+
+    fn specification() -> Specification {
+        let mut spec = Specification::new();
+        spec.symbols.push_str("0123456789ABCDEFGHIJKLMNOPQRSTUV");
+        spec.translate.from.push_str("abcdefghijklmnopqrstuv");
+        spec.translate.to.push_str("ABCDEFGHIJKLMNOPQRSTUV");
+        spec
+    }
+}
+
+/// Douglas Crockford's character set (uses `0-9` and `A-Z` without `I`, `L`, `O`, and `U`).
+///
+/// The alphabet does not use padding.
+/// Deserialization is case-insensitive, maps the ambiguous characters `I` and `L` to `1` and `O` to `0`, and ignores hyphens.
+///
+/// See [Crockford's Base32](https://www.crockford.com/base32.html).
+pub struct Crockford;
+impl Alphabet for Crockford {
+    const SUPPORTS_PADDING: bool = false;
+
+    fn specification() -> Specification {
+        let mut spec = Specification::new();
+        spec.symbols.push_str("0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+        spec.translate.from.push_str("abcdefghjkmnpqrstvwxyzIiLlOo");
+        spec.translate.to.push_str("ABCDEFGHJKMNPQRSTVWXYZ111100");
+        spec.ignore.push('-');
+        spec
+    }
+}
+
+/// The human-oriented z-base-32 character set (uses `ybndrfg8ejkmcpqxot1uwisza345h769`).
+///
+/// The alphabet does not use padding.
+///
+/// See [z-base-32](https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt).
+pub struct ZBase32;
+impl Alphabet for ZBase32 {
+    const SUPPORTS_PADDING: bool = false;
+
+    fn specification() -> Specification {
+        let mut spec = Specification::new();
+        spec.symbols.push_str("ybndrfg8ejkmcpqxot1uwisza345h769");
+        spec
+    }
+}
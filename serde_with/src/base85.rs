@@ -0,0 +1,265 @@
+//! De/Serialization of base85 encoded bytes
+//!
+//! This modules is only available when using the `base85` feature of the crate.
+//!
+//! Please check the documentation on the [`Base85`] type for details.
+
+use crate::prelude::*;
+
+/// Serialize bytes with base85
+///
+/// The type serializes a sequence of bytes as a base85 string.
+/// It works on any type implementing `AsRef<[u8]>` for serialization and `TryFrom<Vec<u8>>` for deserialization.
+/// This includes `Vec<u8>`, `[u8; N]`, `Box<[u8]>`, and `Cow<'_, [u8]>`.
+///
+/// The `ALPHABET` is a type implementing [`Alphabet`] and selects the base85 variant.
+/// The variants differ not only in the character set, but also in how they treat data which is not a multiple of four bytes long.
+///
+/// * [`Z85`] is the variant used by `ZeroMQ`.
+///   It only supports data with a length that is a multiple of four bytes and fails otherwise.
+/// * [`Ascii85`] is the variant used by `btoa` and in PDF and PostScript files.
+///   A group of four zero bytes is abbreviated as `z` and a shorter final group is supported.
+///   Deserialization ignores whitespace and the optional `<~` and `~>` delimiters.
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::serde_as;
+/// use serde_with::base85::{Ascii85, Base85, Z85};
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct B85 {
+///     #[serde_as(as = "Base85<Z85>")]
+///     curve_key: [u8; 8],
+///     #[serde_as(as = "Base85<Ascii85>")]
+///     stream: Vec<u8>,
+/// }
+///
+/// let b85 = B85 {
+///     curve_key: [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B],
+///     stream: b"\0\0\0\0Hello".to_vec(),
+/// };
+/// let json = serde_json::json!({
+///     "curve_key": "HelloWorld",
+///     "stream": "z87cURDZ",
+/// });
+///
+/// // Test serialization and deserialization
+/// assert_eq!(json, serde_json::to_value(&b85).unwrap());
+/// assert_eq!(b85, serde_json::from_value(json).unwrap());
+///
+/// // Ascii85 accepts the delimiters and whitespace
+/// let json = serde_json::json!({
+///     "curve_key": "HelloWorld",
+///     "stream": "<~z 87cU\nRDZ~>",
+/// });
+/// assert_eq!(b85, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct Base85<ALPHABET: Alphabet>(PhantomData<ALPHABET>);
+
+impl<T, ALPHABET> SerializeAs<T> for Base85<ALPHABET>
+where
+    T: AsRef<[u8]>,
+    ALPHABET: Alphabet,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        encode::<ALPHABET>(source.as_ref())
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de, T, ALPHABET> DeserializeAs<'de, T> for Base85<ALPHABET>
+where
+    T: TryFrom<Vec<u8>>,
+    ALPHABET: Alphabet,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<T, ALPHABET>(PhantomData<(T, ALPHABET)>);
+
+        impl<T, ALPHABET> Visitor<'_> for Helper<T, ALPHABET>
+        where
+            T: TryFrom<Vec<u8>>,
+            ALPHABET: Alphabet,
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a base85 encoded string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                let bytes = decode::<ALPHABET, E>(value)?;
+
+                let length = bytes.len();
+                bytes.try_into().map_err(|_e: T::Error| {
+                    DeError::custom(format_args!(
+                        "Can't convert a Byte Vector of length {length} to the output type."
+                    ))
+                })
+            }
+        }
+
+        deserializer.deserialize_str(Helper::<T, ALPHABET>(PhantomData))
+    }
+}
+
+fn encode<ALPHABET: Alphabet>(bytes: &[u8]) -> Result<String, &'static str> {
+    if !ALPHABET::PARTIAL_GROUPS && !bytes.len().is_multiple_of(4) {
+        return Err("Z85 can only encode data with a length that is a multiple of 4");
+    }
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(4) * 5);
+    for chunk in bytes.chunks(4) {
+        let mut group = [0; 4];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(group);
+        if ALPHABET::ZERO_SHORTCUT && value == 0 && chunk.len() == 4 {
+            encoded.push('z');
+            continue;
+        }
+
+        let mut digits = [0; 5];
+        for digit in digits.iter_mut().rev() {
+            // The remainder is always smaller than 85
+            *digit = ALPHABET::CHARSET[(value % 85) as usize];
+            value /= 85;
+        }
+        // A final group of n bytes is encoded with n + 1 characters
+        encoded.extend(digits[..=chunk.len()].iter().map(|&c| char::from(c)));
+    }
+    Ok(encoded)
+}
+
+fn decode<ALPHABET: Alphabet, E: DeError>(value: &str) -> Result<Vec<u8>, E> {
+    let mut value = value;
+    if ALPHABET::DELIMITERS {
+        value = value.strip_prefix("<~").unwrap_or(value);
+        value = value.strip_suffix("~>").unwrap_or(value);
+    }
+
+    let mut bytes = Vec::new();
+    let mut group = [0u8; 5];
+    let mut group_len = 0;
+    for (idx, c) in value.char_indices() {
+        if ALPHABET::IGNORE_WHITESPACE && c.is_ascii_whitespace() {
+            continue;
+        }
+        if ALPHABET::ZERO_SHORTCUT && c == 'z' {
+            if group_len != 0 {
+                return Err(DeError::custom(format_args!(
+                    "Invalid base85 character 'z' at position {idx}, it is only allowed between groups"
+                )));
+            }
+            bytes.extend_from_slice(&[0; 4]);
+            continue;
+        }
+        let digit = u8::try_from(c)
+            .ok()
+            .and_then(|c| ALPHABET::CHARSET.iter().position(|&d| d == c))
+            .ok_or_else(|| {
+                DeError::custom(format_args!(
+                    "Invalid base85 character {c:?} at position {idx}"
+                ))
+            })?;
+        // The position within the 85 characters always fits
+        group[group_len] = digit as u8;
+        group_len += 1;
+        if group_len == 5 {
+            bytes.extend_from_slice(&decode_group(group)?);
+            group_len = 0;
+        }
+    }
+
+    if group_len > 0 {
+        if !ALPHABET::PARTIAL_GROUPS {
+            return Err(DeError::custom(
+                "Z85 can only decode data with a length that is a multiple of 5",
+            ));
+        }
+        if group_len == 1 {
+            return Err(DeError::custom(
+                "Invalid base85 length, the last group consists of a single character",
+            ));
+        }
+        // Pad the final group with the highest digit and drop the extra bytes
+        group[group_len..].fill(84);
+        bytes.extend_from_slice(&decode_group(group)?[..group_len - 1]);
+    }
+    Ok(bytes)
+}
+
+fn decode_group<E: DeError>(group: [u8; 5]) -> Result<[u8; 4], E> {
+    let value = group
+        .iter()
+        .fold(0u64, |acc, &digit| acc * 85 + u64::from(digit));
+    u32::try_from(value)
+        .map(u32::to_be_bytes)
+        .map_err(|_| DeError::custom("Invalid base85 group, the value does not fit into 4 bytes"))
+}
+
+mod sealed {
+    pub trait Sealed {
+        /// The 85 characters in the order of their value
+        const CHARSET: &'static [u8; 85];
+        /// Encode four zero bytes as `z`
+        const ZERO_SHORTCUT: bool;
+        /// Support data which is not a multiple of four bytes
+        const PARTIAL_GROUPS: bool;
+        /// Strip the `<~` and `~>` delimiters while decoding
+        const DELIMITERS: bool;
+        /// Skip whitespace while decoding
+        const IGNORE_WHITESPACE: bool;
+    }
+
+    impl Sealed for super::Z85 {
+        const CHARSET: &'static [u8; 85] =
+            b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+        const ZERO_SHORTCUT: bool = false;
+        const PARTIAL_GROUPS: bool = false;
+        const DELIMITERS: bool = false;
+        const IGNORE_WHITESPACE: bool = false;
+    }
+
+    impl Sealed for super::Ascii85 {
+        const CHARSET: &'static [u8; 85] =
+            b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstu";
+        const ZERO_SHORTCUT: bool = true;
+        const PARTIAL_GROUPS: bool = true;
+        const DELIMITERS: bool = true;
+        const IGNORE_WHITESPACE: bool = true;
+    }
+}
+
+/// A base85 alphabet
+pub trait Alphabet: sealed::Sealed {}
+
+/// The Z85 character set as used by `ZeroMQ`.
+///
+/// Only data with a length that is a multiple of four bytes can be encoded.
+///
+/// See [`ZeroMQ` RFC 32](https://rfc.zeromq.org/spec/32/).
+pub struct Z85;
+impl Alphabet for Z85 {}
+
+/// The Ascii85 character set (uses `!` to `u`) as used by `btoa`, PDF, and PostScript.
+///
+/// Four zero bytes are encoded as `z`.
+/// The final group may be shorter than four bytes.
+/// Deserialization ignores whitespace and the `<~` and `~>` delimiters.
+///
+/// See [Ascii85 on Wikipedia](https://en.wikipedia.org/wiki/Ascii85).
+pub struct Ascii85;
+impl Alphabet for Ascii85 {}
@@ -0,0 +1,465 @@
+use crate::{
+    prelude::*,
+    utils::{forward_deserialize, forward_visit},
+};
+use serde_core::de::value::{BorrowedStrDeserializer, BytesDeserializer, StrDeserializer};
+
+/// Deserialize struct fields and enum variants with differently spelled names
+///
+/// Data from other systems does not always use the exact spelling of the names expected by the derived [`Deserialize`] implementation.
+/// Some send `"ACTIVE"`, `"active"`, and `"Active"` for the same enum variant.
+/// This adapter wraps the deserializer and replaces every field and variant name, which matches one of the expected names, with the expected spelling.
+/// Names expected via `#[serde(alias = "...")]` are matched too.
+/// Names without a match are passed on unchanged, such that the error messages about unknown fields and variants stay the same.
+/// Exact matches take precedence over other matches.
+///
+/// The `MATCHING` parameter controls which names match each other.
+///
+/// * [`AsciiLowercase`] (default): Ignore the ASCII case of the names.
+/// * [`AnyCaseConvention`]: Additionally, ignore the `_` and `-` word separators, such that `snake_case` and `camelCase` spellings match.
+///
+/// The `T` parameter is the adapter for the inner type, which by default is [`Same`].
+///
+/// Only the names of the outermost struct or enum are matched, which allows combining the adapter with other adapters.
+/// Nested structs and enums need their own `CaseInsensitive` annotations.
+/// The enum must use the default *externally tagged* representation.
+/// `#[serde(flatten)]` fields disable the matching, since the struct is then deserialized as a map.
+/// Serialization is unchanged and uses the serialization of `T`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::Deserialize;
+/// use serde_with::{formats::AnyCaseConvention, serde_as, CaseInsensitive};
+///
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize)]
+/// enum Status {
+///     Active,
+///     Inactive,
+/// }
+///
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize)]
+/// #[serde(rename_all = "snake_case")]
+/// struct Account {
+///     account_id: u32,
+///     status: Status,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize)]
+/// struct Data {
+///     #[serde_as(as = "Vec<CaseInsensitive>")]
+///     states: Vec<Status>,
+///     #[serde_as(as = "CaseInsensitive<_, AnyCaseConvention>")]
+///     account: Account,
+/// }
+///
+/// let json = r#"{
+///     "states": ["ACTIVE", "active", "Inactive"],
+///     "account": {"AccountId": 7, "STATUS": "Active"}
+/// }"#;
+/// assert_eq!(
+///     Data {
+///         states: vec![Status::Active, Status::Active, Status::Inactive],
+///         account: Account {
+///             account_id: 7,
+///             status: Status::Active,
+///         },
+///     },
+///     serde_json::from_str(json).unwrap(),
+/// );
+/// # }
+/// ```
+///
+/// [`AnyCaseConvention`]: crate::formats::AnyCaseConvention
+/// [`AsciiLowercase`]: crate::formats::AsciiLowercase
+pub struct CaseInsensitive<
+    T = Same,
+    MATCHING: formats::IdentifierMatching = formats::AsciiLowercase,
+>(PhantomData<(T, MATCHING)>);
+
+impl<T, U, MATCHING> SerializeAs<T> for CaseInsensitive<U, MATCHING>
+where
+    T: ?Sized,
+    U: SerializeAs<T>,
+    MATCHING: formats::IdentifierMatching,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        U::serialize_as(source, serializer)
+    }
+}
+
+impl<'de, T, U, MATCHING> DeserializeAs<'de, T> for CaseInsensitive<U, MATCHING>
+where
+    U: DeserializeAs<'de, T>,
+    MATCHING: formats::IdentifierMatching,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        U::deserialize_as(Wrapper::<_, MATCHING>::new(deserializer, &[]))
+    }
+}
+
+/// Wrapper around the deserializer and the types it produces.
+///
+/// `names` are the expected field or variant names.
+struct Wrapper<T, MATCHING> {
+    delegate: T,
+    names: &'static [&'static str],
+    phantom: PhantomData<MATCHING>,
+}
+
+impl<T, MATCHING> Wrapper<T, MATCHING> {
+    fn new(delegate: T, names: &'static [&'static str]) -> Self {
+        Wrapper {
+            delegate,
+            names,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, D, MATCHING> Deserializer<'de> for Wrapper<D, MATCHING>
+where
+    D: Deserializer<'de>,
+    MATCHING: formats::IdentifierMatching,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.delegate
+            .deserialize_struct(name, fields, Wrapper::<_, MATCHING>::new(visitor, fields))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.delegate.deserialize_enum(
+            name,
+            variants,
+            Wrapper::<_, MATCHING>::new(visitor, variants),
+        )
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.delegate.is_human_readable()
+    }
+}
+
+impl<'de, V, MATCHING> Visitor<'de> for Wrapper<V, MATCHING>
+where
+    V: Visitor<'de>,
+    MATCHING: formats::IdentifierMatching,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.delegate.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+    }
+
+    #[cfg(feature = "alloc")]
+    forward_visit! {
+        visit_string(String);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.delegate.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.delegate.visit_some(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.delegate.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.delegate.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.delegate.visit_seq(seq)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.delegate
+            .visit_map(Wrapper::<_, MATCHING>::new(map, self.names))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.delegate
+            .visit_enum(Wrapper::<_, MATCHING>::new(data, self.names))
+    }
+}
+
+impl<'de, A, MATCHING> MapAccess<'de> for Wrapper<A, MATCHING>
+where
+    A: MapAccess<'de>,
+    MATCHING: formats::IdentifierMatching,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.delegate.next_key_seed(Identifier {
+            seed,
+            names: self.names,
+            phantom: PhantomData::<MATCHING>,
+        })
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.delegate.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.delegate.size_hint()
+    }
+}
+
+impl<'de, A, MATCHING> EnumAccess<'de> for Wrapper<A, MATCHING>
+where
+    A: EnumAccess<'de>,
+    MATCHING: formats::IdentifierMatching,
+{
+    type Error = A::Error;
+    type Variant = Wrapper<A::Variant, MATCHING>;
+
+    fn variant_seed<S>(self, seed: S) -> Result<(S::Value, Self::Variant), Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let (value, variant) = self.delegate.variant_seed(Identifier {
+            seed,
+            names: self.names,
+            phantom: PhantomData::<MATCHING>,
+        })?;
+        Ok((value, Wrapper::<_, MATCHING>::new(variant, &[])))
+    }
+}
+
+impl<'de, A, MATCHING> VariantAccess<'de> for Wrapper<A, MATCHING>
+where
+    A: VariantAccess<'de>,
+    MATCHING: formats::IdentifierMatching,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.delegate.unit_variant()
+    }
+
+    fn newtype_variant_seed<S>(self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.delegate.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.delegate.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.delegate
+            .struct_variant(fields, Wrapper::<_, MATCHING>::new(visitor, fields))
+    }
+}
+
+/// Deserialize a field or variant name and replace it with the matching expected name.
+struct Identifier<S, MATCHING> {
+    seed: S,
+    names: &'static [&'static str],
+    phantom: PhantomData<MATCHING>,
+}
+
+impl<S, MATCHING> Identifier<S, MATCHING>
+where
+    MATCHING: formats::IdentifierMatching,
+{
+    fn find(&self, input: &str) -> Option<&'static str> {
+        let names = self.names.iter().copied();
+        names
+            .clone()
+            .find(|&name| name == input)
+            .or_else(|| names.clone().find(|name| MATCHING::matches(name, input)))
+    }
+}
+
+impl<'de, S, MATCHING> DeserializeSeed<'de> for Identifier<S, MATCHING>
+where
+    S: DeserializeSeed<'de>,
+    MATCHING: formats::IdentifierMatching,
+{
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de, S, MATCHING> Visitor<'de> for Identifier<S, MATCHING>
+where
+    S: DeserializeSeed<'de>,
+    MATCHING: formats::IdentifierMatching,
+{
+    type Value = S::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a field or variant identifier")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.seed.deserialize(v.into_deserializer())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        match self.find(v) {
+            Some(name) => self.seed.deserialize(BorrowedStrDeserializer::new(name)),
+            None => self.seed.deserialize(StrDeserializer::new(v)),
+        }
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        match self.find(v) {
+            Some(name) => self.seed.deserialize(BorrowedStrDeserializer::new(name)),
+            None => self.seed.deserialize(BorrowedStrDeserializer::new(v)),
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        match str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => self.seed.deserialize(BytesDeserializer::new(v)),
+        }
+    }
+}
@@ -12,7 +12,7 @@
 use crate::{
     formats::{Flexible, Format, Strict, Strictness},
     prelude::*,
-    utils::duration::{use_duration_signed_de, use_duration_signed_ser},
+    utils::duration::{use_duration_signed_de, use_duration_signed_ser, use_duration_str},
 };
 #[cfg(feature = "std")]
 use ::chrono_0_4::Local;
@@ -402,3 +402,8 @@ use_duration_signed_de!(
         #[cfg(feature = "alloc")] {String, Strict =>}
     }
 );
+
+use_duration_str!(
+    Iso8601Duration, HumanDuration
+    => Duration; duration_into_duration_signed, duration_from_duration_signed
+);
@@ -17,44 +17,12 @@
 use self::utils::{get_unexpected_i128, get_unexpected_u128};
 use crate::{prelude::*, utils::size_hint_from_bounds};
 
-/// Used from generated code to buffer the contents of the Deserializer when
-/// deserializing untagged enums and internally tagged enums.
-pub(crate) enum Content<'de> {
-    Bool(bool),
-
-    U8(u8),
-    U16(u16),
-    U32(u32),
-    U64(u64),
-    U128(u128),
-
-    I8(i8),
-    I16(i16),
-    I32(i32),
-    I64(i64),
-    I128(i128),
-
-    F32(f32),
-    F64(f64),
-
-    Char(char),
-    String(String),
-    Str(&'de str),
-    ByteBuf(Vec<u8>),
-    Bytes(&'de [u8]),
-
-    None,
-    Some(Box<Content<'de>>),
-
-    Unit,
-    Newtype(Box<Content<'de>>),
-    Seq(Vec<Content<'de>>),
-    Map(Vec<(Content<'de>, Content<'de>)>),
-}
+// The buffered content is the public `Value` type.
+pub(crate) use crate::value::Value as Content;
 
 impl Content<'_> {
     #[cold]
-    fn unexpected<'a>(&'a self, buf: &'a mut [u8; 58]) -> Unexpected<'a> {
+    pub(crate) fn unexpected<'a>(&'a self, buf: &'a mut [u8; 58]) -> Unexpected<'a> {
         match *self {
             Content::Bool(b) => Unexpected::Bool(b),
             Content::U8(n) => Unexpected::Unsigned(u64::from(n)),
@@ -306,7 +274,10 @@ impl<'de> Visitor<'de> for ContentVisitor<'de> {
     }
 }
 
-pub(crate) struct ContentDeserializer<'de, E> {
+/// Deserializer for an owned [`Value`](crate::value::Value)
+///
+/// Create it using [`Value::into_deserializer`](crate::value::Value::into_deserializer) or [`ValueDeserializer::new`](crate::value::ValueDeserializer::new).
+pub struct ContentDeserializer<'de, E> {
     is_human_readable: bool,
     content: Content<'de>,
     err: PhantomData<E>,
@@ -801,8 +772,8 @@ where
 }
 
 impl<'de, E> ContentDeserializer<'de, E> {
-    /// private API, don't use
-    pub(crate) fn new(content: Content<'de>, is_human_readable: bool) -> Self {
+    /// Create a deserializer for `content`, which reports `is_human_readable` to the deserialized types.
+    pub fn new(content: Content<'de>, is_human_readable: bool) -> Self {
         ContentDeserializer {
             is_human_readable,
             content,
@@ -1126,7 +1097,10 @@ where
     }
 }
 
-/// Not public API.
+/// Deserializer for a borrowed [`Value`](crate::value::Value)
+///
+/// The [`Value`](crate::value::Value) is not consumed, so it can be deserialized multiple times.
+/// Create it using [`Value::as_deserializer`](crate::value::Value::as_deserializer) or [`ValueRefDeserializer::new`](crate::value::ValueRefDeserializer::new).
 pub struct ContentRefDeserializer<'a, 'de, E> {
     is_human_readable: bool,
     content: &'a Content<'de>,
@@ -1593,8 +1567,8 @@ where
 }
 
 impl<'a, 'de, E> ContentRefDeserializer<'a, 'de, E> {
-    /// private API, don't use
-    pub(crate) fn new(content: &'a Content<'de>, is_human_readable: bool) -> Self {
+    /// Create a deserializer for `content`, which reports `is_human_readable` to the deserialized types.
+    pub fn new(content: &'a Content<'de>, is_human_readable: bool) -> Self {
         ContentRefDeserializer {
             is_human_readable,
             content,
@@ -0,0 +1,267 @@
+use super::impls::macros::foreach_map;
+use crate::{content::de::Content, prelude::*};
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::HashMap as HashbrownMap014;
+#[cfg(feature = "hashbrown_0_15")]
+use hashbrown_0_15::HashMap as HashbrownMap015;
+#[cfg(feature = "hashbrown_0_16")]
+use hashbrown_0_16::HashMap as HashbrownMap016;
+#[cfg(feature = "hashbrown_0_17")]
+use hashbrown_0_17::HashMap as HashbrownMap017;
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::IndexMap;
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::IndexMap as IndexMap2;
+
+impl CollectedErrors {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error at `path`.
+    pub fn push(&mut self, path: impl Display, error: impl Display) {
+        self.errors.push(CollectedError {
+            path: path.to_string(),
+            message: error.to_string(),
+        });
+    }
+
+    /// Add all errors of `errors` with `path` as prefix of their paths.
+    pub fn nest(&mut self, path: impl Display, errors: CollectedErrors) {
+        self.errors
+            .extend(errors.errors.into_iter().map(|error| CollectedError {
+                path: alloc::format!("{path}{}", error.path),
+                message: error.message,
+            }));
+    }
+
+    /// Return the value of `result` or add its error at `path`.
+    pub fn record<T, E>(&mut self, path: impl Display, result: Result<T, E>) -> Option<T>
+    where
+        E: Display,
+    {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(path, error);
+                None
+            }
+        }
+    }
+
+    /// Return `true` if no error was collected.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Return the number of collected errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Iterate over all collected errors in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &CollectedError> {
+        self.errors.iter()
+    }
+
+    /// Return `value` if no error was collected, otherwise a single error listing all collected errors.
+    pub fn into_result<T, E>(self, value: T) -> Result<T, E>
+    where
+        E: DeError,
+    {
+        if self.is_empty() {
+            Ok(value)
+        } else {
+            Err(DeError::custom(self))
+        }
+    }
+}
+
+impl Display for CollectedErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, error) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("\n")?;
+            }
+            Display::fmt(error, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl CollectedError {
+    /// The location of the error, e.g., `[3]["name"]`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The error message without the path.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for CollectedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Render a map key as path segment.
+fn key_path(key: &Content<'_>) -> String {
+    match key {
+        Content::String(s) => alloc::format!("[{s:?}]"),
+        Content::Str(s) => alloc::format!("[{s:?}]"),
+        Content::Char(c) => alloc::format!("[{c:?}]"),
+        Content::Bool(b) => alloc::format!("[{b}]"),
+        Content::U8(n) => alloc::format!("[{n}]"),
+        Content::U16(n) => alloc::format!("[{n}]"),
+        Content::U32(n) => alloc::format!("[{n}]"),
+        Content::U64(n) => alloc::format!("[{n}]"),
+        Content::U128(n) => alloc::format!("[{n}]"),
+        Content::I8(n) => alloc::format!("[{n}]"),
+        Content::I16(n) => alloc::format!("[{n}]"),
+        Content::I32(n) => alloc::format!("[{n}]"),
+        Content::I64(n) => alloc::format!("[{n}]"),
+        Content::I128(n) => alloc::format!("[{n}]"),
+        Content::Some(inner) | Content::Newtype(inner) => key_path(inner),
+        _ => "[?]".into(),
+    }
+}
+
+impl<'de, T, U> DeserializeAs<'de, Vec<T>> for CollectErrors<U>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor<T, U> {
+            is_human_readable: bool,
+            marker: PhantomData<(T, U)>,
+        }
+
+        impl<'de, T, TAs> Visitor<'de> for SeqVisitor<T, TAs>
+        where
+            TAs: DeserializeAs<'de, T>,
+        {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a sequence")
+            }
+
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut errors = CollectedErrors::new();
+                let mut values = Vec::new();
+                for (idx, content) in utils::SeqIter::<A, Content<'de>>::new(seq).enumerate() {
+                    let result = <DeserializeAsWrap<T, TAs>>::deserialize(
+                        content::de::ContentDeserializer::<A::Error>::new(
+                            content?,
+                            self.is_human_readable,
+                        ),
+                    );
+                    if let Some(value) = errors.record(format_args!("[{idx}]"), result) {
+                        values.push(value.into_inner());
+                    }
+                }
+                errors.into_result(values)
+            }
+        }
+
+        let visitor = SeqVisitor::<T, U> {
+            is_human_readable: deserializer.is_human_readable(),
+            marker: PhantomData,
+        };
+        deserializer.deserialize_seq(visitor)
+    }
+}
+
+struct MapCollectErrorsVisitor<MAP, K, KAs, V, VAs> {
+    is_human_readable: bool,
+    marker: PhantomData<(MAP, K, KAs, V, VAs)>,
+}
+
+impl<'de, MAP, K, KAs, V, VAs> Visitor<'de> for MapCollectErrorsVisitor<MAP, K, KAs, V, VAs>
+where
+    MAP: FromIterator<(K, V)>,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
+{
+    type Value = MAP;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut errors = CollectedErrors::new();
+        let mut entries = Vec::new();
+        for entry in utils::MapIter::<A, Content<'de>, Content<'de>>::new(access) {
+            let (key, value) = entry?;
+            let path = key_path(&key);
+            let key = errors.record(
+                &path,
+                <DeserializeAsWrap<K, KAs>>::deserialize(content::de::ContentDeserializer::<
+                    A::Error,
+                >::new(
+                    key, self.is_human_readable
+                )),
+            );
+            let value = errors.record(
+                &path,
+                <DeserializeAsWrap<V, VAs>>::deserialize(content::de::ContentDeserializer::<
+                    A::Error,
+                >::new(
+                    value, self.is_human_readable
+                )),
+            );
+            if let (Some(key), Some(value)) = (key, value) {
+                entries.push((key.into_inner(), value.into_inner()));
+            }
+        }
+        errors.into_result(entries.into_iter().collect())
+    }
+}
+
+macro_rules! map_impl {
+    (
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)?, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >,
+        $with_capacity:expr
+    ) => {
+        impl<'de, K, V, KAs, VAs $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>>
+            for MapCollectErrors<KAs, VAs>
+        where
+            KAs: DeserializeAs<'de, K>,
+            VAs: DeserializeAs<'de, V>,
+            $(K: $kbound1 $(+ $kbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let is_human_readable = deserializer.is_human_readable();
+                deserializer.deserialize_map(MapCollectErrorsVisitor::<
+                    $ty<K, V $(, $typaram)*>,
+                    K,
+                    KAs,
+                    V,
+                    VAs,
+                > {
+                    is_human_readable,
+                    marker: PhantomData,
+                })
+            }
+        }
+    };
+}
+foreach_map!(map_impl);
@@ -1357,6 +1357,46 @@ use_signed_duration!(
     }
 );
 
+impl<'de> DeserializeAs<'de, Duration> for Iso8601Duration<Strict> {
+    fn deserialize_as<D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dur: DurationSigned = Iso8601Duration::<Strict>::deserialize_as(deserializer)?;
+        dur.to_std_duration::<D>()
+    }
+}
+
+impl<'de> DeserializeAs<'de, Duration> for Iso8601Duration<Flexible> {
+    fn deserialize_as<D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dur: DurationSigned = Iso8601Duration::<Flexible>::deserialize_as(deserializer)?;
+        dur.to_std_duration::<D>()
+    }
+}
+
+impl<'de> DeserializeAs<'de, Duration> for HumanDuration<Strict> {
+    fn deserialize_as<D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dur: DurationSigned = HumanDuration::<Strict>::deserialize_as(deserializer)?;
+        dur.to_std_duration::<D>()
+    }
+}
+
+impl<'de> DeserializeAs<'de, Duration> for HumanDuration<Flexible> {
+    fn deserialize_as<D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dur: DurationSigned = HumanDuration::<Flexible>::deserialize_as(deserializer)?;
+        dur.to_std_duration::<D>()
+    }
+}
+
 #[cfg(feature = "std")]
 use_signed_duration!(
     TimestampSeconds DurationSeconds,
@@ -1398,6 +1438,19 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'de, T, U> DeserializeAs<'de, T> for Canonical<U>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        U::deserialize_as(deserializer)
+    }
+}
+
 impl<'de> DeserializeAs<'de, &'de [u8]> for Bytes {
     fn deserialize_as<D>(deserializer: D) -> Result<&'de [u8], D::Error>
     where
@@ -1750,6 +1803,43 @@ impl<'de, const N: usize> DeserializeAs<'de, Box<[u8; N]>> for Bytes {
     }
 }
 
+/// Shared implementation of [`OneOrMany`] for all collection types.
+///
+/// `null` is turned into an empty collection, unless `T` itself can be deserialized from `null`.
+/// Lists are deserialized with `CAs`, which decides how duplicates are handled.
+#[cfg(feature = "alloc")]
+fn deserialize_one_or_many<'de, D, T, TAs, C, CAs>(
+    deserializer: D,
+    one: impl FnOnce(Option<T>) -> C,
+) -> Result<C, D::Error>
+where
+    D: Deserializer<'de>,
+    TAs: DeserializeAs<'de, T>,
+    CAs: DeserializeAs<'de, C>,
+{
+    let is_hr = deserializer.is_human_readable();
+    let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
+
+    let one_err: D::Error = match <DeserializeAsWrap<T, TAs>>::deserialize(
+        content::de::ContentRefDeserializer::new(&content, is_hr),
+    ) {
+        Ok(value) => return Ok(one(Some(value.into_inner()))),
+        Err(err) => err,
+    };
+    if let content::de::Content::None | content::de::Content::Unit = content {
+        return Ok(one(None));
+    }
+    let many_err: D::Error = match <DeserializeAsWrap<C, CAs>>::deserialize(
+        content::de::ContentDeserializer::new(content, is_hr),
+    ) {
+        Ok(many) => return Ok(many.into_inner()),
+        Err(err) => err,
+    };
+    Err(DeError::custom(format_args!(
+        "OneOrMany could not deserialize any variant:\n  One: {one_err}\n  Many: {many_err}"
+    )))
+}
+
 #[cfg(feature = "alloc")]
 macro_rules! one_or_many_impl {
     (
@@ -1757,7 +1847,15 @@ macro_rules! one_or_many_impl {
         $with_capacity:expr,
         $append:ident
     ) => {
-        impl<'de, T, TAs, FORMAT $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for OneOrMany<TAs, FORMAT>
+        one_or_many_impl!(@impl AllowDuplicates, $ty<TAs $(, $typaram)*>, $ty<T $(: $tbound1 $(+ $tbound2)*)? $(, $typaram: $bound1 $(+ $bound2)*)*>, $with_capacity, $append);
+    };
+    (
+        @impl $duplicates:ident, $many:ty,
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        impl<'de, T, TAs, FORMAT $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for OneOrMany<TAs, FORMAT, $duplicates>
         where
             TAs: DeserializeAs<'de, T>,
             FORMAT: Format,
@@ -1768,29 +1866,17 @@ macro_rules! one_or_many_impl {
             where
                 D: Deserializer<'de>,
             {
-                let is_hr = deserializer.is_human_readable();
-                let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
-
-                let one_err: D::Error = match <DeserializeAsWrap<T, TAs>>::deserialize(
-                    content::de::ContentRefDeserializer::new(&content, is_hr),
-                ) {
-                    Ok(one) => {
+                deserialize_one_or_many::<D, T, TAs, _, $many>(
+                    deserializer,
+                    |value| {
                         #[allow(clippy::redundant_closure_call)]
-                        let mut values = ($with_capacity)(1);
-                        values.$append(one.into_inner());
-                        return Ok(values.into());
-                    }
-                    Err(err) => err,
-                };
-                let many_err: D::Error = match <DeserializeAsWrap<$ty<T $(, $typaram)*>, $ty<TAs $(, $typaram)*>>>::deserialize(
-                    content::de::ContentDeserializer::new(content, is_hr),
-                ) {
-                    Ok(many) => return Ok(many.into_inner()),
-                    Err(err) => err,
-                };
-                Err(DeError::custom(format_args!(
-                    "OneOrMany could not deserialize any variant:\n  One: {one_err}\n  Many: {many_err}"
-                )))
+                        let mut values = ($with_capacity)(usize::from(value.is_some()));
+                        if let Some(value) = value {
+                            values.$append(value);
+                        }
+                        values.into()
+                    },
+                )
             }
         }
     };
@@ -1798,8 +1884,22 @@ macro_rules! one_or_many_impl {
 #[cfg(feature = "alloc")]
 foreach_seq!(one_or_many_impl);
 
+#[cfg(feature = "alloc")]
+macro_rules! one_or_many_prevent_duplicates_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        one_or_many_impl!(@impl PreventDuplicates, SetPreventDuplicates<TAs>, $ty<T $(: $tbound1 $(+ $tbound2)*)? $(, $typaram: $bound1 $(+ $bound2)*)*>, $with_capacity, $append);
+    };
+}
+#[cfg(feature = "alloc")]
+foreach_set!(one_or_many_prevent_duplicates_impl);
+
 #[cfg(all(feature = "alloc", feature = "smallvec_1"))]
-impl<'de, T, TAs, FORMAT, A> DeserializeAs<'de, SmallVec<A>> for OneOrMany<TAs, FORMAT>
+impl<'de, T, TAs, FORMAT, A> DeserializeAs<'de, SmallVec<A>>
+    for OneOrMany<TAs, FORMAT, AllowDuplicates>
 where
     A: smallvec_1::Array<Item = T>,
     TAs: DeserializeAs<'de, T>,
@@ -1809,28 +1909,10 @@ where
     where
         D: Deserializer<'de>,
     {
-        let is_hr = deserializer.is_human_readable();
-        let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
-
-        let one_err: D::Error = match <DeserializeAsWrap<T, TAs>>::deserialize(
-            content::de::ContentRefDeserializer::new(&content, is_hr),
-        ) {
-            Ok(one) => {
-                let mut res = SmallVec::<A>::new();
-                res.push(one.into_inner());
-                return Ok(res);
-            }
-            Err(err) => err,
-        };
-        let many_err: D::Error = match <DeserializeAsWrap<Vec<T>, Vec<TAs>>>::deserialize(
-            content::de::ContentDeserializer::new(content, is_hr),
-        ) {
-            Ok(many) => return Ok(SmallVec::from_vec(many.into_inner())),
-            Err(err) => err,
-        };
-        Err(DeError::custom(format_args!(
-            "OneOrMany could not deserialize any variant:\n  One: {one_err}\n  Many: {many_err}"
-        )))
+        deserialize_one_or_many::<D, T, TAs, _, Vec<TAs>>(deserializer, |value| {
+            value.into_iter().collect()
+        })
+        .map(SmallVec::from_vec)
     }
 }
 
@@ -2245,4 +2327,262 @@ impl<'de> DeserializeAs<'de, bool> for BoolFromInt<Flexible> {
     }
 }
 
+/// Number read by [`IntFromFloat`] and [`FloatFromInt`]
+enum ParsedNumber {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+struct ParsedNumberVisitor;
+
+impl Visitor<'_> for ParsedNumberVisitor {
+    type Value = ParsedNumber;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a number")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(ParsedNumber::Signed(i128::from(v)))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(ParsedNumber::Unsigned(u128::from(v)))
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(ParsedNumber::Signed(v))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(ParsedNumber::Unsigned(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        Ok(ParsedNumber::Float(v))
+    }
+}
+
+/// Convert the number into the integer `I`, see [`IntFromFloat`].
+fn int_from_float<I, ROUNDING, OVERFLOW, E>(number: ParsedNumber, strict: bool) -> Result<I, E>
+where
+    I: Integer,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+    E: DeError,
+{
+    let saturate = !strict && OVERFLOW::SATURATE;
+    let expected = if strict {
+        "an integral value in the range of"
+    } else {
+        "a value in the range of"
+    };
+    let error = |unexp: &dyn Display| {
+        DeError::custom(format_args!(
+            "invalid value: {unexp}, expected {expected} {}",
+            I::NAME
+        ))
+    };
+    match number {
+        ParsedNumber::Signed(v) => I::try_from(v)
+            .ok()
+            .or_else(|| saturate.then_some(if v < 0 { I::MIN } else { I::MAX }))
+            .ok_or_else(|| error(&format_args!("integer `{v}`"))),
+        ParsedNumber::Unsigned(v) => I::try_from(v)
+            .ok()
+            .or_else(|| saturate.then_some(I::MAX))
+            .ok_or_else(|| error(&format_args!("integer `{v}`"))),
+        ParsedNumber::Float(v) => utils::float_to_int::<I, ROUNDING, OVERFLOW>(v, strict)
+            .ok_or_else(|| error(&Unexpected::Float(v))),
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for IntFromFloat<Strict>
+where
+    T: Integer,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = deserializer.deserialize_f64(ParsedNumberVisitor)?;
+        int_from_float::<T, Truncate, Reject, _>(number, true)
+    }
+}
+
+impl<'de, T, ROUNDING, OVERFLOW> DeserializeAs<'de, T>
+    for IntFromFloat<Flexible, ROUNDING, OVERFLOW>
+where
+    T: Integer,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = deserializer.deserialize_f64(ParsedNumberVisitor)?;
+        int_from_float::<T, ROUNDING, OVERFLOW, _>(number, false)
+    }
+}
+
+/// Convert the number into the float `F`, see [`FloatFromInt`].
+fn float_from_int<F, OVERFLOW, E>(number: ParsedNumber, strict: bool) -> Result<F, E>
+where
+    F: Float,
+    OVERFLOW: Overflow,
+    E: DeError,
+{
+    let expected = if strict {
+        "an integer, which is exactly representable as"
+    } else {
+        "an integer in the range of"
+    };
+    let error = |unexp: &dyn Display| {
+        DeError::custom(format_args!(
+            "invalid value: {unexp}, expected {expected} {}",
+            F::NAME
+        ))
+    };
+    let check = |value: F, exact: bool| {
+        if strict && !exact {
+            None
+        } else if value.to_f64().is_infinite() {
+            OVERFLOW::SATURATE.then_some(F::MAX)
+        } else {
+            Some(value)
+        }
+    };
+    match number {
+        ParsedNumber::Signed(v) => {
+            let value = F::from_i128(v);
+            check(value, utils::is_exact(value.to_f64(), v))
+                .ok_or_else(|| error(&format_args!("integer `{v}`")))
+        }
+        ParsedNumber::Unsigned(v) => {
+            let value = F::from_u128(v);
+            check(value, utils::is_exact(value.to_f64(), v))
+                .ok_or_else(|| error(&format_args!("integer `{v}`")))
+        }
+        ParsedNumber::Float(v) => Err(DeError::invalid_type(Unexpected::Float(v), &"an integer")),
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for FloatFromInt<Strict>
+where
+    T: Float,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = deserializer.deserialize_i64(ParsedNumberVisitor)?;
+        float_from_int::<T, Reject, _>(number, true)
+    }
+}
+
+impl<'de, T, ROUNDING, OVERFLOW> DeserializeAs<'de, T>
+    for FloatFromInt<Flexible, ROUNDING, OVERFLOW>
+where
+    T: Float,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = deserializer.deserialize_i64(ParsedNumberVisitor)?;
+        float_from_int::<T, OVERFLOW, _>(number, false)
+    }
+}
+
+/// The zero rejected by the `NonZero*` types, which is signed for signed primitives.
+fn unexpected_zero<P: Integer>() -> Unexpected<'static> {
+    if P::MIN.to_f64() < 0.0 {
+        Unexpected::Signed(0)
+    } else {
+        Unexpected::Unsigned(0)
+    }
+}
+
+macro_rules! endian_bytes_deserialize {
+    ($($ty:ty => $primitive:ty),* $(,)?) => {
+        $(
+            impl<'de, BYTES> DeserializeAs<'de, $ty> for BigEndianBytes<BYTES>
+            where
+                BYTES: DeserializeAs<'de, [u8; core::mem::size_of::<$primitive>()]>,
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let bytes = BYTES::deserialize_as(deserializer)?;
+                    <$ty>::try_from(<$primitive>::from_be_bytes(bytes)).map_err(|_| {
+                        DeError::invalid_value(unexpected_zero::<$primitive>(), &"a non-zero integer")
+                    })
+                }
+            }
+
+            impl<'de, BYTES> DeserializeAs<'de, $ty> for LittleEndianBytes<BYTES>
+            where
+                BYTES: DeserializeAs<'de, [u8; core::mem::size_of::<$primitive>()]>,
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let bytes = BYTES::deserialize_as(deserializer)?;
+                    <$ty>::try_from(<$primitive>::from_le_bytes(bytes)).map_err(|_| {
+                        DeError::invalid_value(unexpected_zero::<$primitive>(), &"a non-zero integer")
+                    })
+                }
+            }
+        )*
+    };
+}
+
+endian_bytes_deserialize! {
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    i128 => i128,
+    isize => isize,
+    core::num::NonZeroU8 => u8,
+    core::num::NonZeroU16 => u16,
+    core::num::NonZeroU32 => u32,
+    core::num::NonZeroU64 => u64,
+    core::num::NonZeroU128 => u128,
+    core::num::NonZeroUsize => usize,
+    core::num::NonZeroI8 => i8,
+    core::num::NonZeroI16 => i16,
+    core::num::NonZeroI32 => i32,
+    core::num::NonZeroI64 => i64,
+    core::num::NonZeroI128 => i128,
+    core::num::NonZeroIsize => isize,
+}
+
 // endregion
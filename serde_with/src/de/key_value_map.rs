@@ -0,0 +1,147 @@
+use super::impls::macros::{foreach_map, foreach_set};
+use crate::{
+    duplicate_key_impls::{
+        DuplicateInsertsFirstWinsMap, PreventDuplicateInsertsMap, PreventDuplicateInsertsSet,
+    },
+    key_value_map::deserialize_entries,
+    prelude::*,
+};
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
+#[cfg(feature = "hashbrown_0_15")]
+use hashbrown_0_15::{HashMap as HashbrownMap015, HashSet as HashbrownSet015};
+#[cfg(feature = "hashbrown_0_16")]
+use hashbrown_0_16::{HashMap as HashbrownMap016, HashSet as HashbrownSet016};
+#[cfg(feature = "hashbrown_0_17")]
+use hashbrown_0_17::{HashMap as HashbrownMap017, HashSet as HashbrownSet017};
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
+
+macro_rules! map_impl {
+    (
+        $ty:ident < K $(: $kbound1:ident $(+ $kbound2:ident)*)?, V $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)*)* >,
+        $with_capacity:expr
+    ) => {
+        impl<'de, K, V, VAs, KEY $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>> for KeyValueMap<VAs, KEY>
+        where
+            K: Deserialize<'de>,
+            VAs: DeserializeAs<'de, V>,
+            KEY: formats::KeyField,
+            $(K: $kbound1 $(+ $kbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let entries = deserialize_entries::<D, K, V, VAs>(deserializer, KEY::NAME)?;
+                #[allow(clippy::redundant_closure_call)]
+                let mut values = ($with_capacity)(entries.len());
+                values.extend(entries);
+                Ok(values)
+            }
+        }
+
+        impl<'de, K, V, VAs, KEY $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>>
+            for KeyValueMapPreventDuplicates<VAs, KEY>
+        where
+            K: Deserialize<'de>,
+            VAs: DeserializeAs<'de, V>,
+            KEY: formats::KeyField,
+            $(K: $kbound1 $(+ $kbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let entries = deserialize_entries::<D, K, V, VAs>(deserializer, KEY::NAME)?;
+                let mut values: $ty<K, V $(, $typaram)*> = PreventDuplicateInsertsMap::new(Some(entries.len()));
+                for (key, value) in entries {
+                    if !PreventDuplicateInsertsMap::insert(&mut values, key, value) {
+                        return Err(DeError::custom("invalid entry: found duplicate key"));
+                    }
+                }
+                Ok(values)
+            }
+        }
+
+        impl<'de, K, V, VAs, KEY $(, $typaram)*> DeserializeAs<'de, $ty<K, V $(, $typaram)*>>
+            for KeyValueMapFirstKeyWins<VAs, KEY>
+        where
+            K: Deserialize<'de>,
+            VAs: DeserializeAs<'de, V>,
+            KEY: formats::KeyField,
+            $(K: $kbound1 $(+ $kbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<K, V $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let entries = deserialize_entries::<D, K, V, VAs>(deserializer, KEY::NAME)?;
+                let mut values: $ty<K, V $(, $typaram)*> = DuplicateInsertsFirstWinsMap::new(Some(entries.len()));
+                for (key, value) in entries {
+                    DuplicateInsertsFirstWinsMap::insert(&mut values, key, value);
+                }
+                Ok(values)
+            }
+        }
+    };
+}
+foreach_map!(map_impl);
+
+macro_rules! set_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        impl<'de, T, TAs, KEY $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for KeyValueMap<TAs, KEY>
+        where
+            TAs: DeserializeAs<'de, T>,
+            KEY: formats::KeyField,
+            $(T: $tbound1 $(+ $tbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let entries = deserialize_entries::<D, IgnoredAny, T, TAs>(deserializer, KEY::NAME)?;
+                #[allow(clippy::redundant_closure_call)]
+                let mut values = ($with_capacity)(entries.len());
+                for (_, value) in entries {
+                    values.$append(value);
+                }
+                Ok(values)
+            }
+        }
+
+        impl<'de, T, TAs, KEY $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>>
+            for KeyValueMapPreventDuplicates<TAs, KEY>
+        where
+            TAs: DeserializeAs<'de, T>,
+            KEY: formats::KeyField,
+            $(T: $tbound1 $(+ $tbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let entries = deserialize_entries::<D, IgnoredAny, T, TAs>(deserializer, KEY::NAME)?;
+                let mut values: $ty<T $(, $typaram)*> = PreventDuplicateInsertsSet::new(Some(entries.len()));
+                for (_, value) in entries {
+                    if !PreventDuplicateInsertsSet::insert(&mut values, value) {
+                        return Err(DeError::custom("invalid entry: found duplicate value"));
+                    }
+                }
+                Ok(values)
+            }
+        }
+    };
+}
+foreach_set!(set_impl);
@@ -1,49 +1,200 @@
-use super::impls::macros::foreach_map;
-use crate::prelude::*;
+use super::impls::macros::{foreach_map, foreach_seq, foreach_set};
+use crate::{prelude::*, value::Value};
 #[cfg(feature = "hashbrown_0_14")]
-use hashbrown_0_14::HashMap as HashbrownMap014;
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
 #[cfg(feature = "hashbrown_0_15")]
-use hashbrown_0_15::HashMap as HashbrownMap015;
+use hashbrown_0_15::{HashMap as HashbrownMap015, HashSet as HashbrownSet015};
 #[cfg(feature = "hashbrown_0_16")]
-use hashbrown_0_16::HashMap as HashbrownMap016;
+use hashbrown_0_16::{HashMap as HashbrownMap016, HashSet as HashbrownSet016};
 #[cfg(feature = "hashbrown_0_17")]
-use hashbrown_0_17::HashMap as HashbrownMap017;
+use hashbrown_0_17::{HashMap as HashbrownMap017, HashSet as HashbrownSet017};
 #[cfg(feature = "indexmap_1")]
-use indexmap_1::IndexMap;
+use indexmap_1::{IndexMap, IndexSet};
 #[cfg(feature = "indexmap_2")]
-use indexmap_2::IndexMap as IndexMap2;
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
 
-enum GoodOrError<T, TAs, I> {
-    Good(T),
-    // Only here to consume the TAs,I generics
-    Error(PhantomData<(TAs, I)>),
+type BoxedSlice<T> = Box<[T]>;
+
+impl<'a, 'de> SkipErrorContext<'a, 'de> {
+    pub(crate) fn element(index: usize, value: &'a Value<'de>) -> Self {
+        Self {
+            index,
+            key: None,
+            is_key_error: false,
+            value,
+        }
+    }
+
+    pub(crate) fn entry(
+        index: usize,
+        key: &'a Value<'de>,
+        is_key_error: bool,
+        value: &'a Value<'de>,
+    ) -> Self {
+        Self {
+            index,
+            key: Some(key),
+            is_key_error,
+            value,
+        }
+    }
+
+    /// The position of the element in the sequence or of the entry in the map.
+    ///
+    /// The index counts all elements of the input, including the skipped ones.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The key of the map entry, rendered as string.
+    ///
+    /// Strings are returned verbatim, numbers, booleans and characters are formatted using [`Display`], and all other keys using [`Debug`](fmt::Debug).
+    /// Returns `None` for sequence elements.
+    pub fn key(&self) -> Option<String> {
+        self.key.map(key_to_string)
+    }
+
+    /// The buffered key of the map entry, or `None` for sequence elements.
+    pub fn raw_key(&self) -> Option<&'a Value<'de>> {
+        self.key
+    }
+
+    /// Return `true` if the map key failed to deserialize, instead of the value.
+    pub fn is_key_error(&self) -> bool {
+        self.is_key_error
+    }
+
+    /// The buffered sequence element or map value.
+    ///
+    /// For map entries this is always the value, even if the key failed to deserialize.
+    pub fn value(&self) -> &'a Value<'de> {
+        self.value
+    }
+}
+
+fn key_to_string(key: &Value<'_>) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        Value::Str(s) => (*s).into(),
+        Value::Char(c) => c.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::U8(n) => n.to_string(),
+        Value::U16(n) => n.to_string(),
+        Value::U32(n) => n.to_string(),
+        Value::U64(n) => n.to_string(),
+        Value::U128(n) => n.to_string(),
+        Value::I8(n) => n.to_string(),
+        Value::I16(n) => n.to_string(),
+        Value::I32(n) => n.to_string(),
+        Value::I64(n) => n.to_string(),
+        Value::I128(n) => n.to_string(),
+        Value::F32(n) => n.to_string(),
+        Value::F64(n) => n.to_string(),
+        Value::Some(inner) | Value::Newtype(inner) => key_to_string(inner),
+        _ => alloc::format!("{key:?}"),
+    }
 }
 
-impl<'de, T, TAs, I> Deserialize<'de> for GoodOrError<T, TAs, I>
+/// Deserialize a buffered value, passing the error to `I` on failure.
+///
+/// The value is consumed, so no data needs to be copied.
+/// Only use it if `I` does not want the [`SkipErrorContext`].
+fn deserialize_or_inspect<'de, T, TAs, I, E>(
+    value: Value<'de>,
+    is_human_readable: bool,
+) -> Option<T>
 where
     TAs: DeserializeAs<'de, T>,
     I: InspectError,
+    E: DeError,
 {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
+    match <DeserializeAsWrap<T, TAs>>::deserialize(value.into_deserializer::<E>(is_human_readable))
     {
-        let is_hr = deserializer.is_human_readable();
-        let content: content::de::Content<'de> = Deserialize::deserialize(deserializer)?;
+        Ok(value) => Some(value.into_inner()),
+        Err(err) => {
+            I::inspect_error(err);
+            None
+        }
+    }
+}
 
-        Ok(
-            match <DeserializeAsWrap<T, TAs>>::deserialize(content::de::ContentDeserializer::<
-                D::Error,
-            >::new(content, is_hr))
-            {
-                Ok(elem) => GoodOrError::Good(elem.into_inner()),
-                Err(e) => {
-                    I::inspect_error(e);
-                    GoodOrError::Error(PhantomData)
+/// Deserialize a borrowed buffered value, passing the error and the `context` to `I` on failure.
+///
+/// Used if [`InspectError::WANTS_CONTEXT`] is set, since the context must still be available after deserialization.
+fn deserialize_or_inspect_with_context<'de, T, TAs, I, E>(
+    value: &Value<'de>,
+    is_human_readable: bool,
+    context: &SkipErrorContext<'_, 'de>,
+) -> Option<T>
+where
+    TAs: DeserializeAs<'de, T>,
+    I: InspectError,
+    E: DeError,
+{
+    match <DeserializeAsWrap<T, TAs>>::deserialize(value.as_deserializer::<E>(is_human_readable)) {
+        Ok(value) => Some(value.into_inner()),
+        Err(err) => {
+            I::inspect_error_with_context(err, context);
+            None
+        }
+    }
+}
+
+/// Deserialize all elements of `seq`, passing the valid ones to `push` and the errors to `I`.
+///
+/// Returns the number of elements in the sequence, including the skipped ones.
+fn visit_seq_skip_error<'de, A, T, TAs, I>(
+    seq: A,
+    is_human_readable: bool,
+    push: impl FnMut(T),
+) -> Result<usize, A::Error>
+where
+    A: SeqAccess<'de>,
+    TAs: DeserializeAs<'de, T>,
+    I: InspectError,
+{
+    visit_seq_skip_error_with::<A, T, TAs, I>(seq, is_human_readable, push, |_, _| {})
+}
+
+/// Like [`visit_seq_skip_error`], but additionally passes the index and error of every skipped element to `on_error`.
+fn visit_seq_skip_error_with<'de, A, T, TAs, I>(
+    seq: A,
+    is_human_readable: bool,
+    mut push: impl FnMut(T),
+    mut on_error: impl FnMut(usize, &A::Error),
+) -> Result<usize, A::Error>
+where
+    A: SeqAccess<'de>,
+    TAs: DeserializeAs<'de, T>,
+    I: InspectError,
+{
+    let mut len = 0;
+    for content in utils::SeqIter::<A, Value<'de>>::new(seq) {
+        let content = content?;
+        if I::WANTS_CONTEXT {
+            match <DeserializeAsWrap<T, TAs>>::deserialize(
+                content.as_deserializer::<A::Error>(is_human_readable),
+            ) {
+                Ok(value) => push(value.into_inner()),
+                Err(err) => {
+                    on_error(len, &err);
+                    I::inspect_error_with_context(err, &SkipErrorContext::element(len, &content));
                 }
-            },
-        )
+            }
+        } else {
+            match <DeserializeAsWrap<T, TAs>>::deserialize(
+                content.into_deserializer::<A::Error>(is_human_readable),
+            ) {
+                Ok(value) => push(value.into_inner()),
+                Err(err) => {
+                    on_error(len, &err);
+                    I::inspect_error(err);
+                }
+            }
+        }
+        len += 1;
     }
+    Ok(len)
 }
 
 impl<'de, T, U, I> DeserializeAs<'de, Vec<T>> for VecSkipError<U, I>
@@ -55,7 +206,10 @@ where
     where
         D: Deserializer<'de>,
     {
-        struct SeqVisitor<T, U, I>(PhantomData<(T, U, I)>);
+        struct SeqVisitor<T, U, I> {
+            is_human_readable: bool,
+            marker: PhantomData<(T, U, I)>,
+        }
 
         impl<'de, T, TAs, I> Visitor<'de> for SeqVisitor<T, TAs, I>
         where
@@ -72,22 +226,250 @@ where
             where
                 A: SeqAccess<'de>,
             {
-                utils::SeqIter::new(seq)
-                    .filter_map(|res: Result<GoodOrError<T, TAs, I>, A::Error>| match res {
-                        Ok(GoodOrError::Good(value)) => Some(Ok(value)),
-                        Ok(GoodOrError::Error(_)) => None,
-                        Err(err) => Some(Err(err)),
-                    })
-                    .collect()
+                let mut values = Vec::new();
+                visit_seq_skip_error::<A, T, TAs, I>(seq, self.is_human_readable, |value| {
+                    values.push(value);
+                })?;
+                Ok(values)
             }
         }
 
-        let visitor = SeqVisitor::<T, U, I>(PhantomData);
+        let visitor = SeqVisitor::<T, U, I> {
+            is_human_readable: deserializer.is_human_readable(),
+            marker: PhantomData,
+        };
         deserializer.deserialize_seq(visitor)
     }
 }
 
-struct MapSkipErrorVisitor<MAP, K, KAs, V, VAs, I>(PhantomData<(MAP, K, KAs, V, VAs, I)>);
+macro_rules! seq_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        impl<'de, T, U, I $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for SeqSkipError<U, I>
+        where
+            U: DeserializeAs<'de, T>,
+            I: InspectError,
+            $(T: $tbound1 $(+ $tbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct SeqVisitor<T, U, I $(, $typaram)*> {
+                    is_human_readable: bool,
+                    marker: PhantomData<(T, U, I $(, $typaram)*)>,
+                }
+
+                impl<'de, T, U, I $(, $typaram)*> Visitor<'de> for SeqVisitor<T, U, I $(, $typaram)*>
+                where
+                    U: DeserializeAs<'de, T>,
+                    I: InspectError,
+                    $(T: $tbound1 $(+ $tbound2)*,)?
+                    $($typaram: $bound1 $(+ $bound2)*),*
+                {
+                    type Value = $ty<T $(, $typaram)*>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str("a sequence")
+                    }
+
+                    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        #[allow(clippy::redundant_closure_call)]
+                        let mut values = ($with_capacity)(utils::size_hint_cautious::<T>(seq.size_hint()));
+                        visit_seq_skip_error::<A, T, U, I>(seq, self.is_human_readable, |value| {
+                            values.$append(value);
+                        })?;
+                        Ok(values.into())
+                    }
+                }
+
+                let visitor = SeqVisitor::<T, U, I $(, $typaram)*> {
+                    is_human_readable: deserializer.is_human_readable(),
+                    marker: PhantomData,
+                };
+                deserializer.deserialize_seq(visitor)
+            }
+        }
+    };
+}
+foreach_seq!(seq_impl);
+
+impl<'de, T, U, I, const N: usize> DeserializeAs<'de, [T; N]> for SeqSkipError<U, I>
+where
+    U: DeserializeAs<'de, T>,
+    I: InspectError,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor<T, U, I, const M: usize> {
+            is_human_readable: bool,
+            marker: PhantomData<(T, U, I)>,
+        }
+
+        impl<'de, T, U, I, const M: usize> Visitor<'de> for ArrayVisitor<T, U, I, M>
+        where
+            U: DeserializeAs<'de, T>,
+            I: InspectError,
+        {
+            type Value = [T; M];
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_fmt(format_args!("a sequence with {M} valid elements"))
+            }
+
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = Vec::new();
+                let len =
+                    visit_seq_skip_error::<A, T, U, I>(seq, self.is_human_readable, |value| {
+                        values.push(value);
+                    })?;
+                let valid = values.len();
+                if valid < M {
+                    return Err(DeError::custom(format_args!(
+                        "expected {M} valid elements, but only {valid} of {len} elements could be deserialized"
+                    )));
+                }
+                values
+                    .try_into()
+                    .map_err(|_| DeError::invalid_length(valid, &self))
+            }
+        }
+
+        let visitor = ArrayVisitor::<T, U, I, N> {
+            is_human_readable: deserializer.is_human_readable(),
+            marker: PhantomData,
+        };
+        deserializer.deserialize_seq(visitor)
+    }
+}
+
+/// Maximum number of errors listed in the error of [`BoundedSkipError`]
+const BOUNDED_SKIP_ERROR_MAX_REPORTED: usize = 10;
+
+macro_rules! bounded_seq_impl {
+    (
+        $ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)? $(, $typaram:ident : $bound1:ident $(+ $bound2:ident)* )* >,
+        $with_capacity:expr,
+        $append:ident
+    ) => {
+        impl<'de, T, U, LIMIT, I $(, $typaram)*> DeserializeAs<'de, $ty<T $(, $typaram)*>> for BoundedSkipError<U, LIMIT, I>
+        where
+            U: DeserializeAs<'de, T>,
+            LIMIT: formats::SkipLimit,
+            I: InspectError,
+            $(T: $tbound1 $(+ $tbound2)*,)?
+            $($typaram: $bound1 $(+ $bound2)*),*
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty<T $(, $typaram)*>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct SeqVisitor<T, U, LIMIT, I $(, $typaram)*> {
+                    is_human_readable: bool,
+                    marker: PhantomData<(T, U, LIMIT, I $(, $typaram)*)>,
+                }
+
+                impl<'de, T, U, LIMIT, I $(, $typaram)*> Visitor<'de> for SeqVisitor<T, U, LIMIT, I $(, $typaram)*>
+                where
+                    U: DeserializeAs<'de, T>,
+                    LIMIT: formats::SkipLimit,
+                    I: InspectError,
+                    $(T: $tbound1 $(+ $tbound2)*,)?
+                    $($typaram: $bound1 $(+ $bound2)*),*
+                {
+                    type Value = $ty<T $(, $typaram)*>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str("a sequence")
+                    }
+
+                    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        #[allow(clippy::redundant_closure_call)]
+                        let mut values = ($with_capacity)(utils::size_hint_cautious::<T>(seq.size_hint()));
+                        let mut skipped = 0;
+                        let mut errors = CollectedErrors::new();
+                        let len = visit_seq_skip_error_with::<A, T, U, I>(
+                            seq,
+                            self.is_human_readable,
+                            |value| {
+                                values.$append(value);
+                            },
+                            |idx, err| {
+                                skipped += 1;
+                                if errors.len() < BOUNDED_SKIP_ERROR_MAX_REPORTED {
+                                    errors.push(format_args!("[{idx}]"), err);
+                                }
+                            },
+                        )?;
+                        check_skip_limit::<LIMIT, A::Error>(skipped, len, &errors)?;
+                        Ok(values.into())
+                    }
+                }
+
+                let visitor = SeqVisitor::<T, U, LIMIT, I $(, $typaram)*> {
+                    is_human_readable: deserializer.is_human_readable(),
+                    marker: PhantomData,
+                };
+                deserializer.deserialize_seq(visitor)
+            }
+        }
+    };
+}
+foreach_seq!(bounded_seq_impl);
+
+/// Fail with a summary of the `errors`, if skipping `skipped` of `total` elements exceeds the `LIMIT`.
+fn check_skip_limit<LIMIT, E>(
+    skipped: usize,
+    total: usize,
+    errors: &CollectedErrors,
+) -> Result<(), E>
+where
+    LIMIT: formats::SkipLimit,
+    E: DeError,
+{
+    struct Describe<LIMIT>(PhantomData<LIMIT>);
+
+    impl<LIMIT: formats::SkipLimit> Display for Describe<LIMIT> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            LIMIT::describe(f)
+        }
+    }
+
+    if !LIMIT::is_exceeded(skipped, total) {
+        return Ok(());
+    }
+    let limit = Describe::<LIMIT>(PhantomData);
+    let unreported = skipped - errors.len();
+    if unreported == 0 {
+        Err(DeError::custom(format_args!(
+            "skipped {skipped} of {total} elements, but {limit}:\n{errors}"
+        )))
+    } else {
+        Err(DeError::custom(format_args!(
+            "skipped {skipped} of {total} elements, but {limit}:\n{errors}\n... and {unreported} more"
+        )))
+    }
+}
+
+struct MapSkipErrorVisitor<MAP, K, KAs, V, VAs, I> {
+    is_human_readable: bool,
+    marker: PhantomData<(MAP, K, KAs, V, VAs, I)>,
+}
 
 impl<'de, MAP, K, KAs, V, VAs, I> Visitor<'de> for MapSkipErrorVisitor<MAP, K, KAs, V, VAs, I>
 where
@@ -107,18 +489,39 @@ where
     where
         A: MapAccess<'de>,
     {
-        type KVPair<K, KAs, V, VAs, I> = (GoodOrError<K, KAs, I>, GoodOrError<V, VAs, I>);
-        utils::MapIter::new(access)
-            .filter_map(
-                |res: Result<KVPair<K, KAs, V, VAs, I>, A::Error>| match res {
-                    Ok((GoodOrError::Good(key), GoodOrError::Good(value))) => {
-                        Some(Ok((key, value)))
-                    }
-                    Ok(_) => None,
-                    Err(err) => Some(Err(err)),
-                },
-            )
-            .collect()
+        let mut entries = Vec::new();
+        for (idx, entry) in utils::MapIter::<A, Value<'de>, Value<'de>>::new(access).enumerate() {
+            let (key_content, value_content) = entry?;
+            let (key, value) = if I::WANTS_CONTEXT {
+                (
+                    deserialize_or_inspect_with_context::<K, KAs, I, A::Error>(
+                        &key_content,
+                        self.is_human_readable,
+                        &SkipErrorContext::entry(idx, &key_content, true, &value_content),
+                    ),
+                    deserialize_or_inspect_with_context::<V, VAs, I, A::Error>(
+                        &value_content,
+                        self.is_human_readable,
+                        &SkipErrorContext::entry(idx, &key_content, false, &value_content),
+                    ),
+                )
+            } else {
+                (
+                    deserialize_or_inspect::<K, KAs, I, A::Error>(
+                        key_content,
+                        self.is_human_readable,
+                    ),
+                    deserialize_or_inspect::<V, VAs, I, A::Error>(
+                        value_content,
+                        self.is_human_readable,
+                    ),
+                )
+            };
+            if let (Some(key), Some(value)) = (key, value) {
+                entries.push((key, value));
+            }
+        }
+        Ok(entries.into_iter().collect())
     }
 }
 
@@ -141,6 +544,7 @@ macro_rules! map_impl {
             where
                 D: Deserializer<'de>,
             {
+                let is_human_readable = deserializer.is_human_readable();
                 deserializer.deserialize_map(MapSkipErrorVisitor::<
                     $ty<K, V $(, $typaram)*>,
                     K,
@@ -148,7 +552,10 @@ macro_rules! map_impl {
                     V,
                     VAs,
                     I,
-                >(PhantomData))
+                > {
+                    is_human_readable,
+                    marker: PhantomData,
+                })
             }
         }
     };
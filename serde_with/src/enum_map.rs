@@ -1,17 +1,22 @@
 use crate::{
-    content::ser::{Content, ContentSerializer},
+    content::{
+        de::{Content as DeContent, ContentDeserializer},
+        ser::{Content, ContentSerializer},
+    },
     prelude::*,
 };
 
 /// Represent a list of enum values as a map.
 ///
-/// This **only works** if the enum uses the default *externally tagged* representation.
-/// Other enum representations are not supported.
-///
 /// serde data formats often represent *externally tagged* enums as maps with a single key.
 /// The key is the enum variant name, and the value is the variant value.
 /// Sometimes a map with multiple keys should be treated like a list of enum values.
 ///
+/// By default, the enum must use the *externally tagged* representation.
+/// *Internally tagged* and *adjacently tagged* enums are supported with the [`InternallyTagged`] and [`AdjacentlyTagged`] parameters.
+/// The tag value becomes the map key, while the other fields or the content field become the map value.
+/// *Untagged* enums are not supported.
+///
 /// # Examples
 ///
 /// ## JSON Map with multiple keys
@@ -152,7 +157,58 @@ use crate::{
 /// assert_eq!(values, deserialized);
 /// # }
 /// ```
-pub struct EnumMap;
+///
+/// ## Internally tagged enums
+///
+/// The tag field name is provided by a [`KeyField`].
+/// For adjacently tagged enums use `EnumMap<AdjacentlyTagged<Type, Data>>`, with a second [`KeyField`] for the content field.
+/// Deserializing internally and adjacently tagged enums buffers each map value, which requires a self-describing data format.
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::formats::{InternallyTagged, KeyField};
+/// use serde_with::{serde_as, EnumMap};
+///
+/// struct Type;
+/// impl KeyField for Type {
+///     const NAME: &'static str = "type";
+/// }
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(tag = "type")]
+/// enum Event {
+///     Login { user: String },
+///     Logout { user: String },
+///     Shutdown,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Events(#[serde_as(as = "EnumMap<InternallyTagged<Type>>")] Vec<Event>);
+///
+/// let events = Events(vec![
+///     Event::Login {
+///         user: "alice".to_string(),
+///     },
+///     Event::Logout {
+///         user: "alice".to_string(),
+///     },
+///     Event::Shutdown,
+/// ]);
+///
+/// let expected = r#"{"Login":{"user":"alice"},"Logout":{"user":"alice"},"Shutdown":{}}"#;
+/// assert_eq!(expected, serde_json::to_string(&events).unwrap());
+/// assert_eq!(events, serde_json::from_str(expected).unwrap());
+/// # }
+/// ```
+///
+/// [`AdjacentlyTagged`]: crate::formats::AdjacentlyTagged
+/// [`InternallyTagged`]: crate::formats::InternallyTagged
+/// [`KeyField`]: crate::formats::KeyField
+pub struct EnumMap<TAGGING: formats::EnumTagging = formats::ExternallyTagged>(PhantomData<TAGGING>);
 
 impl<T> SerializeAs<Vec<T>> for EnumMap
 where
@@ -205,6 +261,185 @@ where
     }
 }
 
+impl<T, TAG> SerializeAs<Vec<T>> for EnumMap<formats::InternallyTagged<TAG>>
+where
+    T: Serialize,
+    TAG: formats::KeyField,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tagged(source, TAG::NAME, None, serializer)
+    }
+}
+
+impl<'de, T, TAG> DeserializeAs<'de, Vec<T>> for EnumMap<formats::InternallyTagged<TAG>>
+where
+    T: Deserialize<'de>,
+    TAG: formats::KeyField,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_tagged(deserializer, TAG::NAME, None)
+    }
+}
+
+impl<T, TAG, CONTENT> SerializeAs<Vec<T>> for EnumMap<formats::AdjacentlyTagged<TAG, CONTENT>>
+where
+    T: Serialize,
+    TAG: formats::KeyField,
+    CONTENT: formats::KeyField,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tagged(source, TAG::NAME, Some(CONTENT::NAME), serializer)
+    }
+}
+
+impl<'de, T, TAG, CONTENT> DeserializeAs<'de, Vec<T>>
+    for EnumMap<formats::AdjacentlyTagged<TAG, CONTENT>>
+where
+    T: Deserialize<'de>,
+    TAG: formats::KeyField,
+    CONTENT: formats::KeyField,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_tagged(deserializer, TAG::NAME, Some(CONTENT::NAME))
+    }
+}
+
+/// Serialize internally or adjacently tagged enums as a map.
+///
+/// Each element is buffered as [`Content`] and the `tag` field is moved into the map key.
+/// Without a `content` field the remaining fields form the map value, otherwise the value of the `content` field is used.
+fn serialize_tagged<T, S>(
+    source: &[T],
+    tag: &'static str,
+    content: Option<&'static str>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    let is_human_readable = serializer.is_human_readable();
+    let mut map = serializer.serialize_map(Some(source.len()))?;
+    for element in source {
+        let (key, value) = match element.serialize(ContentSerializer::new(is_human_readable))? {
+            // Newtype variants containing a map
+            Content::Map(mut entries) => {
+                let Some(pos) = entries
+                    .iter()
+                    .position(|(key, _)| key.as_str() == Some(tag))
+                else {
+                    return Err(SerError::custom(format_args!(
+                        "missing value for `{tag}` field"
+                    )));
+                };
+                let (_, key) = entries.remove(pos);
+                let value = match content {
+                    None => Content::Map(entries),
+                    Some(content) => entries
+                        .into_iter()
+                        .find(|(key, _)| key.as_str() == Some(content))
+                        .map_or(Content::Unit, |(_, value)| value),
+                };
+                (key, value)
+            }
+            Content::Struct(name, mut fields) => {
+                let Some(pos) = fields.iter().position(|(key, _)| *key == tag) else {
+                    return Err(SerError::custom(format_args!(
+                        "missing value for `{tag}` field"
+                    )));
+                };
+                let (_, key) = fields.remove(pos);
+                let value = match content {
+                    None => Content::Struct(name, fields),
+                    Some(content) => fields
+                        .into_iter()
+                        .find(|(key, _)| *key == content)
+                        .map_or(Content::Unit, |(_, value)| value),
+                };
+                (key, value)
+            }
+            _ => return Err(SerError::custom("wrong type for EnumMap")),
+        };
+        map.serialize_entry(&key, &value)?;
+    }
+    map.end()
+}
+
+/// Deserialize internally or adjacently tagged enums from a map.
+///
+/// The map key is moved back into the `tag` field.
+/// Without a `content` field the map value must be a map, whose entries are merged with the tag.
+/// Otherwise the map value is stored in the `content` field.
+fn deserialize_tagged<'de, D, T>(
+    deserializer: D,
+    tag: &'static str,
+    content: Option<&'static str>,
+) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct TaggedVisitor<T> {
+        is_human_readable: bool,
+        tag: &'static str,
+        content: Option<&'static str>,
+        phantom: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for TaggedVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map of enum values")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut values = utils::vec_with_capacity_cautious(map.size_hint());
+            while let Some((key, value)) = map.next_entry::<DeContent<'de>, DeContent<'de>>()? {
+                let mut entries = alloc::vec![(DeContent::Str(self.tag), key)];
+                match (self.content, value) {
+                    (None, DeContent::Map(fields)) => entries.extend(fields),
+                    // Unit variants have no fields besides the tag
+                    (None, DeContent::None | DeContent::Unit) => {}
+                    (None, value) => {
+                        let mut buf = [0; 58];
+                        return Err(DeError::invalid_type(value.unexpected(&mut buf), &"a map"));
+                    }
+                    (Some(content), value) => entries.push((DeContent::Str(content), value)),
+                }
+                values.push(T::deserialize(ContentDeserializer::<A::Error>::new(
+                    DeContent::Map(entries),
+                    self.is_human_readable,
+                ))?);
+            }
+            Ok(values)
+        }
+    }
+
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(TaggedVisitor {
+        is_human_readable,
+        tag,
+        content,
+        phantom: PhantomData,
+    })
+}
+
 static END_OF_MAP_IDENTIFIER: &str = "__PRIVATE_END_OF_MAP_MARKER__";
 
 // Serialization code below here
@@ -2,6 +2,7 @@
 
 #[allow(unused_imports)]
 use crate::prelude::*;
+use core::cmp::Ordering;
 
 /// Specify how to serialize/deserialize a type
 ///
@@ -73,11 +74,18 @@ create_format!(
     PreferOne
     /// Use in combination with [`OneOrMany`]. Always emit the list form.
     PreferMany
+    /// Use in combination with [`OneOrMany`]. Emit `None` for empty lists and a single element for lists of size 1.
+    PreferNone
 
     /// Emit padding during serialization.
     Padded
     /// Do not emit padding during serialization.
     Unpadded
+
+    /// Emit a `0x` prefix during serialization.
+    Prefixed
+    /// Do not emit a prefix during serialization.
+    Unprefixed
 );
 
 /// Specify how lenient the deserialization process should be
@@ -93,12 +101,41 @@ impl Strictness for Strict {}
 pub struct Flexible;
 impl Strictness for Flexible {}
 
+/// Specify how [`OneOrMany`] handles duplicate values in sets
+///
+/// [`OneOrMany`]: crate::OneOrMany
+pub trait Duplicates {}
+
+/// Accept lists with duplicate values, see [`Duplicates`].
+///
+/// Sets keep only one of the duplicate values.
+pub struct AllowDuplicates;
+impl Duplicates for AllowDuplicates {}
+
+/// Reject lists with duplicate values, see [`Duplicates`].
+///
+/// Only sets support this option, since other collections keep all values.
+pub struct PreventDuplicates;
+impl Duplicates for PreventDuplicates {}
+
 /// Separator for string-based collection de/serialization
 pub trait Separator {
     /// Return the string delimiting two elements in the string-based collection
     fn separator() -> &'static str;
 }
 
+/// Predefined separator without any characters, which places the elements directly next to each other
+///
+/// It is not suitable for [`StringWithSeparator`], since the elements cannot be split again.
+pub struct NoSeparator;
+
+impl Separator for NoSeparator {
+    #[inline]
+    fn separator() -> &'static str {
+        ""
+    }
+}
+
 /// Predefined separator using a single space
 pub struct SpaceSeparator;
 
@@ -158,3 +195,384 @@ impl Separator for DosLineSeparator {
         "\r\n"
     }
 }
+
+/// Limit how many elements [`BoundedSkipError`] may skip
+pub trait SkipLimit {
+    /// Return `true` if skipping `skipped` out of `total` elements exceeds the limit.
+    fn is_exceeded(skipped: usize, total: usize) -> bool;
+
+    /// Describe the limit for error messages, e.g., "at most 3 may be skipped".
+    fn describe(formatter: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Skip at most `N` elements, see [`SkipLimit`].
+pub struct MaxSkipped<const N: usize>;
+
+impl<const N: usize> SkipLimit for MaxSkipped<N> {
+    #[inline]
+    fn is_exceeded(skipped: usize, _total: usize) -> bool {
+        skipped > N
+    }
+
+    fn describe(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "at most {N} may be skipped")
+    }
+}
+
+/// Skip at most `PERCENT` percent of all elements, see [`SkipLimit`].
+///
+/// Values above 100 behave like 100, i.e., any number of elements may be skipped.
+pub struct MaxSkippedPercent<const PERCENT: u8>;
+
+impl<const PERCENT: u8> SkipLimit for MaxSkippedPercent<PERCENT> {
+    #[inline]
+    fn is_exceeded(skipped: usize, total: usize) -> bool {
+        // u128 cannot overflow, since both factors fit into 64 bits
+        (skipped as u128) * 100 > (total as u128) * u128::from(PERCENT)
+    }
+
+    fn describe(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "at most {PERCENT}% may be skipped")
+    }
+}
+
+/// Order in which [`SortedMap`] and [`SortedSet`] serialize their elements
+///
+/// Implement this trait on your own type to use a custom comparator.
+/// Sorting by a key extracted from the element is possible by comparing the extracted keys.
+pub trait SortOrder<T: ?Sized> {
+    /// Compare two elements, or two map keys.
+    fn cmp(a: &T, b: &T) -> Ordering;
+}
+
+/// Sort in ascending order of [`Ord`], see [`SortOrder`].
+pub struct Ascending;
+
+impl<T: Ord + ?Sized> SortOrder<T> for Ascending {
+    #[inline]
+    fn cmp(a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// Sort in descending order of [`Ord`], see [`SortOrder`].
+pub struct Descending;
+
+impl<T: Ord + ?Sized> SortOrder<T> for Descending {
+    #[inline]
+    fn cmp(a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+/// Name of the field, which [`KeyValueMap`] and [`EnumMap`] use as the map key
+///
+/// Implement this trait on your own type to use the natural field name of a struct, instead of renaming it.
+/// For [`EnumMap`] the trait names the tag and content fields of the enum representation.
+///
+/// ```rust
+/// # use serde_with::formats::KeyField;
+/// struct Id;
+///
+/// impl KeyField for Id {
+///     const NAME: &'static str = "id";
+/// }
+/// # assert_eq!(<Id as KeyField>::NAME, "id");
+/// ```
+pub trait KeyField {
+    /// The field name, which is converted to and from the map key.
+    const NAME: &'static str;
+}
+
+/// Use the field named `$key$` as the map key, see [`KeyField`].
+pub struct DollarKey;
+
+impl KeyField for DollarKey {
+    const NAME: &'static str = "$key$";
+}
+
+/// Specify the representation of the enum used with [`EnumMap`]
+///
+/// The representation must match the `#[serde(tag = "...", content = "...")]` attributes of the enum.
+pub trait EnumTagging {}
+
+/// The default externally tagged enum representation, see [`EnumTagging`].
+pub struct ExternallyTagged;
+impl EnumTagging for ExternallyTagged {}
+
+/// Internally tagged enum representation with `#[serde(tag = "...")]`, see [`EnumTagging`].
+///
+/// `TAG` provides the name of the tag field.
+pub struct InternallyTagged<TAG: KeyField>(PhantomData<TAG>);
+impl<TAG: KeyField> EnumTagging for InternallyTagged<TAG> {}
+
+/// Adjacently tagged enum representation with `#[serde(tag = "...", content = "...")]`, see [`EnumTagging`].
+///
+/// `TAG` provides the name of the tag field and `CONTENT` the name of the content field.
+pub struct AdjacentlyTagged<TAG: KeyField, CONTENT: KeyField>(PhantomData<(TAG, CONTENT)>);
+impl<TAG: KeyField, CONTENT: KeyField> EnumTagging for AdjacentlyTagged<TAG, CONTENT> {}
+
+/// Rules for matching identifiers with the names expected by [`CaseInsensitive`]
+///
+/// ```rust
+/// # use serde_with::formats::IdentifierMatching;
+/// struct IgnoreDots;
+///
+/// impl IdentifierMatching for IgnoreDots {
+///     fn matches(expected: &str, input: &str) -> bool {
+///         expected.eq_ignore_ascii_case(&input.replace('.', ""))
+///     }
+/// }
+/// # assert!(IgnoreDots::matches("Active", "a.c.t.i.v.e"));
+/// ```
+pub trait IdentifierMatching {
+    /// Return `true` if the `input` identifier refers to the `expected` field or variant name.
+    fn matches(expected: &str, input: &str) -> bool;
+}
+
+/// Match identifiers while ignoring the ASCII case, see [`IdentifierMatching`].
+///
+/// `"ACTIVE"`, `"active"`, and `"Active"` all match the name `Active`.
+pub struct AsciiLowercase;
+
+impl IdentifierMatching for AsciiLowercase {
+    #[inline]
+    fn matches(expected: &str, input: &str) -> bool {
+        expected.eq_ignore_ascii_case(input)
+    }
+}
+
+/// Match identifiers while ignoring the ASCII case and the `_` and `-` word separators, see [`IdentifierMatching`].
+///
+/// The `snake_case`, `camelCase`, `PascalCase`, `kebab-case`, and `SCREAMING_SNAKE_CASE` spellings of a name all match each other.
+pub struct AnyCaseConvention;
+
+impl IdentifierMatching for AnyCaseConvention {
+    fn matches(expected: &str, input: &str) -> bool {
+        fn normalize(ident: &str) -> impl Iterator<Item = char> + '_ {
+            ident
+                .chars()
+                .filter(|&c| c != '_' && c != '-')
+                .map(|c| c.to_ascii_lowercase())
+        }
+        normalize(expected).eq(normalize(input))
+    }
+}
+
+/// Replacement, which [`Redacted`] serializes instead of a secret value
+///
+/// Implement this trait on your own type to use a custom placeholder.
+///
+/// ```rust
+/// # use serde::Serializer;
+/// # use serde_with::{formats::Mask, ser::SerializeAsWrap, Redacted, Same};
+/// struct Stars;
+///
+/// impl<T: ?Sized> Mask<T> for Stars {
+///     fn serialize_masked<S: Serializer>(_value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+///         serializer.serialize_str("***")
+///     }
+/// }
+/// # let json = serde_json::to_string(&SerializeAsWrap::<_, Redacted<Same, Stars>>::new(&1));
+/// # assert_eq!(r#""***""#, json.unwrap());
+/// ```
+pub trait Mask<T: ?Sized> {
+    /// Serialize the replacement for `value`.
+    fn serialize_masked<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// Replace any value with the fixed placeholder `[REDACTED]`, see [`Mask`].
+pub struct Placeholder;
+
+impl<T: ?Sized> Mask<T> for Placeholder {
+    fn serialize_masked<S>(_value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("[REDACTED]")
+    }
+}
+
+/// Replace a string with `****` followed by its last `N` characters, see [`Mask`].
+///
+/// Strings with fewer than `2 * N` characters are replaced by `****` alone, such that at most half of a string is revealed.
+pub struct LastChars<const N: usize>;
+
+impl<T: AsRef<str> + ?Sized, const N: usize> Mask<T> for LastChars<N> {
+    fn serialize_masked<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = value.as_ref();
+        let tail = if N > 0 && value.chars().count() >= N.saturating_mul(2) {
+            value
+                .char_indices()
+                .rev()
+                .nth(N - 1)
+                .map_or("", |(idx, _)| &value[idx..])
+        } else {
+            ""
+        };
+        serializer.collect_str(&format_args!("****{tail}"))
+    }
+}
+
+/// Replace a value with a correlation id derived from its bytes, see [`Mask`].
+///
+/// The id is the unsalted 64-bit FNV-1a hash of the bytes written as 16 lowercase hex digits.
+/// Equal values have equal ids, which allows correlating them across log entries, e.g., for user names or email addresses.
+///
+/// The hash is not cryptographic and offers no protection for secrets.
+/// Values with little entropy, like passwords or PINs, can be recovered by brute force.
+/// Never use it for passwords, keys, or tokens, but use [`Placeholder`] instead.
+pub struct CorrelationHash;
+
+impl<T: AsRef<[u8]> + ?Sized> Mask<T> for CorrelationHash {
+    fn serialize_masked<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let hash = value
+            .as_ref()
+            .iter()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            });
+        serializer.collect_str(&format_args!("{hash:016x}"))
+    }
+}
+
+/// Decide when [`NumberAsString`] serializes a number as a string
+///
+/// Deserialization is not affected and always accepts numbers and strings.
+pub trait NumberRepresentation {
+    /// Return `true` if the number should be serialized as a string.
+    ///
+    /// `is_safe` is `true` for numbers, which JavaScript represents exactly.
+    /// These are integers with a magnitude of at most 2<sup>53</sup> - 1 and finite floats.
+    fn as_string(is_safe: bool) -> bool;
+}
+
+/// Always serialize numbers as strings, see [`NumberRepresentation`].
+pub struct AlwaysString;
+
+impl NumberRepresentation for AlwaysString {
+    fn as_string(_is_safe: bool) -> bool {
+        true
+    }
+}
+
+/// Serialize only numbers as strings, which JavaScript cannot represent exactly, see [`NumberRepresentation`].
+///
+/// These are integers with a magnitude above 2<sup>53</sup> - 1 and the non-finite floats `NaN` and `inf`.
+pub struct LargeAsString;
+
+impl NumberRepresentation for LargeAsString {
+    fn as_string(is_safe: bool) -> bool {
+        !is_safe
+    }
+}
+
+/// Always serialize numbers as numbers, see [`NumberRepresentation`].
+pub struct AlwaysNumber;
+
+impl NumberRepresentation for AlwaysNumber {
+    fn as_string(_is_safe: bool) -> bool {
+        false
+    }
+}
+
+/// Rounding of floats to integral values, used by [`IntFromFloat`] and [`FloatFromInt`] in [`Flexible`] mode
+pub trait Rounding {
+    /// Round `value` to an integral value.
+    ///
+    /// Non-finite values are returned unchanged.
+    fn round(value: f64) -> f64;
+}
+
+/// Round towards zero, see [`Rounding`].
+pub struct Truncate;
+
+impl Rounding for Truncate {
+    fn round(value: f64) -> f64 {
+        trunc(value)
+    }
+}
+
+/// Round to the nearest integral value and ties away from zero, see [`Rounding`].
+pub struct Nearest;
+
+impl Rounding for Nearest {
+    fn round(value: f64) -> f64 {
+        let truncated = trunc(value);
+        let fraction = value - truncated;
+        if fraction >= 0.5 {
+            truncated + 1.0
+        } else if fraction <= -0.5 {
+            truncated - 1.0
+        } else {
+            truncated
+        }
+    }
+}
+
+/// Round towards negative infinity, see [`Rounding`].
+pub struct Floor;
+
+impl Rounding for Floor {
+    fn round(value: f64) -> f64 {
+        let truncated = trunc(value);
+        if truncated > value {
+            truncated - 1.0
+        } else {
+            truncated
+        }
+    }
+}
+
+/// Round towards positive infinity, see [`Rounding`].
+pub struct Ceil;
+
+impl Rounding for Ceil {
+    fn round(value: f64) -> f64 {
+        let truncated = trunc(value);
+        if truncated < value {
+            truncated + 1.0
+        } else {
+            truncated
+        }
+    }
+}
+
+/// Round towards zero without the `std` float functions.
+fn trunc(value: f64) -> f64 {
+    // Floats with a magnitude of at least 2^52 have no fractional part
+    const INTEGRAL: f64 = 4_503_599_627_370_496.0;
+    if -INTEGRAL < value && value < INTEGRAL {
+        value as i64 as f64
+    } else {
+        value
+    }
+}
+
+/// Handling of values outside of the target range, used by [`IntFromFloat`] and [`FloatFromInt`] in [`Flexible`] mode
+pub trait Overflow {
+    /// Clamp values to the target range, instead of returning an error.
+    const SATURATE: bool;
+}
+
+/// Clamp values to the smallest or largest value of the target type, see [`Overflow`].
+pub struct Saturate;
+
+impl Overflow for Saturate {
+    const SATURATE: bool = true;
+}
+
+/// Return an error for values outside of the target range, see [`Overflow`].
+pub struct Reject;
+
+impl Overflow for Reject {
+    const SATURATE: bool = false;
+}
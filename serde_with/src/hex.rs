@@ -15,6 +15,15 @@ use crate::prelude::*;
 /// Valid options are the types [`formats::Lowercase`] and [`formats::Uppercase`].
 /// Deserialization always supports lower- and uppercase characters, even mixed in one string.
 ///
+/// The separator type parameter specifies a [`formats::Separator`] placed between the bytes, e.g., [`formats::ColonSeparator`] for MAC addresses like `aa:bb:cc`.
+/// The default [`formats::NoSeparator`] places the bytes directly next to each other.
+/// Deserialization requires the separator between groups of bytes, but each group may contain multiple bytes.
+///
+/// The prefix type parameter specifies if the hex string starts with `0x`.
+/// Valid options are the types [`formats::Unprefixed`] and [`formats::Prefixed`].
+/// With [`formats::Prefixed`], deserialization accepts an optional `0x` or `0X` prefix.
+/// With [`formats::Unprefixed`], a prefix is rejected, since `0x` could also be the start of a hex string like `0x0a`.
+///
 /// # Example
 ///
 /// ```rust
@@ -92,52 +101,314 @@ use crate::prelude::*;
 /// let error_result: Result<ByteArray, _> =
 ///     serde_json::from_value(json!("000000000000000000000000000000")); // Too long
 /// error_result.unwrap_err();
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq, Eq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Formatted {
+///     #[serde_as(as = "serde_with::hex::Hex<Uppercase, ColonSeparator>")]
+///     mac: [u8; 6],
+///     #[serde_as(as = "serde_with::hex::Hex<Lowercase, SpaceSeparator>")]
+///     dump: Vec<u8>,
+///     #[serde_as(as = "serde_with::hex::Hex<Lowercase, NoSeparator, Prefixed>")]
+///     prefixed: Vec<u8>,
+/// }
+/// # use serde_with::formats::{ColonSeparator, Lowercase, NoSeparator, Prefixed, SpaceSeparator, Uppercase};
+///
+/// let formatted = Formatted {
+///     mac: [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e],
+///     dump: b"Hello".to_vec(),
+///     prefixed: vec![0xde, 0xad, 0xbe, 0xef],
+/// };
+/// let json = json!({
+///     "mac": "00:1A:2B:3C:4D:5E",
+///     "dump": "48 65 6c 6c 6f",
+///     "prefixed": "0xdeadbeef",
+/// });
+/// assert_eq!(json, serde_json::to_value(&formatted).unwrap());
+/// assert_eq!(formatted, serde_json::from_value(json).unwrap());
 /// # }
 /// ```
-pub struct Hex<FORMAT: formats::Format = formats::Lowercase>(PhantomData<FORMAT>);
-
-impl<T> SerializeAs<T> for Hex<formats::Lowercase>
-where
-    T: AsRef<[u8]>,
-{
-    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(&::hex::encode(source))
+pub struct Hex<
+    FORMAT: formats::Format = formats::Lowercase,
+    SEPARATOR: formats::Separator = formats::NoSeparator,
+    PREFIX: formats::Format = formats::Unprefixed,
+>(PhantomData<(FORMAT, SEPARATOR, PREFIX)>);
+
+/// Encode the bytes as hex, with `separator` between two bytes
+fn encode(bytes: &[u8], uppercase: bool, separator: &str, prefix: &str) -> String {
+    let digits: &[u8; 16] = if uppercase {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+
+    let mut encoded = String::with_capacity(prefix.len() + bytes.len() * (2 + separator.len()));
+    encoded.push_str(prefix);
+    for (idx, byte) in bytes.iter().enumerate() {
+        if idx > 0 {
+            encoded.push_str(separator);
+        }
+        encoded.push(char::from(digits[usize::from(byte >> 4)]));
+        encoded.push(char::from(digits[usize::from(byte & 0xf)]));
     }
+    encoded
 }
 
-impl<T> SerializeAs<T> for Hex<formats::Uppercase>
-where
-    T: AsRef<[u8]>,
-{
-    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(&::hex::encode_upper(source))
+/// Decode a hex string with groups of bytes delimited by `separator`
+///
+/// If `prefixed`, the string may start with a `0x` or `0X` prefix.
+fn decode<E: DeError>(value: &str, separator: &str, prefixed: bool) -> Result<Vec<u8>, E> {
+    let value = if prefixed {
+        value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+            .unwrap_or(value)
+    } else {
+        value
+    };
+    if separator.is_empty() || value.is_empty() {
+        return ::hex::decode(value).map_err(DeError::custom);
+    }
+
+    let mut bytes = Vec::new();
+    for group in value.split(separator) {
+        if group.is_empty() {
+            return Err(DeError::custom(format_args!(
+                "Invalid hex string, expected bytes between two separators {separator:?}"
+            )));
+        }
+        bytes.extend(::hex::decode(group).map_err(DeError::custom)?);
     }
+    Ok(bytes)
 }
 
-impl<'de, T, FORMAT> DeserializeAs<'de, T> for Hex<FORMAT>
-where
-    T: TryFrom<Vec<u8>>,
-    FORMAT: formats::Format,
-{
-    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        <Cow<'de, str> as Deserialize<'de>>::deserialize(deserializer)
-            .and_then(|s| ::hex::decode(&*s).map_err(DeError::custom))
-            .and_then(|vec: Vec<u8>| {
-                let length = vec.len();
-                vec.try_into().map_err(|_e: T::Error| {
-                    DeError::custom(format_args!(
-                        "Can't convert a Byte Vector of length {length} to the output type."
-                    ))
-                })
-            })
+macro_rules! hex_serialize_as {
+    ($($format:ident $uppercase:literal, $prefix:ident $prefix_str:literal;)+) => {$(
+        impl<T, SEPARATOR> SerializeAs<T> for Hex<formats::$format, SEPARATOR, formats::$prefix>
+        where
+            T: AsRef<[u8]>,
+            SEPARATOR: formats::Separator,
+        {
+            fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&encode(
+                    source.as_ref(),
+                    $uppercase,
+                    SEPARATOR::separator(),
+                    $prefix_str,
+                ))
+            }
+        }
+    )+};
+}
+hex_serialize_as!(
+    Lowercase false, Unprefixed "";
+    Uppercase true, Unprefixed "";
+    Lowercase false, Prefixed "0x";
+    Uppercase true, Prefixed "0x";
+);
+
+macro_rules! hex_deserialize_as {
+    ($($prefix:ident $prefixed:literal;)+) => {$(
+        impl<'de, T, FORMAT, SEPARATOR> DeserializeAs<'de, T> for Hex<FORMAT, SEPARATOR, formats::$prefix>
+        where
+            T: TryFrom<Vec<u8>>,
+            FORMAT: formats::Format,
+            SEPARATOR: formats::Separator,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                <Cow<'de, str> as Deserialize<'de>>::deserialize(deserializer)
+                    .and_then(|s| decode(&s, SEPARATOR::separator(), $prefixed))
+                    .and_then(|vec: Vec<u8>| {
+                        let length = vec.len();
+                        vec.try_into().map_err(|_e: T::Error| {
+                            DeError::custom(format_args!(
+                                "Can't convert a Byte Vector of length {length} to the output type."
+                            ))
+                        })
+                    })
+            }
+        }
+    )+};
+}
+hex_deserialize_as!(
+    Unprefixed false;
+    Prefixed true;
+);
+
+/// Serialize integers as a hex string
+///
+/// The type serializes an integer as a hexadecimal string, e.g., `0xff1a`.
+/// It supports all primitive integer types.
+/// Signed integers use their two's complement representation, like the [`LowerHex`](core::fmt::LowerHex) formatting, so `-1i8` serializes as `0xff`.
+///
+/// The prefix type parameter specifies if the hex string starts with `0x`.
+/// Valid options are the types [`formats::Prefixed`] and [`formats::Unprefixed`].
+/// The case type parameter specifies if the hex string should use lower- or uppercase characters.
+/// Valid options are the types [`formats::Lowercase`] and [`formats::Uppercase`].
+/// The `MIN_WIDTH` specifies the minimal number of hex digits, not counting the prefix.
+/// Shorter values are padded with leading zeros.
+///
+/// Deserialization always accepts lower- and uppercase characters and an optional `0x` or `0X` prefix.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::serde_as;
+/// use serde_with::formats::{Lowercase, Prefixed, Unprefixed, Uppercase};
+/// use serde_with::hex::HexInt;
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq, Eq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Registers {
+///     // Equivalent to HexInt<Prefixed, Lowercase>
+///     #[serde_as(as = "HexInt")]
+///     flags: u16,
+///     #[serde_as(as = "HexInt<Prefixed, Lowercase, 8>")]
+///     address: u32,
+///     #[serde_as(as = "HexInt<Unprefixed, Uppercase>")]
+///     offset: i8,
+/// }
+///
+/// let registers = Registers {
+///     flags: 0xff1a,
+///     address: 0xff1a,
+///     offset: -2,
+/// };
+/// let json = json!({
+///     "flags": "0xff1a",
+///     "address": "0x0000ff1a",
+///     "offset": "FE",
+/// });
+/// assert_eq!(json, serde_json::to_value(&registers).unwrap());
+/// assert_eq!(registers, serde_json::from_value(json).unwrap());
+///
+/// // The prefix is optional and the case does not matter during deserialization.
+/// let json = json!({
+///     "flags": "FF1A",
+///     "address": "0XFF1A",
+///     "offset": "0xfe",
+/// });
+/// assert_eq!(registers, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct HexInt<
+    PREFIX: formats::Format = formats::Prefixed,
+    CASE: formats::Format = formats::Lowercase,
+    const MIN_WIDTH: usize = 0,
+>(PhantomData<(PREFIX, CASE)>);
+
+macro_rules! hex_int {
+    ($($ty:ty => $unsigned:ty),+ $(,)?) => {$(
+        hex_int!(@ser $ty; Prefixed "0x", Lowercase "x");
+        hex_int!(@ser $ty; Prefixed "0x", Uppercase "X");
+        hex_int!(@ser $ty; Unprefixed "", Lowercase "x");
+        hex_int!(@ser $ty; Unprefixed "", Uppercase "X");
+
+        impl<'de, PREFIX, CASE, const MIN_WIDTH: usize> DeserializeAs<'de, $ty>
+            for HexInt<PREFIX, CASE, MIN_WIDTH>
+        where
+            PREFIX: formats::Format,
+            CASE: formats::Format,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct Helper;
+
+                impl Visitor<'_> for Helper {
+                    type Value = $ty;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(concat!("a hex encoded ", stringify!($ty)))
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where
+                        E: DeError,
+                    {
+                        let digits = value
+                            .strip_prefix("0x")
+                            .or_else(|| value.strip_prefix("0X"))
+                            .unwrap_or(value);
+                        // `from_str_radix` accepts a leading `+`, which is not a hex digit
+                        if !digits.starts_with(|c: char| c.is_ascii_hexdigit()) {
+                            return Err(DeError::invalid_value(Unexpected::Str(value), &self));
+                        }
+                        <$unsigned>::from_str_radix(digits, 16)
+                            // Reinterpret the bits as two's complement for signed integers
+                            .map(|unsigned| <$ty>::from_ne_bytes(unsigned.to_ne_bytes()))
+                            .map_err(|_| DeError::invalid_value(Unexpected::Str(value), &self))
+                    }
+                }
+
+                deserializer.deserialize_str(Helper)
+            }
+        }
+    )+};
+    (@ser $ty:ty; $prefix:ident $prefix_str:literal, $case:ident $case_fmt:literal) => {
+        impl<const MIN_WIDTH: usize> SerializeAs<$ty>
+            for HexInt<formats::$prefix, formats::$case, MIN_WIDTH>
+        {
+            fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_str(&format_args!(
+                    concat!($prefix_str, "{:0width$", $case_fmt, "}"),
+                    source,
+                    width = MIN_WIDTH,
+                ))
+            }
+        }
+    };
+}
+hex_int!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
+
+/// The JSON schema pattern of the strings accepted by [`Hex`]
+///
+/// Like the deserialization, it allows multiple bytes between two separators and an optional `0x` prefix if `prefixed`.
+#[cfg(any(
+    feature = "schemars_0_8",
+    feature = "schemars_0_9",
+    feature = "schemars_1"
+))]
+pub(crate) fn schema_pattern(separator: &str, prefixed: bool) -> String {
+    let prefix = if prefixed { "(?:0[xX])?" } else { "" };
+    if separator.is_empty() {
+        return alloc::format!("^{prefix}(?:[0-9A-Fa-f]{{2}})*$");
+    }
+
+    let mut escaped = String::new();
+    for c in separator.chars() {
+        if "\\.+*?()|[]{}^$-/".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
     }
+    alloc::format!("^{prefix}(?:(?:[0-9A-Fa-f]{{2}})+(?:{escaped}(?:[0-9A-Fa-f]{{2}})+)*)?$")
 }
@@ -0,0 +1,268 @@
+use crate::prelude::*;
+
+/// Enums without fields, which are represented by their discriminant
+///
+/// The trait is used by [`EnumAsInt`] and should be implemented with the [`IntEnum`](macro@crate::IntEnum) derive macro.
+/// It connects each variant with its name and its discriminant.
+pub trait IntEnum: Sized {
+    /// Integer type of the discriminants
+    ///
+    /// The derive macro uses the type of the `#[repr(...)]` attribute and `isize` otherwise.
+    type Repr: Copy
+        + Display
+        + Serialize
+        + DeserializeOwned
+        + TryFrom<i64>
+        + TryFrom<u64>
+        + TryFrom<i128>
+        + TryFrom<u128>
+        + TryInto<i128>
+        + TryInto<u128>
+        + 'static;
+
+    /// Names and discriminants of all variants
+    const VARIANTS: &'static [(&'static str, Self::Repr)];
+
+    /// Whether unknown discriminants and names turn into a fallback variant
+    const HAS_FALLBACK: bool;
+
+    /// Return the discriminant of the variant.
+    fn to_repr(&self) -> Self::Repr;
+
+    /// Return the variant with the discriminant, or the fallback variant.
+    fn from_repr(value: Self::Repr) -> Option<Self>;
+
+    /// Return the variant with the name, or the fallback variant.
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+/// De/Serialize enums without fields as their integer discriminant
+///
+/// Many wire protocols encode enums as integers instead of their variant names.
+/// This adapter serializes the discriminant of the enum, which needs to implement [`IntEnum`].
+/// The [`IntEnum`](macro@crate::IntEnum) derive macro implements the trait for enums without fields.
+/// The discriminant has the type of the `#[repr(...)]` attribute and `isize` if the attribute is missing.
+///
+/// A variant marked with `#[serde_with(other)]` becomes the fallback variant, which is used for unknown discriminants.
+/// Without a fallback variant unknown discriminants are an error.
+///
+/// The `STRICTNESS` parameter controls which values are accepted during deserialization.
+///
+/// * [`Strict`] (default): Only accept the integer discriminants.
+/// * [`Flexible`]: Additionally, accept the variant names as strings.
+///   This requires a self-describing data format.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{formats::Flexible, serde_as, EnumAsInt, IntEnum};
+///
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(IntEnum)]
+/// #[repr(u8)]
+/// enum Status {
+///     Active = 1,
+///     Suspended = 2,
+///     Deleted = 4,
+///     #[serde_with(other)]
+///     Unknown = 255,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "EnumAsInt")]
+///     status: Status,
+///     #[serde_as(as = "Vec<EnumAsInt<Flexible>>")]
+///     history: Vec<Status>,
+/// }
+///
+/// let data = Data {
+///     status: Status::Suspended,
+///     history: vec![Status::Active, Status::Suspended],
+/// };
+/// assert_eq!(json!({"status": 2, "history": [1, 2]}), serde_json::to_value(&data).unwrap());
+///
+/// // Flexible also accepts the variant names and unknown values use the fallback variant
+/// let json = json!({"status": 9, "history": ["Active", 4, "Archived"]});
+/// assert_eq!(
+///     Data {
+///         status: Status::Unknown,
+///         history: vec![Status::Active, Status::Deleted, Status::Unknown],
+///     },
+///     serde_json::from_value(json).unwrap(),
+/// );
+/// # }
+/// ```
+///
+/// [`Flexible`]: crate::formats::Flexible
+/// [`Strict`]: crate::formats::Strict
+pub struct EnumAsInt<STRICTNESS: formats::Strictness = formats::Strict>(PhantomData<STRICTNESS>);
+
+impl<T, STRICTNESS> SerializeAs<T> for EnumAsInt<STRICTNESS>
+where
+    T: IntEnum,
+    STRICTNESS: formats::Strictness,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        source.to_repr().serialize(serializer)
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for EnumAsInt<formats::Strict>
+where
+    T: IntEnum,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::Repr::deserialize(deserializer)?;
+        T::from_repr(value).ok_or_else(|| {
+            let mut buf = [0u8; 58];
+            DeError::invalid_value(
+                unexpected_repr(value, &mut buf),
+                &ExpectedVariants::<T> {
+                    with_names: false,
+                    phantom: PhantomData,
+                },
+            )
+        })
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for EnumAsInt<formats::Flexible>
+where
+    T: IntEnum,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<T>(PhantomData<T>);
+
+        impl<T> Helper<T>
+        where
+            T: IntEnum,
+        {
+            fn visit_int<I, E>(self, value: I, unexp: Unexpected<'_>) -> Result<T, E>
+            where
+                T::Repr: TryFrom<I>,
+                E: DeError,
+            {
+                T::Repr::try_from(value)
+                    .ok()
+                    .and_then(T::from_repr)
+                    .ok_or_else(|| DeError::invalid_value(unexp, &self))
+            }
+        }
+
+        impl<T> Visitor<'_> for Helper<T>
+        where
+            T: IntEnum,
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                ExpectedVariants::<T> {
+                    with_names: true,
+                    phantom: PhantomData,
+                }
+                .fmt(formatter)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                self.visit_int(v, Unexpected::Signed(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                self.visit_int(v, Unexpected::Unsigned(v))
+            }
+
+            fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                let mut buf = [0u8; 58];
+                self.visit_int(v, utils::get_unexpected_i128(v, &mut buf))
+            }
+
+            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                let mut buf = [0u8; 58];
+                self.visit_int(v, utils::get_unexpected_u128(v, &mut buf))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                T::from_name(v).ok_or_else(|| DeError::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(Helper(PhantomData))
+    }
+}
+
+/// Describe a discriminant, which does not belong to any variant.
+fn unexpected_repr<R>(value: R, buf: &mut [u8; 58]) -> Unexpected<'_>
+where
+    R: Copy + TryInto<i128> + TryInto<u128>,
+{
+    if let Ok(value) = TryInto::<u128>::try_into(value) {
+        u64::try_from(value).map_or_else(
+            |_| utils::get_unexpected_u128(value, buf),
+            Unexpected::Unsigned,
+        )
+    } else if let Ok(value) = TryInto::<i128>::try_into(value) {
+        i64::try_from(value).map_or_else(
+            |_| utils::get_unexpected_i128(value, buf),
+            Unexpected::Signed,
+        )
+    } else {
+        Unexpected::Other("integer")
+    }
+}
+
+/// List the discriminants and optionally the names of all variants.
+struct ExpectedVariants<T> {
+    with_names: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Expected for ExpectedVariants<T>
+where
+    T: IntEnum,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("one of")?;
+        for (idx, (_, value)) in T::VARIANTS.iter().enumerate() {
+            let sep = if idx == 0 { " " } else { ", " };
+            write!(formatter, "{sep}`{value}`")?;
+        }
+        if self.with_names {
+            formatter.write_str(" or")?;
+            for (idx, (name, _)) in T::VARIANTS.iter().enumerate() {
+                let sep = if idx == 0 { " " } else { ", " };
+                write!(formatter, "{sep}`{name}`")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -14,7 +14,7 @@
 use crate::{
     formats::{Flexible, Format, Strict, Strictness},
     prelude::*,
-    utils::duration::{use_duration_signed_de, use_duration_signed_ser},
+    utils::duration::{use_duration_signed_de, use_duration_signed_ser, use_duration_str},
 };
 use ::jiff_0_2::{
     civil::DateTime as CivilDateTime, tz::TimeZone, SignedDuration, Timestamp, Zoned,
@@ -262,3 +262,8 @@ use_duration_signed_de!(
         #[cfg(feature = "alloc")] {String, Strict =>}
     }
 );
+
+use_duration_str!(
+    Iso8601Duration, HumanDuration
+    => SignedDuration; duration_into_duration_signed, duration_from_duration_signed
+);
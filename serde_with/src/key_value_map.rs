@@ -1,6 +1,6 @@
 use crate::{
     content::{
-        de::{Content as DeContent, ContentDeserializer},
+        de::{Content as DeContent, ContentDeserializer, ContentRefDeserializer},
         ser::{Content as SerContent, ContentSerializer},
     },
     prelude::*,
@@ -16,6 +16,7 @@ use crate::{
 /// Structs need a field that is named `$key$` to be used as the map key.
 /// This can be done with the `#[serde(rename = "$key$")]` attribute.
 /// Maps similarly need a map-key that is named `$key$`.
+/// A different field name can be chosen with the second type parameter, see [`KeyField`].
 /// For tuples, tuple structs, and sequences the first element is used as the map key.
 ///
 /// # Examples
@@ -154,36 +155,178 @@ use crate::{
 /// assert_eq!(values, deserialized);
 /// # }
 /// ```
-pub struct KeyValueMap<T>(PhantomData<T>);
+///
+/// ## Custom key field name
+///
+/// Renaming the field to `$key$` also affects other serializations of the struct.
+/// Instead, the field name can be specified with a type implementing [`KeyField`], such that the struct keeps its natural field name.
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{formats::KeyField, serde_as, KeyValueMap};
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     id: String,
+///     age: u8,
+/// }
+///
+/// struct Id;
+/// impl KeyField for Id {
+///     const NAME: &'static str = "id";
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Users(#[serde_as(as = "KeyValueMap<_, Id>")] Vec<User>);
+///
+/// let users = Users(vec![User {
+///     id: "alice".to_string(),
+///     age: 30,
+/// }]);
+/// let json = r#"{"alice":{"age":30}}"#;
+/// assert_eq!(json, serde_json::to_string(&users).unwrap());
+/// assert_eq!(users, serde_json::from_str(json).unwrap());
+///
+/// // The struct itself still uses the `id` field
+/// assert_eq!(
+///     r#"{"id":"alice","age":30}"#,
+///     serde_json::to_string(&users.0[0]).unwrap(),
+/// );
+/// # }
+/// ```
+///
+/// ## Maps and sets
+///
+/// Besides `Vec`, the values can also be stored in maps and sets.
+/// For maps, the map key is deserialized a second time into the key type of the map, while the value still contains the `$key$` field.
+/// During serialization, only the values are used and the keys of the map are ignored.
+///
+/// Duplicate map keys overwrite earlier entries in maps and are ignored in sets, like the default serde behavior.
+/// [`KeyValueMapPreventDuplicates`] rejects duplicates, while [`KeyValueMapFirstKeyWins`] keeps the first entry in maps.
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, KeyValueMap};
+/// use std::collections::BTreeMap;
+///
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Server {
+///     #[serde(rename = "$key$")]
+///     name: String,
+///     port: u16,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde_as(as = "KeyValueMap<_>")]
+///     servers: BTreeMap<String, Server>,
+/// }
+///
+/// let json = r#"{"servers":{"db":{"port":5432},"web":{"port":80}}}"#;
+/// let config: Config = serde_json::from_str(json).unwrap();
+/// assert_eq!(5432, config.servers["db"].port);
+/// // The values still contain their key
+/// assert_eq!("web", config.servers["web"].name);
+/// assert_eq!(json, serde_json::to_string(&config).unwrap());
+/// # }
+/// ```
+///
+/// [`KeyField`]: crate::formats::KeyField
+pub struct KeyValueMap<T, KEY: formats::KeyField = formats::DollarKey>(PhantomData<(T, KEY)>);
 
-impl<T, TAs> SerializeAs<Vec<T>> for KeyValueMap<TAs>
+/// Like [`KeyValueMap`], but error on duplicate keys
+///
+/// This returns an error if the same map key appears twice when deserializing into a map, or if two equal values are deserialized into a set.
+/// Serialization is identical to [`KeyValueMap`].
+///
+/// The implementation supports all maps and sets, but not `Vec`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, KeyValueMapPreventDuplicates};
+/// use std::collections::HashMap;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Server {
+///     #[serde(rename = "$key$")]
+///     name: String,
+///     port: u16,
+/// }
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde_as(as = "KeyValueMapPreventDuplicates<_>")]
+///     servers: HashMap<String, Server>,
+/// }
+///
+/// let json = r#"{"servers":{"db":{"port":5432},"db":{"port":80}}}"#;
+/// assert!(serde_json::from_str::<Config>(json).is_err());
+/// # }
+/// ```
+pub struct KeyValueMapPreventDuplicates<T, KEY: formats::KeyField = formats::DollarKey>(
+    PhantomData<(T, KEY)>,
+);
+
+/// Like [`KeyValueMap`], but keep the first entry of duplicate keys
+///
+/// By default, later entries overwrite earlier entries with the same key, when deserializing into a map.
+/// This helper keeps the first entry instead.
+/// Serialization is identical to [`KeyValueMap`].
+///
+/// The implementation supports all maps, but neither sets nor `Vec`.
+pub struct KeyValueMapFirstKeyWins<T, KEY: formats::KeyField = formats::DollarKey>(
+    PhantomData<(T, KEY)>,
+);
+
+impl<T, TAs, KEY> SerializeAs<Vec<T>> for KeyValueMap<TAs, KEY>
 where
     TAs: SerializeAs<T>,
+    KEY: formats::KeyField,
 {
     fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        <Vec<TAs>>::serialize_as(source, SeqAsMapSerializer(serializer))
+        <Vec<TAs>>::serialize_as(
+            source,
+            SeqAsMapSerializer {
+                delegate: serializer,
+                key_field: KEY::NAME,
+            },
+        )
     }
 }
 
-impl<'de, T, TAs> DeserializeAs<'de, Vec<T>> for KeyValueMap<TAs>
+impl<'de, T, TAs, KEY> DeserializeAs<'de, Vec<T>> for KeyValueMap<TAs, KEY>
 where
     TAs: DeserializeAs<'de, T>,
+    KEY: formats::KeyField,
 {
     fn deserialize_as<D>(deserializer: D) -> Result<Vec<T>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct KeyValueMapVisitor<T, TAs> {
+        struct KeyValueMapVisitor<T, TAs, KEY> {
             is_human_readable: bool,
-            phantom: PhantomData<(T, TAs)>,
+            phantom: PhantomData<(T, TAs, KEY)>,
         }
 
-        impl<'de, T, TAs> Visitor<'de> for KeyValueMapVisitor<T, TAs>
+        impl<'de, T, TAs, KEY> Visitor<'de> for KeyValueMapVisitor<T, TAs, KEY>
         where
             TAs: DeserializeAs<'de, T>,
+            KEY: formats::KeyField,
         {
             type Value = Vec<T>;
 
@@ -195,30 +338,104 @@ where
                 <Vec<TAs>>::deserialize_as(SeqDeserializer {
                     delegate: map,
                     is_human_readable: self.is_human_readable,
+                    key_field: KEY::NAME,
                 })
             }
         }
 
         let is_human_readable = deserializer.is_human_readable();
-        deserializer.deserialize_map(KeyValueMapVisitor::<T, TAs> {
+        deserializer.deserialize_map(KeyValueMapVisitor::<T, TAs, KEY> {
             is_human_readable,
             phantom: PhantomData,
         })
     }
 }
 
-// TODO Replace this with a const generic string once adt_const_params is stable.
-// This will allow something like this.
-// The `"id"` part is the field name, which gets converted to/from the map key.
-// #[serde_as(as = r#"KeyValueMap<"id", _>"#)]
-// Vec<SimpleStruct>,
-static MAP_KEY_IDENTIFIER: &str = "$key$";
+/// Serialize the values as map entries, each value providing its own map key.
+pub(crate) fn serialize_values<'a, S, T, TAs>(
+    values: impl Iterator<Item = &'a T>,
+    key_field: &'static str,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: 'a,
+    TAs: SerializeAs<T>,
+{
+    SeqAsMapSerializer {
+        delegate: serializer,
+        key_field,
+    }
+    .collect_seq(values.map(SerializeAsWrap::<T, TAs>::new))
+}
+
+/// Deserialize all map entries into values of type `T`, which contain the map key.
+///
+/// Each map key is additionally deserialized into a `K`, such that the values can be stored in a map.
+pub(crate) fn deserialize_entries<'de, D, K, T, TAs>(
+    deserializer: D,
+    key_field: &'static str,
+) -> Result<Vec<(K, T)>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de>,
+    TAs: DeserializeAs<'de, T>,
+{
+    struct EntriesVisitor<K, T, TAs> {
+        is_human_readable: bool,
+        key_field: &'static str,
+        phantom: PhantomData<(K, T, TAs)>,
+    }
+
+    impl<'de, K, T, TAs> Visitor<'de> for EntriesVisitor<K, T, TAs>
+    where
+        K: Deserialize<'de>,
+        TAs: DeserializeAs<'de, T>,
+    {
+        type Value = Vec<(K, T)>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut entries = utils::vec_with_capacity_cautious(map.size_hint());
+            while let Some(key_value) = map.next_key::<DeContent<'de>>()? {
+                let key = K::deserialize(ContentRefDeserializer::<A::Error>::new(
+                    &key_value,
+                    self.is_human_readable,
+                ))?;
+                let value = DeserializeAsWrap::<T, TAs>::deserialize(MapKeyDeserializer {
+                    delegate: &mut map,
+                    is_human_readable: self.is_human_readable,
+                    key_field: self.key_field,
+                    key_value,
+                })?;
+                entries.push((key, value.into_inner()));
+            }
+            Ok(entries)
+        }
+    }
+
+    let is_human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(EntriesVisitor::<K, T, TAs> {
+        is_human_readable,
+        key_field,
+        phantom: PhantomData,
+    })
+}
 
 /// Convert a sequence to a map during serialization.
 ///
 /// Only `serialize_seq` is implemented and forwarded to `serialize_map` on the inner `Serializer`.
 /// The elements are serialized with [`SerializeSeqElement`].
-struct SeqAsMapSerializer<S>(S);
+struct SeqAsMapSerializer<S> {
+    delegate: S,
+    key_field: &'static str,
+}
 
 impl<S> Serializer for SeqAsMapSerializer<S>
 where
@@ -352,12 +569,13 @@ where
     }
 
     fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        let is_human_readable = self.0.is_human_readable();
-        self.0
+        let is_human_readable = self.delegate.is_human_readable();
+        self.delegate
             .serialize_map(len)
             .map(|delegate| SerializeSeqElement {
                 delegate,
                 is_human_readable,
+                key_field: self.key_field,
             })
     }
 
@@ -406,7 +624,7 @@ where
     }
 
     fn is_human_readable(&self) -> bool {
-        self.0.is_human_readable()
+        self.delegate.is_human_readable()
     }
 }
 
@@ -418,6 +636,7 @@ where
 struct SerializeSeqElement<M> {
     delegate: M,
     is_human_readable: bool,
+    key_field: &'static str,
 }
 
 impl<M> SerializeSeq for SerializeSeqElement<M>
@@ -434,6 +653,7 @@ where
         value.serialize(ElementAsKeyValueSerializer {
             delegate: &mut self.delegate,
             is_human_readable: self.is_human_readable,
+            key_field: self.key_field,
         })?;
         Ok(())
     }
@@ -446,6 +666,7 @@ where
 struct ElementAsKeyValueSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
+    key_field: &'static str,
 }
 
 impl<'a, M> Serializer for ElementAsKeyValueSerializer<'a, M>
@@ -625,6 +846,7 @@ where
         Ok(KeyValueMapSerializer {
             delegate: self.delegate,
             is_human_readable: self.is_human_readable,
+            key_field: self.key_field,
             content: utils::vec_with_capacity_cautious(Some(len.unwrap_or(17).saturating_sub(1))),
             next_is_magic_key: false,
             key: None,
@@ -640,6 +862,7 @@ where
         Ok(KeyValueStructSerializer {
             delegate: self.delegate,
             is_human_readable: self.is_human_readable,
+            key_field: self.key_field,
             name,
             content: utils::vec_with_capacity_cautious(Some(len.saturating_sub(1))),
             key: None,
@@ -786,10 +1009,11 @@ where
 
 /// Serialize a map to a key and value pair of a map.
 ///
-/// This requires that the map has one element which serializes using the magic `$key$` key.
+/// This requires that the map has one element which serializes using the configured key field, `$key$` by default.
 struct KeyValueMapSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
+    key_field: &'static str,
     content: Vec<(SerContent, SerContent)>,
     next_is_magic_key: bool,
     key: Option<SerContent>,
@@ -808,7 +1032,7 @@ where
         T: Serialize + ?Sized,
     {
         let key: SerContent = key.serialize(ContentSerializer::new(self.is_human_readable))?;
-        if key.as_str() == Some(MAP_KEY_IDENTIFIER) {
+        if key.as_str() == Some(self.key_field) {
             self.next_is_magic_key = true;
             return Ok(());
         }
@@ -841,17 +1065,21 @@ where
             self.delegate
                 .serialize_entry(&key, &SerContent::Map(self.content))
         } else {
-            Err(SerError::custom("missing value for `$key$` field"))
+            Err(SerError::custom(format_args!(
+                "missing value for `{}` field",
+                self.key_field
+            )))
         }
     }
 }
 
 /// Serialize a struct to a key and value pair of a map.
 ///
-/// This requires that the struct has one field named `$key$`.
+/// This requires that the struct has one field named like the configured key field, `$key$` by default.
 struct KeyValueStructSerializer<'a, M> {
     delegate: &'a mut M,
     is_human_readable: bool,
+    key_field: &'static str,
     name: &'static str,
     content: Vec<(&'static str, SerContent)>,
     key: Option<SerContent>,
@@ -871,7 +1099,7 @@ where
         // Serialize to a Content type first
         let value: SerContent = value.serialize(ContentSerializer::new(self.is_human_readable))?;
 
-        if key == MAP_KEY_IDENTIFIER {
+        if key == self.key_field {
             self.key = Some(value);
             return Ok(());
         }
@@ -884,7 +1112,10 @@ where
             self.delegate
                 .serialize_entry(&key, &SerContent::Struct(self.name, self.content))
         } else {
-            Err(SerError::custom("missing value for `$key$` field"))
+            Err(SerError::custom(format_args!(
+                "missing value for `{}` field",
+                self.key_field
+            )))
         }
     }
 }
@@ -897,6 +1128,7 @@ where
 struct SeqDeserializer<M> {
     delegate: M,
     is_human_readable: bool,
+    key_field: &'static str,
 }
 
 impl<'de, M> Deserializer<'de> for SeqDeserializer<M>
@@ -945,6 +1177,7 @@ where
             seed.deserialize(MapKeyDeserializer {
                 delegate: &mut self.delegate,
                 is_human_readable: self.is_human_readable,
+                key_field: self.key_field,
                 key_value,
             })
             .map(Some)
@@ -961,6 +1194,7 @@ where
 struct MapKeyDeserializer<'de, M> {
     delegate: M,
     is_human_readable: bool,
+    key_field: &'static str,
     key_value: DeContent<'de>,
 }
 
@@ -984,6 +1218,7 @@ where
         self.delegate.next_value_seed(KeyValueSeqDeserialize {
             delegate: visitor,
             first: Some(self.key_value),
+            key_field: self.key_field,
         })
     }
 
@@ -995,6 +1230,7 @@ where
             delegate: visitor,
             len,
             first: Some(self.key_value),
+            key_field: self.key_field,
         })
     }
 
@@ -1013,6 +1249,7 @@ where
                 name,
                 len,
                 first: Some(self.key_value),
+                key_field: self.key_field,
             })
     }
 
@@ -1023,6 +1260,7 @@ where
         self.delegate.next_value_seed(KeyValueMapDeserialize {
             delegate: visitor,
             first: Some(self.key_value),
+            key_field: self.key_field,
         })
     }
 
@@ -1040,6 +1278,7 @@ where
             name,
             fields,
             first: Some(self.key_value),
+            key_field: self.key_field,
         })
     }
 
@@ -1056,6 +1295,7 @@ where
 
 struct KeyValueSeqDeserialize<'de, V> {
     delegate: V,
+    key_field: &'static str,
     first: Option<DeContent<'de>>,
 }
 
@@ -1074,12 +1314,14 @@ where
             delegate: self.delegate,
             is_human_readable,
             first: self.first.take(),
+            key_field: self.key_field,
         })
     }
 }
 
 struct KeyValueTupleDeserialize<'de, V> {
     delegate: V,
+    key_field: &'static str,
     len: usize,
     first: Option<DeContent<'de>>,
 }
@@ -1101,6 +1343,7 @@ where
                 delegate: self.delegate,
                 is_human_readable,
                 first: self.first.take(),
+                key_field: self.key_field,
             },
         )
     }
@@ -1108,6 +1351,7 @@ where
 
 struct KeyValueTupleStructDeserialize<'de, V> {
     delegate: V,
+    key_field: &'static str,
     name: &'static str,
     len: usize,
     first: Option<DeContent<'de>>,
@@ -1131,6 +1375,7 @@ where
                 delegate: self.delegate,
                 is_human_readable,
                 first: self.first.take(),
+                key_field: self.key_field,
             },
         )
     }
@@ -1138,6 +1383,7 @@ where
 
 struct KeyValueMapDeserialize<'de, V> {
     delegate: V,
+    key_field: &'static str,
     first: Option<DeContent<'de>>,
 }
 
@@ -1156,12 +1402,14 @@ where
             delegate: self.delegate,
             is_human_readable,
             first: self.first.take(),
+            key_field: self.key_field,
         })
     }
 }
 
 struct KeyValueStructDeserialize<'de, V> {
     delegate: V,
+    key_field: &'static str,
     name: &'static str,
     fields: &'static [&'static str],
     first: Option<DeContent<'de>>,
@@ -1185,6 +1433,7 @@ where
                 delegate: self.delegate,
                 is_human_readable,
                 first: self.first.take(),
+                key_field: self.key_field,
             },
         )
     }
@@ -1193,6 +1442,7 @@ where
 struct VisitorWrapper<'de, V> {
     delegate: V,
     is_human_readable: bool,
+    key_field: &'static str,
     first: Option<DeContent<'de>>,
 }
 
@@ -1225,6 +1475,7 @@ where
             delegate: map,
             is_human_readable: self.is_human_readable,
             first: self.first,
+            key_field: self.key_field,
         })
     }
 }
@@ -1232,6 +1483,7 @@ where
 struct MapAccessWrapper<'de, M> {
     delegate: M,
     is_human_readable: bool,
+    key_field: &'static str,
     first: Option<DeContent<'de>>,
 }
 
@@ -1247,7 +1499,7 @@ where
     {
         if self.first.is_some() {
             seed.deserialize(serde_core::de::value::StringDeserializer::new(
-                MAP_KEY_IDENTIFIER.to_string(),
+                self.key_field.to_string(),
             ))
             .map(Some)
         } else {
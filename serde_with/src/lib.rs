@@ -272,12 +272,18 @@ extern crate serde_core;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "base32")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base32")))]
+pub mod base32;
 #[cfg(feature = "base58")]
 #[cfg_attr(docsrs, doc(cfg(feature = "base58")))]
 pub mod base58;
 #[cfg(feature = "base64")]
 #[cfg_attr(docsrs, doc(cfg(feature = "base64")))]
 pub mod base64;
+#[cfg(feature = "base85")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base85")))]
+pub mod base85;
 #[cfg(feature = "chrono_0_4")]
 #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
 pub mod chrono_0_4;
@@ -288,6 +294,7 @@ pub mod chrono {
     pub use crate::chrono_0_4::*;
     pub use chrono_0_4::*;
 }
+mod case_insensitive;
 #[cfg(feature = "alloc")]
 mod content;
 pub mod de;
@@ -303,6 +310,7 @@ pub mod formats;
 #[cfg(feature = "hex")]
 #[cfg_attr(docsrs, doc(cfg(feature = "hex")))]
 pub mod hex;
+mod int_enum;
 #[cfg(feature = "jiff_0_2")]
 #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
 pub mod jiff_0_2;
@@ -311,6 +319,10 @@ pub mod jiff_0_2;
 pub mod json;
 #[cfg(feature = "alloc")]
 mod key_value_map;
+#[cfg(feature = "alloc")]
+mod normalize;
+mod number_as_string;
+mod redacted;
 pub mod rust;
 #[cfg(feature = "schemars_0_8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "schemars_0_8")))]
@@ -326,7 +338,13 @@ mod serde_conv;
 #[cfg(feature = "time_0_3")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
 pub mod time_0_3;
+#[cfg(feature = "alloc")]
+mod unknown_variant;
 mod utils;
+mod validate;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod value;
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub mod with_prefix;
@@ -440,12 +458,32 @@ pub mod __private__ {
     pub use crate::prelude::*;
 }
 
+#[doc(inline)]
+pub use crate::case_insensitive::CaseInsensitive;
 #[cfg(feature = "alloc")]
 #[doc(inline)]
 pub use crate::enum_map::EnumMap;
+#[doc(inline)]
+pub use crate::int_enum::{EnumAsInt, IntEnum};
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::key_value_map::{
+    KeyValueMap, KeyValueMapFirstKeyWins, KeyValueMapPreventDuplicates,
+};
 #[cfg(feature = "alloc")]
 #[doc(inline)]
-pub use crate::key_value_map::KeyValueMap;
+pub use crate::normalize::{Lowercased, Trimmed};
+#[doc(inline)]
+pub use crate::number_as_string::{Number, NumberAsString};
+#[doc(inline)]
+pub use crate::redacted::Redacted;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use crate::unknown_variant::{CatchUnknownVariant, UnknownVariant};
+#[doc(inline)]
+pub use crate::utils::{Float, Integer};
+#[doc(inline)]
+pub use crate::validate::{Bounded, BoundedNumber, HasLength, Length, NonEmpty};
 #[doc(inline)]
 pub use crate::{de::DeserializeAs, ser::SerializeAs};
 use core::marker::PhantomData;
@@ -1220,6 +1258,142 @@ pub struct DurationNanoSecondsWithFrac<
     STRICTNESS: formats::Strictness = formats::Strict,
 >(PhantomData<(FORMAT, STRICTNESS)>);
 
+/// De/Serialize Durations as ISO 8601 duration strings.
+///
+/// De/serialize durations in the ISO 8601 duration format, e.g., `PT1H30M12.5S`.
+/// This is the format used by Java's `java.time.Duration`, .NET's `XmlConvert`, and XML Schema.
+/// Serialization always emits hours, minutes, and seconds, with a leading `-` for negative durations, e.g., `-PT1M30S`.
+/// Zero components are omitted, except for the zero duration which serializes as `PT0S`.
+///
+/// Years and months are rejected during deserialization, since they do not have a fixed length.
+///
+/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// [`formats::Strict`] only accepts strings following the ISO 8601 grammar: uppercase designators, weeks not combined with other units, and a fraction only on the last component.
+/// [`formats::Flexible`] additionally accepts lowercase designators, signs on individual components as emitted by Java (`PT-1M-30S`), weeks combined with days, and numbers or numeric strings, which are interpreted as seconds.
+///
+/// This type also supports [`chrono::Duration`] with the `chrono_0_4`-[feature flag].
+/// This type also supports [`jiff::SignedDuration`][::jiff_0_2::SignedDuration] with the `jiff_0_2`-[feature flag].
+/// This type also supports [`time::Duration`][::time_0_3::Duration] with the `time_0_3`-[feature flag].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{serde_as, Iso8601Duration};
+/// use serde_with::formats::Flexible;
+/// use std::time::Duration;
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Timeouts {
+///     #[serde_as(as = "Iso8601Duration")]
+///     connect: Duration,
+///     #[serde_as(as = "Iso8601Duration<Flexible>")]
+///     read: Duration,
+/// }
+///
+/// let timeouts = Timeouts {
+///     connect: Duration::new(5412, 500_000_000),
+///     read: Duration::from_secs(90),
+/// };
+/// let expected = json!({
+///     "connect": "PT1H30M12.5S",
+///     "read": "PT1M30S",
+/// });
+/// assert_eq!(expected, serde_json::to_value(&timeouts).unwrap());
+/// assert_eq!(timeouts, serde_json::from_value(expected).unwrap());
+///
+/// // Days and weeks are supported, too.
+/// // The flexible variant also accepts plain numbers as seconds.
+/// let json = json!({
+///     "connect": "P1DT2H",
+///     "read": 90,
+/// });
+/// let expected = Timeouts {
+///     connect: Duration::from_secs(26 * 3600),
+///     read: Duration::from_secs(90),
+/// };
+/// assert_eq!(expected, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`chrono::Duration`]: ::chrono_0_4::Duration
+/// [feature flag]: https://docs.rs/serde_with/3.21.0/serde_with/guide/feature_flags/index.html
+pub struct Iso8601Duration<STRICTNESS: formats::Strictness = formats::Strict>(
+    PhantomData<STRICTNESS>,
+);
+
+/// De/serialize durations in a compact human-readable form, e.g., `1h30m5s` or `250ms`.
+///
+/// This format is convenient for hand-edited configuration files.
+/// A duration consists of one or more components, each a number followed by a unit.
+/// The supported units are `w` (weeks), `d` (days), `h` (hours), `m` (minutes), `s` (seconds), `ms` (milliseconds), `us` (microseconds), and `ns` (nanoseconds).
+/// Numbers may have a fraction, e.g., `1.5h`, and whitespace is allowed between the components and between number and unit, e.g., `1h 30m` or `5 s`.
+/// Negative durations are prefixed with `-`.
+///
+/// Serialization emits the shortest canonical form without whitespace.
+/// Durations of at least one second are split into weeks, days, hours, minutes, and seconds with an optional fraction, e.g., `1d2h0.5s`.
+/// Shorter durations use the largest fitting sub-second unit, e.g., `250ms` or `1.5us`.
+/// The zero duration serializes as `0s`.
+///
+/// The `STRICTNESS` specifier can either be [`formats::Strict`] or [`formats::Flexible`] and defaults to [`formats::Strict`].
+/// [`formats::Strict`] only accepts lowercase units, with each unit appearing at most once and in descending order.
+/// [`formats::Flexible`] additionally accepts units in any case, `µs` for microseconds, surrounding whitespace, components in any order, and numbers or numeric strings, which are interpreted as seconds.
+///
+/// This type also supports [`chrono::Duration`] with the `chrono_0_4`-[feature flag].
+/// This type also supports [`jiff::SignedDuration`][::jiff_0_2::SignedDuration] with the `jiff_0_2`-[feature flag].
+/// This type also supports [`time::Duration`][::time_0_3::Duration] with the `time_0_3`-[feature flag].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{serde_as, HumanDuration};
+/// use serde_with::formats::Flexible;
+/// use std::time::Duration;
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Timeouts {
+///     #[serde_as(as = "HumanDuration")]
+///     connect: Duration,
+///     #[serde_as(as = "HumanDuration<Flexible>")]
+///     read: Duration,
+/// }
+///
+/// let timeouts = Timeouts {
+///     connect: Duration::from_secs(5405),
+///     read: Duration::from_millis(250),
+/// };
+/// let expected = json!({
+///     "connect": "1h30m5s",
+///     "read": "250ms",
+/// });
+/// assert_eq!(expected, serde_json::to_value(&timeouts).unwrap());
+/// assert_eq!(timeouts, serde_json::from_value(expected).unwrap());
+///
+/// // Whitespace between the components is allowed.
+/// // The flexible variant also accepts plain numbers as seconds.
+/// let json = json!({
+///     "connect": "1h 30m 5s",
+///     "read": 0.25,
+/// });
+/// assert_eq!(timeouts, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`chrono::Duration`]: ::chrono_0_4::Duration
+/// [feature flag]: https://docs.rs/serde_with/3.21.0/serde_with/guide/feature_flags/index.html
+pub struct HumanDuration<STRICTNESS: formats::Strictness = formats::Strict>(
+    PhantomData<STRICTNESS>,
+);
+
 /// De/Serialize timestamps as seconds since the UNIX epoch
 ///
 /// De/serialize timestamps as seconds since the UNIX epoch.
@@ -1726,6 +1900,107 @@ pub struct TimestampNanoSecondsWithFrac<
 /// ```
 pub struct Bytes;
 
+/// Serialize integers as big-endian bytes
+///
+/// Integers are converted into a byte array with their most significant byte first.
+/// The byte order is independent of the platform.
+/// Comparing the encoded bytes lexicographically matches the numeric order of unsigned integers.
+/// Signed integers use the two's complement, so negative values sort after all positive values.
+/// This is useful for binary formats, like bincode, postcard, or CBOR, which should have a stable wire layout.
+/// All primitive integers and the [`NonZero`](core::num) types are supported.
+/// `usize` and `isize` use the platform dependent size of the type.
+///
+/// The byte array is de/serialized with `BYTES`, which defaults to [`Bytes`].
+/// [`Bytes`] serializes using `serialize_bytes` and accepts both byte strings and sequences while deserializing.
+/// Any other type working on byte arrays can be used instead, like [`Hex`](crate::hex::Hex) or [`Base64`](crate::base64::Base64).
+/// Combining them with [`IfIsHumanReadable`] uses a readable representation only for human-readable formats.
+///
+/// [`LittleEndianBytes`] is the equivalent type for a little-endian byte order.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "macros", feature = "hex"))] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use std::num::NonZeroU16;
+/// # use serde_with::{serde_as, BigEndianBytes, IfIsHumanReadable};
+/// use serde_with::hex::Hex;
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "BigEndianBytes")]
+///     id: u32,
+///     #[serde_as(as = "BigEndianBytes")]
+///     port: NonZeroU16,
+///     #[serde_as(as = "IfIsHumanReadable<BigEndianBytes<Hex>, BigEndianBytes>")]
+///     key: i64,
+/// }
+///
+/// let data = Data {
+///     id: 0x0102_0304,
+///     port: NonZeroU16::new(443).unwrap(),
+///     key: -2,
+/// };
+/// let json = json!({
+///     "id": [1, 2, 3, 4],
+///     "port": [1, 187],
+///     "key": "fffffffffffffffe",
+/// });
+/// assert_eq!(json, serde_json::to_value(&data).unwrap());
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+///
+/// // Zero is rejected for the NonZero types
+/// let json = json!({
+///     "id": [1, 2, 3, 4],
+///     "port": [0, 0],
+///     "key": "fffffffffffffffe",
+/// });
+/// assert!(serde_json::from_value::<Data>(json).is_err());
+/// # }
+/// ```
+pub struct BigEndianBytes<BYTES = Bytes>(PhantomData<BYTES>);
+
+/// Serialize integers as little-endian bytes
+///
+/// Integers are converted into a byte array with their least significant byte first.
+/// This matches the in-memory layout on most platforms.
+/// Otherwise, this type behaves like [`BigEndianBytes`], check its documentation for details.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{serde_as, LittleEndianBytes};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "LittleEndianBytes")]
+///     id: u32,
+///     #[serde_as(as = "LittleEndianBytes")]
+///     offset: i16,
+/// }
+///
+/// let data = Data {
+///     id: 0x0102_0304,
+///     offset: -2,
+/// };
+/// let json = json!({
+///     "id": [4, 3, 2, 1],
+///     "offset": [254, 255],
+/// });
+/// assert_eq!(json, serde_json::to_value(&data).unwrap());
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+pub struct LittleEndianBytes<BYTES = Bytes>(PhantomData<BYTES>);
+
 /// Deserialize one or many elements
 ///
 /// Sometimes it is desirable to have a shortcut in writing 1-element lists in a config file.
@@ -1734,10 +2009,22 @@ pub struct Bytes;
 ///
 /// The `OneOrMany` adapter achieves exactly this use case.
 /// The serialization behavior can be tweaked to either always serialize as a list using [`PreferMany`] or to serialize as the inner element if possible using [`PreferOne`].
+/// [`PreferNone`] additionally serializes empty lists as `None`, which together with `skip_serializing_if` omits them entirely.
 /// By default, [`PreferOne`] is assumed, which can also be omitted like `OneOrMany<_>`.
 ///
+/// A `null` value deserializes into an empty collection, unless the element type itself can be deserialized from `null`.
+/// Add `#[serde(default)]` to also accept a missing field.
+///
+/// All sequence and set types are supported.
+/// Duplicate values in sets are ignored by default ([`AllowDuplicates`]).
+/// With [`PreventDuplicates`], like `OneOrMany<_, PreferOne, PreventDuplicates>`, a list containing duplicate values is rejected, like with [`SetPreventDuplicates`].
+/// [`PreventDuplicates`] is only available for sets.
+///
+/// [`AllowDuplicates`]: crate::formats::AllowDuplicates
 /// [`PreferMany`]: crate::formats::PreferMany
+/// [`PreferNone`]: crate::formats::PreferNone
 /// [`PreferOne`]: crate::formats::PreferOne
+/// [`PreventDuplicates`]: crate::formats::PreventDuplicates
 ///
 /// # Examples
 ///
@@ -1786,9 +2073,47 @@ pub struct Bytes;
 /// assert_eq!(serde_json::to_value(data).unwrap(), j);
 /// # }
 /// ```
-pub struct OneOrMany<T: ?Sized, FORMAT: formats::Format = formats::PreferOne>(
-    PhantomData<(FORMAT, T)>,
-);
+///
+/// `null`, a single value, or a list can all be read into a set.
+/// [`PreferNone`] together with `skip_serializing_if` omits empty sets during serialization.
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # use serde_with::{serde_as, OneOrMany};
+/// # use serde_with::formats::{PreferNone, PreventDuplicates};
+/// # use std::collections::BTreeSet;
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+///     #[serde_as(as = "OneOrMany<_, PreferNone>")]
+///     tags: BTreeSet<String>,
+///     #[serde_as(as = "OneOrMany<_, PreferNone, PreventDuplicates>")]
+///     ids: BTreeSet<u32>,
+/// }
+///
+/// let data: Data = serde_json::from_value(json!({"tags": null, "ids": 1})).unwrap();
+/// assert!(data.tags.is_empty());
+/// assert_eq!(json!({"ids": 1}), serde_json::to_value(&data).unwrap());
+///
+/// let data: Data = serde_json::from_value(json!({"ids": [2, 3]})).unwrap();
+/// assert!(data.tags.is_empty());
+/// assert_eq!(json!({"ids": [2, 3]}), serde_json::to_value(&data).unwrap());
+///
+/// // Duplicate values are rejected because of `PreventDuplicates`
+/// let res = serde_json::from_value::<Data>(json!({"ids": [2, 2]}));
+/// assert!(res.is_err());
+/// # }
+/// ```
+pub struct OneOrMany<
+    T: ?Sized,
+    FORMAT: formats::Format = formats::PreferOne,
+    DUPLICATES: formats::Duplicates = formats::AllowDuplicates,
+>(PhantomData<(FORMAT, DUPLICATES, T)>);
 
 /// Try multiple deserialization options until one succeeds.
 ///
@@ -2265,19 +2590,63 @@ pub struct BorrowCow;
 /// The [`VecSkipError`] and [`MapSkipError`] adapters allow to skip values which fail to deserialize.
 /// This trait allows inspecting these errors, for example for logging purposes.
 ///
-/// The trait has a single method [`inspect_error`][InspectError::inspect_error], which will be called for each deserialization error.
-/// The default implementation for `()` does nothing.
+/// The method [`inspect_error`][InspectError::inspect_error] is called for each deserialization error.
+/// Inspectors which set [`WANTS_CONTEXT`][InspectError::WANTS_CONTEXT] to `true` get [`inspect_error_with_context`][InspectError::inspect_error_with_context] called instead.
+/// Besides the error, it receives a [`SkipErrorContext`] describing where the error occurred, i.e., the index of the element, the key of the map entry, and the raw value which failed to deserialize.
+/// The context requires keeping the buffered value alive during deserialization, which copies owned strings and bytes, so it is only provided on request.
+/// The implementation for `()` does nothing.
 ///
 /// See the documentation of [`VecSkipError`] and [`MapSkipError`] for usage examples.
 #[cfg(feature = "alloc")]
 pub trait InspectError {
+    /// Call [`inspect_error_with_context`][InspectError::inspect_error_with_context] instead of [`inspect_error`][InspectError::inspect_error].
+    ///
+    /// Set it to `true` when overriding [`inspect_error_with_context`][InspectError::inspect_error_with_context].
+    /// Defaults to `false`.
+    const WANTS_CONTEXT: bool = false;
+
     /// Inspect a deserialization error which was skipped.
-    fn inspect_error(error: impl serde_core::de::Error);
+    ///
+    /// The default implementation does nothing.
+    fn inspect_error(error: impl serde_core::de::Error) {
+        let _ = error;
+    }
+
+    /// Inspect a deserialization error which was skipped, together with the location it occurred at.
+    ///
+    /// Only called if [`WANTS_CONTEXT`][InspectError::WANTS_CONTEXT] is `true`.
+    /// The default implementation forwards the error to [`inspect_error`][InspectError::inspect_error].
+    fn inspect_error_with_context(
+        error: impl serde_core::de::Error,
+        context: &SkipErrorContext<'_, '_>,
+    ) {
+        let _ = context;
+        Self::inspect_error(error);
+    }
 }
 
 #[cfg(feature = "alloc")]
 impl InspectError for () {
     fn inspect_error(_error: impl serde_core::de::Error) {}
+
+    fn inspect_error_with_context(
+        _error: impl serde_core::de::Error,
+        _context: &SkipErrorContext<'_, '_>,
+    ) {
+    }
+}
+
+/// The location of an error skipped by [`VecSkipError`] or [`MapSkipError`].
+///
+/// It is passed to [`InspectError::inspect_error_with_context`], if the inspector sets [`InspectError::WANTS_CONTEXT`].
+/// The key is only rendered as string when calling [`SkipErrorContext::key`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct SkipErrorContext<'a, 'de> {
+    index: usize,
+    key: Option<&'a value::Value<'de>>,
+    is_key_error: bool,
+    value: &'a value::Value<'de>,
 }
 
 /// Deserialize a sequence into `Vec<T>`, skipping elements which fail to deserialize.
@@ -2362,9 +2731,166 @@ impl InspectError for () {
 /// assert!(errors[4].contains("invalid value: integer `300`, expected u8"));
 /// # }
 /// ```
+///
+/// ## Using [`SkipErrorContext`] to locate skipped elements
+///
+/// Setting [`WANTS_CONTEXT`](InspectError::WANTS_CONTEXT) and implementing [`inspect_error_with_context`](InspectError::inspect_error_with_context) provides the index and the raw value of each skipped element.
+///
+/// ```rust
+/// # #[cfg(all(feature = "macros", feature = "alloc"))] {
+/// # use serde::Deserialize;
+/// # use serde_with::{serde_as, InspectError, SkipErrorContext, VecSkipError};
+/// # use std::cell::RefCell;
+/// #
+/// struct ErrorInspector;
+///
+/// thread_local! {
+///     static ERRORS: RefCell<Vec<String>> = RefCell::new(Vec::new());
+/// }
+///
+/// impl InspectError for ErrorInspector {
+///     const WANTS_CONTEXT: bool = true;
+///
+///     fn inspect_error_with_context(error: impl serde::de::Error, context: &SkipErrorContext<'_, '_>) {
+///         let message = format!("[{}] {:?}: {error}", context.index(), context.value());
+///         ERRORS.with(|errors| errors.borrow_mut().push(message));
+///     }
+/// }
+///
+/// #[serde_as]
+/// #[derive(Deserialize)]
+/// struct S(#[serde_as(as = "VecSkipError<_, ErrorInspector>")] Vec<u8>);
+///
+/// let s: S = serde_json::from_str(r#"[0, "str", 1, -2]"#).unwrap();
+/// assert_eq!(s.0, vec![0, 1]);
+///
+/// let errors = ERRORS.with(|errors| errors.borrow().clone());
+/// assert_eq!(
+///     errors,
+///     [
+///         r#"[1] Str("str"): invalid type: string "str", expected u8"#,
+///         "[3] I64(-2): invalid value: integer `-2`, expected u8",
+///     ]
+/// );
+/// # }
+/// ```
 #[cfg(feature = "alloc")]
 pub struct VecSkipError<T, I = ()>(PhantomData<(T, I)>);
 
+/// Deserialize any sequence or set, skipping elements which fail to deserialize.
+///
+/// This generalizes [`VecSkipError`] to all sequence types, like [`VecDeque`], [`BTreeSet`], [`HashSet`], [`LinkedList`], [`BinaryHeap`], `Box<[T]>`, and the sets of `hashbrown` and `indexmap`.
+/// The serialization behavior is identical to the underlying sequence.
+///
+/// Arrays `[T; N]` are supported, too.
+/// Invalid elements are skipped until `N` valid elements are found.
+/// Deserialization fails if fewer than `N` elements are valid, or if more than `N` elements are valid.
+///
+/// You can be notified of skipped elements by providing a type that implements the [`InspectError`] trait.
+/// The second generic argument `I` defaults to `()`, which does nothing.
+///
+/// [`BinaryHeap`]: std::collections::BinaryHeap
+/// [`BTreeSet`]: std::collections::BTreeSet
+/// [`HashSet`]: std::collections::HashSet
+/// [`LinkedList`]: std::collections::LinkedList
+/// [`VecDeque`]: std::collections::VecDeque
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, SeqSkipError};
+/// # use std::collections::BTreeSet;
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "SeqSkipError<_>")]
+///     set: BTreeSet<u8>,
+///     #[serde_as(as = "SeqSkipError<_>")]
+///     rgb: [u8; 3],
+/// }
+///
+/// let data = Data {
+///     set: BTreeSet::from([1, 2]),
+///     rgb: [255, 128, 0],
+/// };
+/// let source_json = r#"{"set": [2, "x", 1, -1, 2], "rgb": [255, null, 128, 0]}"#;
+/// let data_json = r#"{"set":[1,2],"rgb":[255,128,0]}"#;
+/// // Ensure serialization and deserialization produce the expected results
+/// assert_eq!(data_json, serde_json::to_string(&data).unwrap());
+/// assert_eq!(data, serde_json::from_str(source_json).unwrap());
+///
+/// // Too few valid elements remain for the array
+/// let res = serde_json::from_str::<Data>(r#"{"set": [], "rgb": [255, null, 128]}"#);
+/// assert_eq!(
+///     "expected 3 valid elements, but only 2 of 3 elements could be deserialized at line 1 column 35",
+///     res.unwrap_err().to_string(),
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SeqSkipError<T, I = ()>(PhantomData<(T, I)>);
+
+/// Deserialize a sequence, skipping a limited number of elements which fail to deserialize.
+///
+/// This behaves like [`SeqSkipError`], but deserialization fails if too many elements are skipped.
+/// Without a limit, a sequence containing only invalid elements silently deserializes into an empty collection.
+///
+/// The `LIMIT` is a [`SkipLimit`](formats::SkipLimit).
+/// [`MaxSkipped<N>`](formats::MaxSkipped) allows skipping at most `N` elements, while [`MaxSkippedPercent<PERCENT>`](formats::MaxSkippedPercent) allows skipping at most `PERCENT` percent of all elements.
+/// The error summarizes how many elements were skipped and lists the first errors.
+///
+/// All sequence and set types are supported, like `Vec`, `VecDeque`, `BTreeSet`, or `HashSet`.
+/// The serialization behavior is identical to the underlying sequence.
+///
+/// You can be notified of skipped elements by providing a type that implements the [`InspectError`] trait.
+/// The third generic argument `I` defaults to `()`, which does nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, formats::{MaxSkipped, MaxSkippedPercent}, BoundedSkipError};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     // Skip at most one element
+///     #[serde_as(as = "BoundedSkipError<_, MaxSkipped<1>>")]
+///     count: Vec<u8>,
+///     // Skip at most half of the elements
+///     #[serde_as(as = "BoundedSkipError<_, MaxSkippedPercent<50>>")]
+///     ratio: Vec<u8>,
+/// }
+///
+/// let data = Data {
+///     count: vec![1, 2],
+///     ratio: vec![3, 4],
+/// };
+/// let source_json = r#"{"count": [1, "x", 2], "ratio": [3, null, 4, -1]}"#;
+/// let data_json = r#"{"count":[1,2],"ratio":[3,4]}"#;
+/// // Ensure serialization and deserialization produce the expected results
+/// assert_eq!(data_json, serde_json::to_string(&data).unwrap());
+/// assert_eq!(data, serde_json::from_str(source_json).unwrap());
+///
+/// // Too many invalid elements
+/// let res = serde_json::from_str::<Data>(r#"{"count": [1, "x", -1], "ratio": []}"#);
+/// assert_eq!(
+///     r#"skipped 2 of 3 elements, but at most 1 may be skipped:
+/// [1]: invalid type: string "x", expected u8
+/// [2]: invalid value: integer `-1`, expected u8 at line 1 column 22"#,
+///     res.unwrap_err().to_string(),
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct BoundedSkipError<T, LIMIT, I = ()>(PhantomData<(T, LIMIT, I)>);
+
 /// Deserialize a map, skipping keys and values which fail to deserialize.
 ///
 /// By default serde terminates if it fails to deserialize a key or a value when deserializing
@@ -2373,6 +2899,7 @@ pub struct VecSkipError<T, I = ()>(PhantomData<(T, I)>);
 ///
 /// You can be notified of skipped elements by providing a type that implements the [`InspectError`] trait.
 /// The third generic argument `I` defaults to `()`, which does nothing.
+/// The [`SkipErrorContext`] contains the key of the skipped entry, such that it can be included in log messages.
 ///
 /// It is especially useful in conjunction to `#[serde(flatten)]` to capture a map mixed in with
 /// other entries which we don't want to exhaust in the type definition.
@@ -2471,6 +2998,125 @@ pub struct VecSkipError<T, I = ()>(PhantomData<(T, I)>);
 #[cfg(feature = "alloc")]
 pub struct MapSkipError<K, V, I = ()>(PhantomData<(K, V, I)>);
 
+/// Deserialize a sequence into `Vec<T>`, reporting all elements which fail to deserialize.
+///
+/// By default serde stops at the first error, such that only a single problem is reported at a time.
+/// This adapter continues deserializing the remaining elements after a failure.
+/// If any element failed, deserialization fails with an error listing all failures together with the index of the element.
+/// The failures are collected in a [`CollectedErrors`] and rendered one per line, in the form `[index]: message`.
+///
+/// Nested adapters, like `CollectErrors<MapCollectErrors<_, _>>`, do not merge their errors.
+/// The errors of the inner adapter are flattened into text and become the message of the outer error, e.g., `[3]: ["port"]: message`.
+/// Errors of the data format itself, like syntax errors, cannot be recovered from and are returned directly.
+///
+/// The serialization behavior is identical to `Vec<T>`.
+/// See [`MapCollectErrors`] for the equivalent type for maps.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, CollectErrors, MapCollectErrors};
+/// # use std::collections::BTreeMap;
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Config {
+///     #[serde_as(as = "CollectErrors<MapCollectErrors<_, _>>")]
+///     servers: Vec<BTreeMap<String, u16>>,
+/// }
+///
+/// let json = r#"{"servers": [{"port": 80}, {"port": -1}, {"port": 443, "backup": "none"}]}"#;
+/// let error = serde_json::from_str::<Config>(json).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "[1]: [\"port\"]: invalid value: integer `-1`, expected u16\n\
+///      [2]: [\"backup\"]: invalid type: string \"none\", expected u16 at line 1 column 73",
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct CollectErrors<T>(PhantomData<T>);
+
+/// Deserialize a map, reporting all keys and values which fail to deserialize.
+///
+/// This adapter continues deserializing the remaining entries after a failure.
+/// If any key or value failed, deserialization fails with an error listing all failures together with the key of the entry.
+/// The failures are rendered one per line, in the form `["key"]: message`.
+/// Check [`CollectErrors`] for more details.
+///
+/// The serialization behavior is identical to the underlying map.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, DisplayFromStr, MapCollectErrors};
+/// # use std::collections::BTreeMap;
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Limits(#[serde_as(as = "MapCollectErrors<_, DisplayFromStr>")] BTreeMap<String, u32>);
+///
+/// let json = r#"{"cpu": "4", "memory": "lots", "disk": "-5"}"#;
+/// let error = serde_json::from_str::<Limits>(json).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "[\"memory\"]: invalid digit found in string\n\
+///      [\"disk\"]: invalid digit found in string at line 1 column 44",
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct MapCollectErrors<K, V>(PhantomData<(K, V)>);
+
+/// Accumulator for deserialization errors and their location
+///
+/// [`CollectErrors`] and [`MapCollectErrors`] use it to gather all failures before reporting them together.
+/// It is also usable in custom [`DeserializeAs`] or [`Visitor`](serde_core::de::Visitor) implementations, which want to continue after an error.
+///
+/// Each error is stored with a path, like `[3]` for the index of a sequence element or `["name"]` for a map key.
+/// The [`Display`](core::fmt::Display) implementation renders one error per line, in the form `path: message`.
+/// [`CollectedErrors::nest`] adds the errors of another accumulator by prefixing their paths.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde::de::value::Error;
+/// use serde_with::CollectedErrors;
+///
+/// let mut errors = CollectedErrors::new();
+/// let ok: Result<u8, &str> = Ok(1);
+/// assert_eq!(Some(1), errors.record("[0]", ok));
+/// let err: Result<u8, &str> = Err("too large");
+/// assert_eq!(None, errors.record("[1]", err));
+///
+/// let mut outer = CollectedErrors::new();
+/// outer.nest("[\"list\"]", errors);
+/// assert_eq!(1, outer.len());
+/// assert_eq!("[\"list\"][1]", outer.iter().next().unwrap().path());
+///
+/// let result: Result<(), Error> = outer.into_result(());
+/// assert_eq!("[\"list\"][1]: too large", result.unwrap_err().to_string());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectedErrors {
+    errors: alloc::vec::Vec<CollectedError>,
+}
+
+/// A single error in [`CollectedErrors`]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectedError {
+    path: alloc::string::String,
+    message: alloc::string::String,
+}
+
 /// Deserialize a boolean from a number
 ///
 /// Deserialize a number (of `u8`) and turn it into a boolean.
@@ -2519,6 +3165,134 @@ pub struct MapSkipError<K, V, I = ()>(PhantomData<(K, V, I)>);
 /// ```
 pub struct BoolFromInt<S: formats::Strictness = formats::Strict>(PhantomData<S>);
 
+/// De/Serialize an integer as a float
+///
+/// Some data sources write integers as floats, like `3.0` for a count or `1e3` for a size.
+/// This adapter deserializes an integer from such floats and from integers.
+/// Values are serialized as `f64`.
+///
+/// The adapter supports a [`Strict`](crate::formats::Strict) and [`Flexible`](crate::formats::Flexible) format.
+/// In `Strict` mode, only integral floats in the range of the integer type are accepted.
+/// All other values produce an error, as do integers, which `f64` cannot represent exactly during serialization.
+/// In `Flexible` mode, floats are rounded with the [`Rounding`] mode, by default [`Truncate`].
+/// Values outside of the range of the integer produce an error or saturate, depending on the [`Overflow`] behavior, by default [`Reject`].
+/// `NaN` is never accepted.
+///
+/// The adapter works with all integer primitives.
+/// [`FloatFromInt`] works in the opposite direction.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{
+///     formats::{Flexible, Nearest, Saturate},
+///     serde_as, IntFromFloat,
+/// };
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "IntFromFloat")]
+///     count: u32,
+///     #[serde_as(as = "IntFromFloat<Flexible, Nearest, Saturate>")]
+///     percent: u8,
+/// }
+///
+/// let data = Data {
+///     count: 1000,
+///     percent: 255,
+/// };
+/// let json = json!({"count": 1e3, "percent": 300.7});
+/// assert_eq!(data, serde_json::from_value(json).unwrap());
+/// assert_eq!(
+///     json!({"count": 1000.0, "percent": 255.0}),
+///     serde_json::to_value(&data).unwrap(),
+/// );
+///
+/// // Strict rejects fractional values
+/// let json = json!({"count": 2.5, "percent": 1});
+/// assert!(serde_json::from_value::<Data>(json).is_err());
+/// # }
+/// ```
+///
+/// [`Overflow`]: crate::formats::Overflow
+/// [`Reject`]: crate::formats::Reject
+/// [`Rounding`]: crate::formats::Rounding
+/// [`Truncate`]: crate::formats::Truncate
+pub struct IntFromFloat<
+    S: formats::Strictness = formats::Strict,
+    ROUNDING: formats::Rounding = formats::Truncate,
+    OVERFLOW: formats::Overflow = formats::Reject,
+>(PhantomData<(S, ROUNDING, OVERFLOW)>);
+
+/// De/Serialize a float as an integer
+///
+/// This adapter serializes floats, which hold integral values, as an `i64`, e.g., `3.0` as `3`.
+/// During deserialization integers are converted to the float type.
+///
+/// The adapter supports a [`Strict`](crate::formats::Strict) and [`Flexible`](crate::formats::Flexible) format.
+/// In `Strict` mode, only integral floats in the range of `i64` are serialized and only integers, which the float type represents exactly, are deserialized.
+/// All other values produce an error.
+/// In `Flexible` mode, floats are rounded with the [`Rounding`] mode, by default [`Truncate`], and integers are converted to the nearest float.
+/// Values outside of the target range produce an error or saturate, depending on the [`Overflow`] behavior, by default [`Reject`].
+/// `NaN` is never serialized.
+///
+/// The adapter works with `f32` and `f64`.
+/// [`IntFromFloat`] works in the opposite direction.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{
+///     formats::{Flexible, Nearest},
+///     serde_as, FloatFromInt,
+/// };
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "FloatFromInt")]
+///     total: f64,
+///     #[serde_as(as = "FloatFromInt<Flexible, Nearest>")]
+///     average: f32,
+/// }
+///
+/// let data = Data {
+///     total: 12.0,
+///     average: 2.6,
+/// };
+/// assert_eq!(
+///     json!({"total": 12, "average": 3}),
+///     serde_json::to_value(&data).unwrap(),
+/// );
+///
+/// // Strict rejects fractional values
+/// let data = Data {
+///     total: 12.5,
+///     average: 2.0,
+/// };
+/// assert!(serde_json::to_value(&data).is_err());
+/// # }
+/// ```
+///
+/// [`Overflow`]: crate::formats::Overflow
+/// [`Reject`]: crate::formats::Reject
+/// [`Rounding`]: crate::formats::Rounding
+/// [`Truncate`]: crate::formats::Truncate
+pub struct FloatFromInt<
+    S: formats::Strictness = formats::Strict,
+    ROUNDING: formats::Rounding = formats::Truncate,
+    OVERFLOW: formats::Overflow = formats::Reject,
+>(PhantomData<(S, ROUNDING, OVERFLOW)>);
+
 /// De/Serialize a delimited collection using [`Display`] and [`FromStr`] implementation
 ///
 /// `StringWithSeparator` takes a second type, which needs to implement [`Display`]+[`FromStr`] and constitutes the inner type of the collection.
@@ -2787,6 +3561,188 @@ pub struct SetPreventDuplicates<T>(PhantomData<T>);
 #[cfg(feature = "alloc")]
 pub struct SetLastValueWins<T>(PhantomData<T>);
 
+/// Serialize a map with its entries sorted by key
+///
+/// Maps like [`HashMap`] serialize their entries in a random order, which breaks snapshot tests and content hashes.
+/// This helper collects references to all entries, sorts them by key, and serializes them in this order.
+/// The map itself is not modified.
+///
+/// The `ORDER` defaults to [`Ascending`](formats::Ascending), which uses the [`Ord`] implementation of the key.
+/// [`Descending`](formats::Descending) reverses it, and custom orders can be provided by implementing [`SortOrder`](formats::SortOrder).
+/// The order is applied to the original key, not to its serialized form.
+///
+/// Deserialization is identical to the underlying map.
+/// All maps are supported, i.e., [`BTreeMap`], [`HashMap`], and the maps of `hashbrown` and `indexmap`.
+///
+/// [`BTreeMap`]: std::collections::BTreeMap
+/// [`HashMap`]: std::collections::HashMap
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use std::collections::HashMap;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, formats::SortOrder, DisplayFromStr, SortedMap};
+/// #
+/// /// Sort the keys by length, then alphabetically
+/// struct ByLength;
+///
+/// impl SortOrder<String> for ByLength {
+///     fn cmp(a: &String, b: &String) -> std::cmp::Ordering {
+///         (a.len(), a).cmp(&(b.len(), b))
+///     }
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Doc {
+///     #[serde_as(as = "SortedMap<DisplayFromStr, _>")]
+///     numbers: HashMap<u32, bool>,
+///     #[serde_as(as = "SortedMap<_, _, ByLength>")]
+///     words: HashMap<String, u32>,
+/// }
+///
+/// let doc = Doc {
+///     numbers: HashMap::from([(10, true), (2, false), (1, true)]),
+///     words: HashMap::from([("ccc".into(), 1), ("a".into(), 2), ("bb".into(), 3)]),
+/// };
+/// // Keys are sorted as numbers, not as strings
+/// let json = r#"{"numbers":{"1":true,"2":false,"10":true},"words":{"a":2,"bb":3,"ccc":1}}"#;
+/// assert_eq!(json, serde_json::to_string(&doc).unwrap());
+/// assert_eq!(doc, serde_json::from_str(json).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SortedMap<K, V, ORDER = formats::Ascending>(PhantomData<(K, V, ORDER)>);
+
+/// Serialize a set with its elements sorted
+///
+/// Sets like [`HashSet`] serialize their elements in a random order, which breaks snapshot tests and content hashes.
+/// This helper collects references to all elements, sorts them, and serializes them in this order.
+/// The set itself is not modified.
+///
+/// The `ORDER` defaults to [`Ascending`](formats::Ascending), which uses the [`Ord`] implementation of the element.
+/// [`Descending`](formats::Descending) reverses it, and custom orders can be provided by implementing [`SortOrder`](formats::SortOrder).
+///
+/// Deserialization is identical to the underlying set.
+/// All sets are supported, i.e., [`BTreeSet`], [`HashSet`], and the sets of `hashbrown` and `indexmap`.
+///
+/// [`BTreeSet`]: std::collections::BTreeSet
+/// [`HashSet`]: std::collections::HashSet
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use std::collections::HashSet;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, formats::Descending, SortedSet};
+/// #
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Doc {
+///     #[serde_as(as = "SortedSet<_>")]
+///     ascending: HashSet<u32>,
+///     #[serde_as(as = "SortedSet<_, Descending>")]
+///     descending: HashSet<u32>,
+/// }
+///
+/// let doc = Doc {
+///     ascending: HashSet::from([3, 1, 2]),
+///     descending: HashSet::from([3, 1, 2]),
+/// };
+/// let json = r#"{"ascending":[1,2,3],"descending":[3,2,1]}"#;
+/// assert_eq!(json, serde_json::to_string(&doc).unwrap());
+/// assert_eq!(doc, serde_json::from_str(json).unwrap());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SortedSet<T, ORDER = formats::Ascending>(PhantomData<(T, ORDER)>);
+
+/// Serialize a value in a canonical form, for signing and hashing
+///
+/// Many types do not have a unique serialized form, for example maps and sets which serialize their entries in random order.
+/// This helper first serializes the value into an internal buffer and then normalizes it:
+///
+/// * All map keys are sorted, recursively in all nested values.
+///   Human-readable formats, like JSON, serialize keys as strings, which are sorted by their UTF-16 code units as in the JSON Canonicalization Scheme ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)).
+///   This means the number `10` is sorted before `2`.
+///   Compact formats sort keys by their value, e.g., numbers numerically and strings by their UTF-8 bytes.
+///   Duplicate keys are an error.
+/// * The fields of all structs are sorted by name, like map keys, if the format is human-readable.
+///   For formats like JSON, which serialize structs as maps, this matches the sorting of map keys.
+///   Compact formats, like bincode, identify fields by their position and keep the declared order.
+/// * Floats are normalized, such that `-0.0` is serialized as `0.0`.
+/// * Non-finite floats, i.e., `NaN` and infinities, are an error.
+///
+/// Afterwards, the normalized value is serialized.
+/// The order of sequences is kept, since it is significant.
+/// Sets serialize as sequences, so a [`HashSet`] still serializes in random order.
+/// Use [`SortedSet`] for them.
+/// The inner type `T` defaults to [`Same`], which uses the [`Serialize`] implementation of the value.
+///
+/// Deserialization is identical to `T` and does not check that the input is canonical.
+///
+/// [`HashSet`]: std::collections::HashSet
+/// [`Serialize`]: serde_core::Serialize
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use std::collections::{BTreeSet, HashMap};
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_with::{serde_as, Canonical};
+/// #
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Payload {
+///     z_last: f64,
+///     tags: BTreeSet<String>,
+///     nested: HashMap<String, HashMap<u32, bool>>,
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Signed {
+///     #[serde_as(as = "Canonical")]
+///     payload: Payload,
+/// }
+///
+/// let signed = Signed {
+///     payload: Payload {
+///         z_last: -0.0,
+///         tags: BTreeSet::from(["y".into(), "x".into()]),
+///         nested: HashMap::from([
+///             ("b".into(), HashMap::from([(10, true), (2, false)])),
+///             ("a".into(), HashMap::new()),
+///         ]),
+///     },
+/// };
+/// let json = r#"{"payload":{"nested":{"a":{},"b":{"10":true,"2":false}},"tags":["x","y"],"z_last":0.0}}"#;
+/// assert_eq!(json, serde_json::to_string(&signed).unwrap());
+/// assert_eq!(signed, serde_json::from_str(json).unwrap());
+///
+/// // Non-finite floats are rejected
+/// let invalid = Signed {
+///     payload: Payload {
+///         z_last: f64::NAN,
+///         tags: BTreeSet::new(),
+///         nested: HashMap::new(),
+///     },
+/// };
+/// assert_eq!(
+///     "Canonical cannot serialize the non-finite float NaN",
+///     serde_json::to_string(&invalid).unwrap_err().to_string(),
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Canonical<T = Same>(PhantomData<T>);
+
 /// Helper for implementing [`JsonSchema`] on serializers whose output depends
 /// on the type of the concrete field.
 ///
@@ -0,0 +1,319 @@
+use crate::{
+    prelude::*,
+    utils::{forward_deserialize, forward_visit},
+};
+
+/// Trim leading and trailing whitespace from strings before deserializing them
+///
+/// Form submissions and CSV imports often contain padded strings like `" foo "`, or strings starting with a byte order mark (`\u{FEFF}`).
+/// This adapter removes the Unicode whitespace and byte order marks at both ends of every string, before it reaches the `T` adapter.
+/// The `T` adapter, which by default is [`Same`], can then parse the string.
+/// Combined with [`DisplayFromStr`] `" 42 "` becomes `42`, and combined with [`NoneAsEmptyString`] whitespace-only strings become `None`.
+///
+/// Values which are not strings are passed on unchanged.
+/// Nested values, like the elements of a sequence, are not trimmed, instead the adapter should be applied to the elements, e.g., `Vec<Trimmed>`.
+/// Serialization is unchanged and uses the serialization of `T`.
+///
+/// The adapter can be combined with [`Lowercased`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::Deserialize;
+/// use serde_with::{serde_as, DisplayFromStr, NoneAsEmptyString, Trimmed};
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize)]
+/// struct Form {
+///     #[serde_as(as = "Trimmed")]
+///     name: String,
+///     #[serde_as(as = "Trimmed<DisplayFromStr>")]
+///     age: u32,
+///     #[serde_as(as = "Trimmed<NoneAsEmptyString>")]
+///     nickname: Option<String>,
+/// }
+///
+/// let json = r#"{"name": "\ufeffAlice ", "age": " 42 ", "nickname": "   "}"#;
+/// assert_eq!(
+///     Form {
+///         name: "Alice".into(),
+///         age: 42,
+///         nickname: None,
+///     },
+///     serde_json::from_str(json).unwrap(),
+/// );
+/// # }
+/// ```
+pub struct Trimmed<T = Same>(PhantomData<T>);
+
+/// Convert strings to lowercase before deserializing them
+///
+/// This adapter converts every string to lowercase, before it reaches the `T` adapter, which by default is [`Same`].
+/// The conversion uses the Unicode rules of [`str::to_lowercase`].
+/// Strings, which are already lowercase, are not copied.
+///
+/// Values which are not strings are passed on unchanged.
+/// Nested values, like the elements of a sequence, are not converted, instead the adapter should be applied to the elements, e.g., `Vec<Lowercased>`.
+/// Serialization is unchanged and uses the serialization of `T`.
+///
+/// The adapter can be combined with [`Trimmed`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::Deserialize;
+/// use serde_with::{serde_as, Lowercased, Trimmed};
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize)]
+/// struct Account {
+///     #[serde_as(as = "Trimmed<Lowercased>")]
+///     email: String,
+///     #[serde_as(as = "Vec<Lowercased>")]
+///     tags: Vec<String>,
+/// }
+///
+/// let json = r#"{"email": " Alice@Example.COM ", "tags": ["Admin", "ÜBER"]}"#;
+/// assert_eq!(
+///     Account {
+///         email: "alice@example.com".into(),
+///         tags: vec!["admin".into(), "über".into()],
+///     },
+///     serde_json::from_str(json).unwrap(),
+/// );
+/// # }
+/// ```
+pub struct Lowercased<T = Same>(PhantomData<T>);
+
+/// Change applied to all strings by the normalization adapters
+trait Normalization {
+    /// Return the normalized string, which is borrowed only if it is a substring of `value`.
+    fn normalize(value: &str) -> Cow<'_, str>;
+}
+
+impl<T> Normalization for Trimmed<T> {
+    fn normalize(value: &str) -> Cow<'_, str> {
+        Cow::Borrowed(value.trim_matches(|c: char| c.is_whitespace() || c == '\u{FEFF}'))
+    }
+}
+
+impl<T> Normalization for Lowercased<T> {
+    fn normalize(value: &str) -> Cow<'_, str> {
+        if value.chars().flat_map(char::to_lowercase).eq(value.chars()) {
+            Cow::Borrowed(value)
+        } else {
+            Cow::Owned(value.to_lowercase())
+        }
+    }
+}
+
+macro_rules! normalization_impls {
+    ($($adapter:ident)*) => {$(
+        impl<T, U> SerializeAs<T> for $adapter<U>
+        where
+            T: ?Sized,
+            U: SerializeAs<T>,
+        {
+            fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                U::serialize_as(source, serializer)
+            }
+        }
+
+        impl<'de, T, U> DeserializeAs<'de, T> for $adapter<U>
+        where
+            U: DeserializeAs<'de, T>,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                U::deserialize_as(Normalize::<_, Self>::new(deserializer))
+            }
+        }
+    )*};
+}
+normalization_impls!(Trimmed Lowercased);
+
+/// Wrapper around the deserializer and its visitors, which normalizes all visited strings.
+struct Normalize<T, N> {
+    delegate: T,
+    phantom: PhantomData<N>,
+}
+
+impl<T, N> Normalize<T, N> {
+    fn new(delegate: T) -> Self {
+        Self {
+            delegate,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, D, N> Deserializer<'de> for Normalize<D, N>
+where
+    D: Deserializer<'de>,
+    N: Normalization,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        |visitor| Normalize::<_, N>::new(visitor);
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.delegate.is_human_readable()
+    }
+}
+
+impl<'de, V, N> Visitor<'de> for Normalize<V, N>
+where
+    V: Visitor<'de>,
+    N: Normalization,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.delegate.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        match N::normalize(v) {
+            Cow::Borrowed(v) => self.delegate.visit_str(v),
+            Cow::Owned(v) => self.delegate.visit_string(v),
+        }
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        match N::normalize(v) {
+            Cow::Borrowed(v) => self.delegate.visit_borrowed_str(v),
+            Cow::Owned(v) => self.delegate.visit_string(v),
+        }
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        let normalized = match N::normalize(&v) {
+            // Pass on unchanged strings without allocating
+            Cow::Borrowed(normalized) if normalized.len() == v.len() => None,
+            normalized => Some(normalized.into_owned()),
+        };
+        self.delegate.visit_string(normalized.unwrap_or(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.delegate.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.delegate
+            .visit_some(Normalize::<_, N>::new(deserializer))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        self.delegate.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.delegate
+            .visit_newtype_struct(Normalize::<_, N>::new(deserializer))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.delegate.visit_seq(seq)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.delegate.visit_map(map)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.delegate.visit_enum(data)
+    }
+}
@@ -0,0 +1,336 @@
+use crate::prelude::*;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+/// De/Serialize numbers as strings, such that JavaScript does not lose precision
+///
+/// JavaScript represents all numbers as `f64`, which loses precision for integers with a magnitude above 2<sup>53</sup> - 1.
+/// APIs consumed by browsers therefore often transmit large `u64` and `i64` values as strings.
+/// This adapter works for all integer and float primitives and the `NonZero*` types, see [`Number`].
+///
+/// The `MODE` parameter controls when numbers are serialized as strings.
+///
+/// * [`AlwaysString`] (default): Serialize all numbers as strings.
+/// * [`LargeAsString`]: Serialize only numbers as strings, which JavaScript cannot represent exactly.
+///   These are integers with a magnitude above 2<sup>53</sup> - 1 and the non-finite floats.
+/// * [`AlwaysNumber`]: Serialize all numbers as numbers.
+///
+/// Deserialization is the same for all modes and accepts numbers and strings.
+/// Strings are parsed with [`FromStr`], which accepts a leading `+`, exponents like `1e3` for floats, and `inf`, `infinity`, and `NaN` in any case.
+/// This requires a self-describing data format.
+/// Some formats cannot deserialize 128-bit integers from arbitrary values, e.g., `serde_json` reads integers beyond the `u64` range as floats.
+/// Serialize these as strings to keep them exact.
+/// Use [`IfIsHumanReadable`] to only apply the adapter to human-readable formats, e.g., `IfIsHumanReadable<NumberAsString, Same>`.
+///
+/// Compared to [`PickFirst<(_, DisplayFromStr)>`](PickFirst) the adapter can serialize depending on the value and provides a JSON schema.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{
+///     formats::{AlwaysNumber, LargeAsString},
+///     serde_as, NumberAsString,
+/// };
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Account {
+///     #[serde_as(as = "NumberAsString")]
+///     id: u64,
+///     #[serde_as(as = "Vec<NumberAsString<LargeAsString>>")]
+///     balances: Vec<i64>,
+///     #[serde_as(as = "NumberAsString<AlwaysNumber>")]
+///     rate: f64,
+/// }
+///
+/// let account = Account {
+///     id: 42,
+///     balances: vec![100, -9_007_199_254_740_993],
+///     rate: 0.5,
+/// };
+/// let json = json!({
+///     "id": "42",
+///     "balances": [100, "-9007199254740993"],
+///     "rate": 0.5,
+/// });
+/// assert_eq!(json, serde_json::to_value(&account).unwrap());
+///
+/// // Numbers and strings are accepted in all modes
+/// let json = json!({"id": 42, "balances": ["100"], "rate": "0.5"});
+/// assert_eq!(
+///     Account {
+///         id: 42,
+///         balances: vec![100],
+///         rate: 0.5,
+///     },
+///     serde_json::from_value(json).unwrap(),
+/// );
+/// # }
+/// ```
+///
+/// [`AlwaysNumber`]: crate::formats::AlwaysNumber
+/// [`AlwaysString`]: crate::formats::AlwaysString
+/// [`LargeAsString`]: crate::formats::LargeAsString
+pub struct NumberAsString<MODE: formats::NumberRepresentation = formats::AlwaysString>(
+    PhantomData<MODE>,
+);
+
+/// Largest integer magnitude, which JavaScript represents exactly
+const MAX_SAFE_INTEGER: u128 = (1 << 53) - 1;
+
+/// Numbers supported by [`NumberAsString`]
+///
+/// The trait is implemented for all primitive integer and float types and the `NonZero*` types.
+/// Strings are converted using [`Display`] and [`FromStr`], numbers using the `from_*` functions.
+pub trait Number: Sized + Display + FromStr + Serialize {
+    /// Name of the type for error messages
+    const NAME: &'static str;
+    /// Regular expression matching all strings, which [`FromStr`] accepts
+    ///
+    /// The JSON schema uses it as `pattern` of the string representation.
+    const PATTERN: &'static str;
+
+    /// Return `true` if JavaScript represents the number exactly.
+    fn is_safe(&self) -> bool;
+    /// Convert from a deserialized `i64`, or return `None` if the value is out of range.
+    fn from_i64(v: i64) -> Option<Self>;
+    /// Convert from a deserialized `u64`, or return `None` if the value is out of range.
+    fn from_u64(v: u64) -> Option<Self>;
+    /// Convert from a deserialized `i128`, or return `None` if the value is out of range.
+    fn from_i128(v: i128) -> Option<Self>;
+    /// Convert from a deserialized `u128`, or return `None` if the value is out of range.
+    fn from_u128(v: u128) -> Option<Self>;
+    /// Convert from a deserialized `f64`, or return `None` if the type does not accept floats.
+    fn from_f64(v: f64) -> Option<Self>;
+}
+
+macro_rules! number_int {
+    ($($ty:ty => $pattern:literal;)*) => {$(
+        impl Number for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const PATTERN: &'static str = $pattern;
+
+            fn is_safe(&self) -> bool {
+                i128::try_from(*self).is_ok_and(|v| v.unsigned_abs() <= MAX_SAFE_INTEGER)
+            }
+
+            fn from_i64(v: i64) -> Option<Self> {
+                Self::try_from(v).ok()
+            }
+
+            fn from_u64(v: u64) -> Option<Self> {
+                Self::try_from(v).ok()
+            }
+
+            fn from_i128(v: i128) -> Option<Self> {
+                Self::try_from(v).ok()
+            }
+
+            fn from_u128(v: u128) -> Option<Self> {
+                Self::try_from(v).ok()
+            }
+
+            fn from_f64(_v: f64) -> Option<Self> {
+                None
+            }
+        }
+    )*};
+}
+number_int! {
+    i8 => "^[+-]?[0-9]+$";
+    i16 => "^[+-]?[0-9]+$";
+    i32 => "^[+-]?[0-9]+$";
+    i64 => "^[+-]?[0-9]+$";
+    i128 => "^[+-]?[0-9]+$";
+    isize => "^[+-]?[0-9]+$";
+    u8 => "^\\+?[0-9]+$";
+    u16 => "^\\+?[0-9]+$";
+    u32 => "^\\+?[0-9]+$";
+    u64 => "^\\+?[0-9]+$";
+    u128 => "^\\+?[0-9]+$";
+    usize => "^\\+?[0-9]+$";
+}
+
+macro_rules! number_float {
+    ($($ty:ty)*) => {$(
+        #[allow(trivial_numeric_casts)]
+        impl Number for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const PATTERN: &'static str = r"^[+-]?(([0-9]+(\.[0-9]*)?|\.[0-9]+)([eE][+-]?[0-9]+)?|[iI][nN][fF]([iI][nN][iI][tT][yY])?|[nN][aA][nN])$";
+
+            fn is_safe(&self) -> bool {
+                self.is_finite()
+            }
+
+            fn from_i64(v: i64) -> Option<Self> {
+                Some(v as Self)
+            }
+
+            fn from_u64(v: u64) -> Option<Self> {
+                Some(v as Self)
+            }
+
+            fn from_i128(v: i128) -> Option<Self> {
+                Some(v as Self)
+            }
+
+            fn from_u128(v: u128) -> Option<Self> {
+                Some(v as Self)
+            }
+
+            fn from_f64(v: f64) -> Option<Self> {
+                Some(v as Self)
+            }
+        }
+    )*};
+}
+number_float!(f32 f64);
+
+macro_rules! number_non_zero {
+    ($($ty:ty => $inner:ty, $pattern:literal;)*) => {$(
+        impl Number for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const PATTERN: &'static str = $pattern;
+
+            fn is_safe(&self) -> bool {
+                self.get().is_safe()
+            }
+
+            fn from_i64(v: i64) -> Option<Self> {
+                <$inner>::from_i64(v).and_then(Self::new)
+            }
+
+            fn from_u64(v: u64) -> Option<Self> {
+                <$inner>::from_u64(v).and_then(Self::new)
+            }
+
+            fn from_i128(v: i128) -> Option<Self> {
+                <$inner>::from_i128(v).and_then(Self::new)
+            }
+
+            fn from_u128(v: u128) -> Option<Self> {
+                <$inner>::from_u128(v).and_then(Self::new)
+            }
+
+            fn from_f64(_v: f64) -> Option<Self> {
+                None
+            }
+        }
+    )*};
+}
+number_non_zero! {
+    NonZeroI8 => i8, "^[+-]?0*[1-9][0-9]*$";
+    NonZeroI16 => i16, "^[+-]?0*[1-9][0-9]*$";
+    NonZeroI32 => i32, "^[+-]?0*[1-9][0-9]*$";
+    NonZeroI64 => i64, "^[+-]?0*[1-9][0-9]*$";
+    NonZeroI128 => i128, "^[+-]?0*[1-9][0-9]*$";
+    NonZeroIsize => isize, "^[+-]?0*[1-9][0-9]*$";
+    NonZeroU8 => u8, "^\\+?0*[1-9][0-9]*$";
+    NonZeroU16 => u16, "^\\+?0*[1-9][0-9]*$";
+    NonZeroU32 => u32, "^\\+?0*[1-9][0-9]*$";
+    NonZeroU64 => u64, "^\\+?0*[1-9][0-9]*$";
+    NonZeroU128 => u128, "^\\+?0*[1-9][0-9]*$";
+    NonZeroUsize => usize, "^\\+?0*[1-9][0-9]*$";
+}
+
+impl<T, MODE> SerializeAs<T> for NumberAsString<MODE>
+where
+    T: Number,
+    MODE: formats::NumberRepresentation,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if MODE::as_string(source.is_safe()) {
+            serializer.collect_str(source)
+        } else {
+            source.serialize(serializer)
+        }
+    }
+}
+
+impl<'de, T, MODE> DeserializeAs<'de, T> for NumberAsString<MODE>
+where
+    T: Number,
+    MODE: formats::NumberRepresentation,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper<T>(PhantomData<T>);
+
+        impl<T> Visitor<'_> for Helper<T>
+        where
+            T: Number,
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "{} as a number or a string", T::NAME)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                T::from_i64(v).ok_or_else(|| DeError::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                T::from_u64(v).ok_or_else(|| DeError::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                T::from_i128(v).ok_or_else(|| {
+                    DeError::custom(format_args!(
+                        "invalid value: integer `{v}`, expected {} as a number or a string",
+                        T::NAME
+                    ))
+                })
+            }
+
+            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                T::from_u128(v).ok_or_else(|| {
+                    DeError::custom(format_args!(
+                        "invalid value: integer `{v}`, expected {} as a number or a string",
+                        T::NAME
+                    ))
+                })
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                T::from_f64(v).ok_or_else(|| DeError::invalid_type(Unexpected::Float(v), &self))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                v.parse()
+                    .map_err(|_| DeError::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(Helper(PhantomData))
+    }
+}
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+/// Hide secret values during serialization, while deserializing the real value
+///
+/// Structs containing passwords or tokens are often serialized into logs or audit trails, where the secrets must not appear.
+/// This adapter serializes a replacement instead of the value, which is controlled by the `MASK` parameter.
+/// Deserialization is unaffected and uses the `T` adapter, which by default is [`Same`].
+///
+/// * [`Placeholder`] (default): Serialize the fixed string `[REDACTED]`.
+/// * [`LastChars<N>`]: Serialize `****` and the last `N` characters of a string, e.g., for credit card numbers.
+///   Strings with fewer than `2 * N` characters are hidden completely, but longer strings reveal their last characters, which also narrows down the rest of the secret.
+/// * [`CorrelationHash`]: Serialize a non-cryptographic hash, which allows correlating equal values.
+///   It is only suited for values, which must not appear verbatim but are no secrets, like email addresses.
+///
+/// Custom replacements can be added by implementing the [`Mask`] trait.
+///
+/// Use [`IfIsHumanReadable`] to only redact the value for human-readable formats.
+/// With `IfIsHumanReadable<Redacted, Same>` compact binary formats keep the real value, e.g., when storing the data.
+///
+/// The replacement cannot be deserialized again.
+/// Data serialized with this adapter does not round-trip.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_with::{
+///     formats::{CorrelationHash, LastChars},
+///     serde_as, IfIsHumanReadable, Redacted, Same,
+/// };
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Login {
+///     user: String,
+///     #[serde_as(as = "Redacted")]
+///     password: String,
+///     #[serde_as(as = "Redacted<_, LastChars<4>>")]
+///     card: String,
+///     #[serde_as(as = "IfIsHumanReadable<Redacted<_, CorrelationHash>, Same>")]
+///     email: String,
+/// }
+///
+/// let login = Login {
+///     user: "alice".into(),
+///     password: "hunter2".into(),
+///     card: "4111111111111111".into(),
+///     email: "alice@example.com".into(),
+/// };
+/// assert_eq!(
+///     json!({
+///         "user": "alice",
+///         "password": "[REDACTED]",
+///         "card": "****1111",
+///         "email": "67023fc4a7ff2a46",
+///     }),
+///     serde_json::to_value(&login).unwrap(),
+/// );
+///
+/// // Deserialization reads the real values
+/// let json = json!({
+///     "user": "alice",
+///     "password": "hunter2",
+///     "card": "4111111111111111",
+///     "email": "alice@example.com",
+/// });
+/// assert_eq!(login, serde_json::from_value(json).unwrap());
+/// # }
+/// ```
+///
+/// [`CorrelationHash`]: crate::formats::CorrelationHash
+/// [`LastChars<N>`]: crate::formats::LastChars
+/// [`Mask`]: crate::formats::Mask
+/// [`Placeholder`]: crate::formats::Placeholder
+pub struct Redacted<T = Same, MASK = formats::Placeholder>(PhantomData<(T, MASK)>);
+
+impl<T, U, MASK> SerializeAs<T> for Redacted<U, MASK>
+where
+    T: ?Sized,
+    MASK: formats::Mask<T>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        MASK::serialize_masked(source, serializer)
+    }
+}
+
+impl<'de, T, U, MASK> DeserializeAs<'de, T> for Redacted<U, MASK>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        U::deserialize_as(deserializer)
+    }
+}
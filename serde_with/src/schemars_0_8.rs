@@ -6,14 +6,19 @@
 //! see [`JsonSchemaAs`].
 
 use crate::{
-    formats::{Flexible, Format, PreferMany, PreferOne, Separator, Strict},
+    formats::{
+        DollarKey, Duplicates, Flexible, Format, KeyField, NumberRepresentation, Overflow,
+        PreferMany, PreferNone, PreferOne, Rounding, Separator, Strict, Strictness,
+    },
+    number_as_string::Number,
     prelude::{Schema as WrapSchema, *},
+    utils::{Float, Integer},
 };
 use ::schemars_0_8::{
     gen::SchemaGenerator,
     schema::{
         ArrayValidation, InstanceType, Metadata, NumberValidation, ObjectValidation, Schema,
-        SchemaObject, SingleOrVec, SubschemaValidation,
+        SchemaObject, SingleOrVec, StringValidation, SubschemaValidation,
     },
     JsonSchema,
 };
@@ -404,6 +409,31 @@ impl<T> JsonSchemaAs<T> for DisplayFromStr {
     forward_schema!(String);
 }
 
+#[cfg(feature = "base32")]
+impl<T, A: base32::Alphabet, F: Format> JsonSchemaAs<T> for base32::Base32<A, F> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "Base32<A, F>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "serde_with::base32::Base32<A, F>".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            // See <https://json-schema.org/draft/2020-12/draft-bhutton-json-schema-validation-00#rfc.section.8.3>
+            extensions: [("contentEncoding".to_string(), serde_json::json!("base32"))].into(),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 #[cfg(feature = "base58")]
 impl<T, A: base58::Alphabet> JsonSchemaAs<T> for base58::Base58<A> {
     fn is_referenceable() -> bool {
@@ -454,17 +484,46 @@ impl<T, A: base64::Alphabet, F: Format> JsonSchemaAs<T> for base64::Base64<A, F>
 }
 
 #[cfg(feature = "hex")]
-impl<T, F: Format> JsonSchemaAs<T> for hex::Hex<F> {
+impl<T, F: Format, S: Separator> JsonSchemaAs<T> for hex::Hex<F, S, formats::Unprefixed> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "Hex<F, S>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "serde_with::hex::Hex<F, S>".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        use ::schemars_0_8::schema::StringValidation;
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(hex::schema_pattern(S::separator(), false)),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "hex")]
+impl<T, F: Format, S: Separator> JsonSchemaAs<T> for hex::Hex<F, S, formats::Prefixed> {
     fn is_referenceable() -> bool {
         false
     }
 
     fn schema_name() -> String {
-        "Hex<F>".into()
+        "Hex<F, S, Prefixed>".into()
     }
 
     fn schema_id() -> Cow<'static, str> {
-        "serde_with::hex::Hex<F>".into()
+        "serde_with::hex::Hex<F, S, Prefixed>".into()
     }
 
     fn json_schema(_: &mut SchemaGenerator) -> Schema {
@@ -473,7 +532,7 @@ impl<T, F: Format> JsonSchemaAs<T> for hex::Hex<F> {
         SchemaObject {
             instance_type: Some(InstanceType::String.into()),
             string: Some(Box::new(StringValidation {
-                pattern: Some(r"^(?:[0-9A-Fa-f]{2})*$".to_owned()),
+                pattern: Some(hex::schema_pattern(S::separator(), true)),
                 ..Default::default()
             })),
             ..Default::default()
@@ -531,6 +590,51 @@ impl JsonSchemaAs<bool> for BoolFromInt<Flexible> {
     }
 }
 
+impl<T> JsonSchemaAs<T> for IntFromFloat<Strict>
+where
+    T: JsonSchema + Integer,
+{
+    forward_schema!(T);
+}
+
+impl<T, ROUNDING, OVERFLOW> JsonSchemaAs<T> for IntFromFloat<Flexible, ROUNDING, OVERFLOW>
+where
+    T: Integer,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "IntFromFloat<Flexible>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "serde_with::IntFromFloat<Flexible>".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::Number.into()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+impl<T, STRICTNESS, ROUNDING, OVERFLOW> JsonSchemaAs<T>
+    for FloatFromInt<STRICTNESS, ROUNDING, OVERFLOW>
+where
+    T: Float,
+    STRICTNESS: Strictness,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    forward_schema!(i64);
+}
+
 impl<'a, T: 'a> JsonSchemaAs<Cow<'a, T>> for BorrowCow
 where
     T: ?Sized + ToOwned,
@@ -644,6 +748,92 @@ where
     forward_schema!(WrapSchema<BTreeMap<K, V>, BTreeMap<KA, VA>>);
 }
 
+impl<T> JsonSchemaAs<T> for EnumAsInt<Strict>
+where
+    T: IntEnum,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "EnumAsInt<Strict>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::EnumAsInt<{}, Strict>",
+            core::any::type_name::<T>()
+        )
+        .into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        int_enum_schema::<T>().into()
+    }
+}
+
+impl<T> JsonSchemaAs<T> for EnumAsInt<Flexible>
+where
+    T: IntEnum,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "EnumAsInt<Flexible>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::EnumAsInt<{}, Flexible>",
+            core::any::type_name::<T>()
+        )
+        .into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let names = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            enum_values: (!T::HAS_FALLBACK).then(|| {
+                T::VARIANTS
+                    .iter()
+                    .map(|(name, _)| serde_json::Value::from(*name))
+                    .collect()
+            }),
+            metadata: Some(Box::new(Metadata {
+                write_only: true,
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(std::vec![int_enum_schema::<T>().into(), names.into()]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// Schema of the discriminants, which only lists them if there is no fallback variant.
+fn int_enum_schema<T: IntEnum>() -> SchemaObject {
+    SchemaObject {
+        instance_type: Some(InstanceType::Integer.into()),
+        enum_values: (!T::HAS_FALLBACK).then(|| {
+            T::VARIANTS
+                .iter()
+                .filter_map(|(_, value)| serde_json::to_value(value).ok())
+                .collect()
+        }),
+        ..Default::default()
+    }
+}
+
 impl<T> JsonSchemaAs<Vec<T>> for EnumMap
 where
     T: JsonSchema,
@@ -694,15 +884,16 @@ where
     }
 }
 
-impl<T, TA> WrapSchema<Vec<T>, KeyValueMap<TA>>
+impl<T, TA, KEY> WrapSchema<Vec<T>, KeyValueMap<TA, KEY>>
 where
     TA: JsonSchemaAs<T>,
+    KEY: KeyField,
 {
     /// Transform a schema from the entry type of a `KeyValueMap<T>` to the
     /// resulting field type.
     ///
     /// This usually means doing one of two things:
-    /// 1. removing the `$key$` property from an object, or,
+    /// 1. removing the key field property, usually `$key$`, from an object, or,
     /// 2. removing the first item from an array.
     ///
     /// We also need to adjust any fields that control the number of items or
@@ -768,11 +959,11 @@ where
         };
 
         if let Some(object) = &mut schema.object {
-            // For objects KeyValueMap uses the $key$ property so we need to remove it from
+            // For objects KeyValueMap uses the key field property so we need to remove it from
             // the inner schema.
 
-            done |= object.properties.remove("$key$").is_some();
-            done |= object.required.remove("$key$");
+            done |= object.properties.remove(KEY::NAME).is_some();
+            done |= object.required.remove(KEY::NAME);
 
             if let Some(max) = &mut object.max_properties {
                 *max = max.saturating_sub(1);
@@ -835,9 +1026,10 @@ where
     }
 }
 
-impl<T, TA> JsonSchemaAs<Vec<T>> for KeyValueMap<TA>
+impl<T, TA, KEY> JsonSchemaAs<Vec<T>> for KeyValueMap<TA, KEY>
 where
     TA: JsonSchemaAs<T>,
+    KEY: KeyField,
 {
     fn is_referenceable() -> bool {
         true
@@ -848,16 +1040,26 @@ where
     }
 
     fn schema_id() -> Cow<'static, str> {
-        std::format!(
-            "serde_with::KeyValueMap({})",
-            <WrapSchema<T, TA>>::schema_id()
-        )
-        .into()
+        // Keep the id of the default key, which was used before the key was configurable
+        if KEY::NAME == DollarKey::NAME {
+            std::format!(
+                "serde_with::KeyValueMap({})",
+                <WrapSchema<T, TA>>::schema_id()
+            )
+            .into()
+        } else {
+            std::format!(
+                "serde_with::KeyValueMap({},{})",
+                <WrapSchema<T, TA>>::schema_id(),
+                KEY::NAME
+            )
+            .into()
+        }
     }
 
     fn json_schema(gen: &mut SchemaGenerator) -> Schema {
         let mut value = <WrapSchema<T, TA>>::json_schema(gen);
-        <WrapSchema<Vec<T>, KeyValueMap<TA>>>::kvmap_transform_schema_0_8(gen, &mut value);
+        <WrapSchema<Vec<T>, KeyValueMap<TA, KEY>>>::kvmap_transform_schema_0_8(gen, &mut value);
 
         SchemaObject {
             instance_type: Some(InstanceType::Object.into()),
@@ -893,6 +1095,44 @@ macro_rules! map_first_last_wins_schema {
         {
             forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
         }
+
+        impl<K, V, $($extra,)? KA, VA> JsonSchemaAs<$type> for MapCollectErrors<KA, VA>
+        where
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+        }
+
+        impl<K, V, $($extra,)? KA, VA, ORDER> JsonSchemaAs<$type> for SortedMap<KA, VA, ORDER>
+        where
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+        }
+
+        impl<K, V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMap<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
+
+        impl<K, V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMapFirstKeyWins<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
+
+        impl<K, V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMapPreventDuplicates<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
     }
 }
 
@@ -911,9 +1151,10 @@ map_first_last_wins_schema!(=> S indexmap_1::IndexMap<K, V, S>);
 #[cfg(feature = "indexmap_2")]
 map_first_last_wins_schema!(=> S indexmap_2::IndexMap<K, V, S>);
 
-impl<T, TA> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferOne>
+impl<T, TA, DUPLICATES> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferOne, DUPLICATES>
 where
     TA: JsonSchemaAs<T>,
+    DUPLICATES: Duplicates,
 {
     fn schema_name() -> String {
         std::format!(
@@ -952,9 +1193,10 @@ where
     }
 }
 
-impl<T, TA> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferMany>
+impl<T, TA, DUPLICATES> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferMany, DUPLICATES>
 where
     TA: JsonSchemaAs<T>,
+    DUPLICATES: Duplicates,
 {
     fn schema_name() -> String {
         std::format!(
@@ -1004,6 +1246,52 @@ where
     }
 }
 
+impl<T, TA, DUPLICATES> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferNone, DUPLICATES>
+where
+    TA: JsonSchemaAs<T>,
+    DUPLICATES: Duplicates,
+{
+    fn schema_name() -> String {
+        std::format!(
+            "OneOrMany<{}, PreferNone>",
+            <WrapSchema<T, TA>>::schema_name()
+        )
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::OneOrMany<{}, PreferNone>",
+            <WrapSchema<T, TA>>::schema_id()
+        )
+        .into()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let single = gen.subschema_for::<WrapSchema<T, TA>>();
+        let null = SchemaObject {
+            instance_type: Some(InstanceType::Null.into()),
+            ..Default::default()
+        };
+        let array = SchemaObject {
+            instance_type: Some(InstanceType::Array.into()),
+            array: Some(Box::new(ArrayValidation {
+                items: Some(single.clone().into()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(std::vec![null.into(), single, array.into()]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 macro_rules! schema_for_pickfirst {
     ($( $param:ident )+) => {
         impl<T, $($param,)+> JsonSchemaAs<T> for PickFirst<($( $param, )+)>
@@ -1122,6 +1410,29 @@ macro_rules! map_first_last_wins_schema {
         {
             forward_schema!(BTreeSet<WrapSchema<V, VA>>);
         }
+
+        impl<V, $($extra,)? VA, ORDER> JsonSchemaAs<$type> for SortedSet<VA, ORDER>
+        where
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeSet<WrapSchema<V, VA>>);
+        }
+
+        impl<V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMap<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
+
+        impl<V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMapPreventDuplicates<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
     }
 }
 
@@ -1148,6 +1459,187 @@ where
     forward_schema!(String);
 }
 
+impl<T, TA> JsonSchemaAs<T> for Canonical<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA, MATCHING> JsonSchemaAs<T> for CaseInsensitive<TA, MATCHING>
+where
+    TA: JsonSchemaAs<T>,
+    MATCHING: formats::IdentifierMatching,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA> JsonSchemaAs<T> for Trimmed<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA> JsonSchemaAs<T> for Lowercased<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA, MASK> JsonSchemaAs<T> for Redacted<TA, MASK>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::Redacted<{}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> String {
+        <WrapSchema<T, TA> as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = <WrapSchema<T, TA> as JsonSchema>::json_schema(gen).into_object();
+        // The real value is only ever deserialized and never serialized.
+        schema.metadata().write_only = true;
+        schema.into()
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+impl<T, TA, const MIN: i128, const MAX: i128> JsonSchemaAs<T> for Bounded<MIN, MAX, TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::Bounded<{MIN}, {MAX}, {}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> String {
+        <WrapSchema<T, TA> as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = <WrapSchema<T, TA> as JsonSchema>::json_schema(gen).into_object();
+        if schema.has_type(InstanceType::Integer) || schema.has_type(InstanceType::Number) {
+            if MIN != i128::MIN {
+                schema.number().minimum = Some(MIN as f64);
+            }
+            if MAX != i128::MAX {
+                schema.number().maximum = Some(MAX as f64);
+            }
+        }
+        schema.into()
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+impl<T, TA, const MIN: usize, const MAX: usize> JsonSchemaAs<T> for Length<MIN, MAX, TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        std::format!(
+            "serde_with::Length<{MIN}, {MAX}, {}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> String {
+        <WrapSchema<T, TA> as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = <WrapSchema<T, TA> as JsonSchema>::json_schema(gen).into_object();
+        let min = (MIN != 0).then(|| u32::try_from(MIN).unwrap_or(u32::MAX));
+        let max = (MAX != usize::MAX).then(|| u32::try_from(MAX).unwrap_or(u32::MAX));
+        if schema.has_type(InstanceType::String) {
+            let string = schema.string();
+            string.min_length = min.or(string.min_length);
+            string.max_length = max.or(string.max_length);
+        }
+        if schema.has_type(InstanceType::Array) {
+            let array = schema.array();
+            array.min_items = min.or(array.min_items);
+            array.max_items = max.or(array.max_items);
+        }
+        if schema.has_type(InstanceType::Object) {
+            let object = schema.object();
+            object.min_properties = min.or(object.min_properties);
+            object.max_properties = max.or(object.max_properties);
+        }
+        schema.into()
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+impl<T, TA> JsonSchemaAs<T> for NonEmpty<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, Length<1, { usize::MAX }, TA>>);
+}
+
+impl<T, MODE> JsonSchemaAs<T> for NumberAsString<MODE>
+where
+    T: JsonSchema + Number,
+    MODE: NumberRepresentation,
+{
+    fn schema_id() -> Cow<'static, str> {
+        std::format!("serde_with::NumberAsString<{}>", T::schema_id()).into()
+    }
+
+    fn schema_name() -> String {
+        std::format!("NumberAsString<{}>", T::schema_name())
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                one_of: Some(std::vec![
+                    T::json_schema(gen),
+                    SchemaObject {
+                        instance_type: Some(InstanceType::String.into()),
+                        string: Some(Box::new(StringValidation {
+                            pattern: Some(T::PATTERN.into()),
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    }
+                    .into(),
+                ]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
 impl<T, TA> JsonSchemaAs<Vec<T>> for VecSkipError<TA>
 where
     TA: JsonSchemaAs<T>,
@@ -1155,6 +1647,27 @@ where
     forward_schema!(Vec<WrapSchema<T, TA>>);
 }
 
+impl<T, TA> JsonSchemaAs<Vec<T>> for SeqSkipError<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
+impl<T, TA, LIMIT> JsonSchemaAs<Vec<T>> for BoundedSkipError<TA, LIMIT>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
+impl<T, TA> JsonSchemaAs<Vec<T>> for CollectErrors<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
 mod timespan {
     use super::*;
 
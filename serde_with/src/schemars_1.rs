@@ -6,9 +6,13 @@
 //! see [`JsonSchemaAs`].
 
 use crate::{
-    formats::{Flexible, Format, PreferMany, PreferOne, Separator, Strict},
+    formats::{
+        DollarKey, Duplicates, Flexible, Format, KeyField, NumberRepresentation, Overflow,
+        PreferMany, PreferNone, PreferOne, Rounding, Separator, Strict, Strictness,
+    },
+    number_as_string::Number,
     prelude::{Schema as WrapSchema, *},
-    utils::NumberExt as _,
+    utils::{Float, Integer, NumberExt as _},
 };
 use ::schemars_1::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use alloc::{
@@ -408,6 +412,29 @@ impl<T> JsonSchemaAs<T> for DisplayFromStr {
     forward_schema!(String);
 }
 
+#[cfg(feature = "base32")]
+impl<T, A: base32::Alphabet, F: Format> JsonSchemaAs<T> for base32::Base32<A, F> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Base32<A, F>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "serde_with::base32::Base32<A, F>".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            // See <https://json-schema.org/draft/2020-12/draft-bhutton-json-schema-validation-00#rfc.section.8.3>
+            "contentEncoding": "base32",
+        })
+    }
+}
+
 #[cfg(feature = "base58")]
 impl<T, A: base58::Alphabet> JsonSchemaAs<T> for base58::Base58<A> {
     fn inline_schema() -> bool {
@@ -454,23 +481,45 @@ impl<T, A: base64::Alphabet, F: Format> JsonSchemaAs<T> for base64::Base64<A, F>
 }
 
 #[cfg(feature = "hex")]
-impl<T, F: Format> JsonSchemaAs<T> for hex::Hex<F> {
+impl<T, F: Format, S: Separator> JsonSchemaAs<T> for hex::Hex<F, S, formats::Unprefixed> {
     fn inline_schema() -> bool {
         true
     }
 
     fn schema_name() -> Cow<'static, str> {
-        "Hex<F>".into()
+        "Hex<F, S>".into()
     }
 
     fn schema_id() -> Cow<'static, str> {
-        "serde_with::hex::Hex<F>".into()
+        "serde_with::hex::Hex<F, S>".into()
     }
 
     fn json_schema(_: &mut SchemaGenerator) -> Schema {
         json_schema!({
             "type": "string",
-            "pattern": r"^(?:[0-9A-Fa-f]{2})*$",
+            "pattern": hex::schema_pattern(S::separator(), false),
+        })
+    }
+}
+
+#[cfg(feature = "hex")]
+impl<T, F: Format, S: Separator> JsonSchemaAs<T> for hex::Hex<F, S, formats::Prefixed> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Hex<F, S, Prefixed>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "serde_with::hex::Hex<F, S, Prefixed>".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": hex::schema_pattern(S::separator(), true),
         })
     }
 }
@@ -517,6 +566,49 @@ impl JsonSchemaAs<bool> for BoolFromInt<Flexible> {
     }
 }
 
+impl<T> JsonSchemaAs<T> for IntFromFloat<Strict>
+where
+    T: JsonSchema + Integer,
+{
+    forward_schema!(T);
+}
+
+impl<T, ROUNDING, OVERFLOW> JsonSchemaAs<T> for IntFromFloat<Flexible, ROUNDING, OVERFLOW>
+where
+    T: Integer,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "IntFromFloat<Flexible>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "serde_with::IntFromFloat<Flexible>".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+        })
+    }
+}
+
+impl<T, STRICTNESS, ROUNDING, OVERFLOW> JsonSchemaAs<T>
+    for FloatFromInt<STRICTNESS, ROUNDING, OVERFLOW>
+where
+    T: Float,
+    STRICTNESS: Strictness,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    forward_schema!(i64);
+}
+
 impl<'a, T: 'a> JsonSchemaAs<Cow<'a, T>> for BorrowCow
 where
     T: ?Sized + ToOwned,
@@ -623,6 +715,92 @@ where
     forward_schema!(WrapSchema<BTreeMap<K, V>, BTreeMap<KA, VA>>);
 }
 
+impl<T> JsonSchemaAs<T> for EnumAsInt<Strict>
+where
+    T: IntEnum,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "EnumAsInt<Strict>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "serde_with::EnumAsInt<{}, Strict>",
+            core::any::type_name::<T>()
+        )
+        .into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        int_enum_schema::<T>()
+    }
+}
+
+impl<T> JsonSchemaAs<T> for EnumAsInt<Flexible>
+where
+    T: IntEnum,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "EnumAsInt<Flexible>".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "serde_with::EnumAsInt<{}, Flexible>",
+            core::any::type_name::<T>()
+        )
+        .into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let mut names = json_schema!({
+            "type": "string",
+            "writeOnly": true
+        });
+        if !T::HAS_FALLBACK {
+            let names_enum = T::VARIANTS
+                .iter()
+                .map(|(name, _)| Value::from(*name))
+                .collect();
+            names
+                .ensure_object()
+                .insert("enum".into(), Value::Array(names_enum));
+        }
+
+        json_schema!({
+            "anyOf": [
+                int_enum_schema::<T>(),
+                names
+            ]
+        })
+    }
+}
+
+/// Schema of the discriminants, which only lists them if there is no fallback variant.
+fn int_enum_schema<T: IntEnum>() -> Schema {
+    let mut schema = json_schema!({
+        "type": "integer"
+    });
+    if !T::HAS_FALLBACK {
+        let values = T::VARIANTS
+            .iter()
+            .filter_map(|(_, value)| serde_json::to_value(value).ok())
+            .collect();
+        schema
+            .ensure_object()
+            .insert("enum".into(), Value::Array(values));
+    }
+    schema
+}
+
 impl<T> JsonSchemaAs<Vec<T>> for EnumMap
 where
     T: JsonSchema,
@@ -675,15 +853,16 @@ where
     }
 }
 
-impl<T, TA> WrapSchema<Vec<T>, KeyValueMap<TA>>
+impl<T, TA, KEY> WrapSchema<Vec<T>, KeyValueMap<TA, KEY>>
 where
     TA: JsonSchemaAs<T>,
+    KEY: KeyField,
 {
     /// Transform a schema from the entry type of a `KeyValueMap<T>` to the
     /// resulting field type.
     ///
     /// This usually means doing one of two things:
-    /// 1. removing the `$key$` property from an object, or,
+    /// 1. removing the key field property, usually `$key$`, from an object, or,
     /// 2. removing the first item from an array.
     ///
     /// We also need to adjust any fields that control the number of items or
@@ -771,16 +950,16 @@ where
         };
 
         if ty == Some("object") {
-            // For objects KeyValueMap uses the $key$ property so we need to remove it from
+            // For objects KeyValueMap uses the key field property so we need to remove it from
             // the inner schema.
 
             if let Some(Value::Object(properties)) = schema.get_mut("properties") {
-                done |= properties.remove("$key$").is_some();
+                done |= properties.remove(KEY::NAME).is_some();
             }
 
             if let Some(Value::Array(required)) = schema.get_mut("required") {
                 required.retain(|req| match req {
-                    Value::String(key) if key == "$key$" => {
+                    Value::String(key) if key == KEY::NAME => {
                         done = true;
                         false
                     }
@@ -869,25 +1048,36 @@ where
     }
 }
 
-impl<T, TA> JsonSchemaAs<Vec<T>> for KeyValueMap<TA>
+impl<T, TA, KEY> JsonSchemaAs<Vec<T>> for KeyValueMap<TA, KEY>
 where
     TA: JsonSchemaAs<T>,
+    KEY: KeyField,
 {
     fn schema_name() -> Cow<'static, str> {
         format!("KeyValueMap({})", <WrapSchema<T, TA>>::schema_name()).into()
     }
 
     fn schema_id() -> Cow<'static, str> {
-        format!(
-            "serde_with::KeyValueMap({})",
-            <WrapSchema<T, TA>>::schema_id()
-        )
-        .into()
+        // Keep the id of the default key, which was used before the key was configurable
+        if KEY::NAME == DollarKey::NAME {
+            format!(
+                "serde_with::KeyValueMap({})",
+                <WrapSchema<T, TA>>::schema_id()
+            )
+            .into()
+        } else {
+            format!(
+                "serde_with::KeyValueMap({},{})",
+                <WrapSchema<T, TA>>::schema_id(),
+                KEY::NAME
+            )
+            .into()
+        }
     }
 
     fn json_schema(g: &mut SchemaGenerator) -> Schema {
         let mut value = <WrapSchema<T, TA>>::json_schema(g);
-        <WrapSchema<Vec<T>, KeyValueMap<TA>>>::kvmap_transform_schema_1(g, &mut value);
+        <WrapSchema<Vec<T>, KeyValueMap<TA, KEY>>>::kvmap_transform_schema_1(g, &mut value);
 
         json_schema!({
             "type": "object",
@@ -921,6 +1111,46 @@ macro_rules! map_first_last_wins_schema {
         {
             forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
         }
+
+        impl<K, V, $($extra,)? KA, VA> JsonSchemaAs<$type> for MapCollectErrors<KA, VA>
+        where
+            KA: JsonSchemaAs<K>,
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+        }
+
+        impl<K, V, $($extra,)? KA, VA, ORDER> JsonSchemaAs<$type> for SortedMap<KA, VA, ORDER>
+        where
+            KA: JsonSchemaAs<K>,
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeMap<WrapSchema<K, KA>, WrapSchema<V, VA>>);
+        }
+
+        impl<K, V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMap<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
+
+        impl<K, V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMapFirstKeyWins<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
+
+        impl<K, V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMapPreventDuplicates<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
     }
 }
 
@@ -940,9 +1170,10 @@ map_first_last_wins_schema!(=> S indexmap_1::IndexMap<K, V, S>);
 #[cfg(feature = "indexmap_2")]
 map_first_last_wins_schema!(=> S indexmap_2::IndexMap<K, V, S>);
 
-impl<T, TA> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferOne>
+impl<T, TA, DUPLICATES> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferOne, DUPLICATES>
 where
     TA: JsonSchemaAs<T>,
+    DUPLICATES: Duplicates,
 {
     fn schema_name() -> Cow<'static, str> {
         format!(
@@ -975,9 +1206,10 @@ where
     }
 }
 
-impl<T, TA> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferMany>
+impl<T, TA, DUPLICATES> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferMany, DUPLICATES>
 where
     TA: JsonSchemaAs<T>,
+    DUPLICATES: Duplicates,
 {
     fn schema_name() -> Cow<'static, str> {
         format!(
@@ -1015,6 +1247,45 @@ where
     }
 }
 
+impl<T, TA, DUPLICATES> JsonSchemaAs<Vec<T>> for OneOrMany<TA, PreferNone, DUPLICATES>
+where
+    TA: JsonSchemaAs<T>,
+    DUPLICATES: Duplicates,
+{
+    fn schema_name() -> Cow<'static, str> {
+        format!(
+            "OneOrMany<{}, PreferNone>",
+            <WrapSchema<T, TA>>::schema_name()
+        )
+        .into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "serde_with::OneOrMany<{}, PreferNone>",
+            <WrapSchema<T, TA>>::schema_id()
+        )
+        .into()
+    }
+
+    fn json_schema(g: &mut SchemaGenerator) -> Schema {
+        let single = g.subschema_for::<WrapSchema<T, TA>>();
+
+        json_schema!({
+            "anyOf": [
+                {
+                    "type": "null"
+                },
+                single,
+                {
+                    "type": "array",
+                    "items": single
+                }
+            ]
+        })
+    }
+}
+
 macro_rules! schema_for_pickfirst {
     ($( $param:ident )+) => {
         impl<T, $($param,)+> JsonSchemaAs<T> for PickFirst<($( $param, )+)>
@@ -1120,6 +1391,29 @@ macro_rules! map_first_last_wins_schema {
         {
             forward_schema!(BTreeSet<WrapSchema<V, VA>>);
         }
+
+        impl<V, $($extra,)? VA, ORDER> JsonSchemaAs<$type> for SortedSet<VA, ORDER>
+        where
+            VA: JsonSchemaAs<V>,
+        {
+            forward_schema!(BTreeSet<WrapSchema<V, VA>>);
+        }
+
+        impl<V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMap<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
+
+        impl<V, $($extra,)? VA, KEY> JsonSchemaAs<$type> for KeyValueMapPreventDuplicates<VA, KEY>
+        where
+            VA: JsonSchemaAs<V>,
+            KEY: KeyField,
+        {
+            forward_schema!(WrapSchema<Vec<V>, KeyValueMap<VA, KEY>>);
+        }
     }
 }
 
@@ -1146,6 +1440,195 @@ where
     forward_schema!(String);
 }
 
+impl<T, TA> JsonSchemaAs<T> for Canonical<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA, MATCHING> JsonSchemaAs<T> for CaseInsensitive<TA, MATCHING>
+where
+    TA: JsonSchemaAs<T>,
+    MATCHING: formats::IdentifierMatching,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA> JsonSchemaAs<T> for Trimmed<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA> JsonSchemaAs<T> for Lowercased<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, TA>);
+}
+
+impl<T, TA, MASK> JsonSchemaAs<T> for Redacted<TA, MASK>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "serde_with::Redacted<{}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        <WrapSchema<T, TA> as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(g: &mut SchemaGenerator) -> Schema {
+        let mut schema = <WrapSchema<T, TA> as JsonSchema>::json_schema(g);
+        // The real value is only ever deserialized and never serialized.
+        schema
+            .ensure_object()
+            .insert("writeOnly".into(), true.into());
+        schema
+    }
+
+    fn inline_schema() -> bool {
+        true
+    }
+}
+
+impl<T, TA, const MIN: i128, const MAX: i128> JsonSchemaAs<T> for Bounded<MIN, MAX, TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "serde_with::Bounded<{MIN}, {MAX}, {}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        <WrapSchema<T, TA> as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(g: &mut SchemaGenerator) -> Schema {
+        let mut schema = <WrapSchema<T, TA> as JsonSchema>::json_schema(g);
+        let object = schema.ensure_object();
+        let is_number = match object.get("type") {
+            Some(Value::String(t)) => t == "integer" || t == "number",
+            Some(Value::Array(types)) => types.iter().any(|t| t == "integer" || t == "number"),
+            _ => false,
+        };
+        if is_number {
+            if MIN != i128::MIN {
+                object.insert("minimum".into(), bound_value(MIN));
+            }
+            if MAX != i128::MAX {
+                object.insert("maximum".into(), bound_value(MAX));
+            }
+        }
+        schema
+    }
+
+    fn inline_schema() -> bool {
+        true
+    }
+}
+
+/// Bounds outside of the `i64` and `u64` ranges are approximated by a float.
+fn bound_value(bound: i128) -> Value {
+    serde_json::Number::from_i128(bound).map_or_else(|| Value::from(bound as f64), Value::Number)
+}
+
+impl<T, TA, const MIN: usize, const MAX: usize> JsonSchemaAs<T> for Length<MIN, MAX, TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "serde_with::Length<{MIN}, {MAX}, {}>",
+            <WrapSchema<T, TA> as JsonSchema>::schema_id()
+        )
+        .into()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        <WrapSchema<T, TA> as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(g: &mut SchemaGenerator) -> Schema {
+        let mut schema = <WrapSchema<T, TA> as JsonSchema>::json_schema(g);
+        let object = schema.ensure_object();
+        let has_type = |ty: &str| match object.get("type") {
+            Some(Value::String(t)) => t == ty,
+            Some(Value::Array(types)) => types.iter().any(|t| t == ty),
+            _ => false,
+        };
+        let keywords: Vec<_> = [
+            ("string", "minLength", "maxLength"),
+            ("array", "minItems", "maxItems"),
+            ("object", "minProperties", "maxProperties"),
+        ]
+        .into_iter()
+        .filter(|(ty, _, _)| has_type(ty))
+        .collect();
+        for (_, min_keyword, max_keyword) in keywords {
+            if MIN != 0 {
+                object.insert(min_keyword.into(), MIN.into());
+            }
+            if MAX != usize::MAX {
+                object.insert(max_keyword.into(), MAX.into());
+            }
+        }
+        schema
+    }
+
+    fn inline_schema() -> bool {
+        true
+    }
+}
+
+impl<T, TA> JsonSchemaAs<T> for NonEmpty<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(WrapSchema<T, Length<1, { usize::MAX }, TA>>);
+}
+
+impl<T, MODE> JsonSchemaAs<T> for NumberAsString<MODE>
+where
+    T: JsonSchema + Number,
+    MODE: NumberRepresentation,
+{
+    fn schema_id() -> Cow<'static, str> {
+        format!("serde_with::NumberAsString<{}>", T::schema_id()).into()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("NumberAsString<{}>", T::schema_name()).into()
+    }
+
+    fn json_schema(g: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "oneOf": [
+                T::json_schema(g),
+                {
+                    "type": "string",
+                    "pattern": T::PATTERN
+                }
+            ]
+        })
+    }
+
+    fn inline_schema() -> bool {
+        true
+    }
+}
+
 impl<T, TA> JsonSchemaAs<Vec<T>> for VecSkipError<TA>
 where
     TA: JsonSchemaAs<T>,
@@ -1153,6 +1636,27 @@ where
     forward_schema!(Vec<WrapSchema<T, TA>>);
 }
 
+impl<T, TA> JsonSchemaAs<Vec<T>> for SeqSkipError<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
+impl<T, TA, LIMIT> JsonSchemaAs<Vec<T>> for BoundedSkipError<TA, LIMIT>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
+impl<T, TA> JsonSchemaAs<Vec<T>> for CollectErrors<TA>
+where
+    TA: JsonSchemaAs<T>,
+{
+    forward_schema!(Vec<WrapSchema<T, TA>>);
+}
+
 mod timespan {
     use super::*;
 
@@ -0,0 +1,290 @@
+use crate::{
+    content::ser::{Content, ContentSerializer},
+    prelude::*,
+};
+use core::cmp::Ordering;
+
+impl<T, U> SerializeAs<T> for Canonical<U>
+where
+    T: ?Sized,
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let human_readable = serializer.is_human_readable();
+        let content =
+            SerializeAsWrap::<T, U>::new(source)
+                .serialize(ContentSerializer::<S::Error>::new(human_readable))?;
+        canonicalize(content, human_readable)?.serialize(serializer)
+    }
+}
+
+/// Recursively sort all maps and normalize all floats.
+///
+/// Struct fields are only sorted for human-readable formats.
+/// Other formats, like bincode, identify the fields by their position, which must not change.
+fn canonicalize<E>(content: Content, human_readable: bool) -> Result<Content, E>
+where
+    E: SerError,
+{
+    Ok(match content {
+        Content::F32(f) => Content::F32(canonical_float(f, f32::is_finite)?),
+        Content::F64(f) => Content::F64(canonical_float(f, f64::is_finite)?),
+        Content::Some(inner) => Content::Some(Box::new(canonicalize(*inner, human_readable)?)),
+        Content::NewtypeStruct(name, inner) => {
+            Content::NewtypeStruct(name, Box::new(canonicalize(*inner, human_readable)?))
+        }
+        Content::NewtypeVariant(name, idx, variant, inner) => Content::NewtypeVariant(
+            name,
+            idx,
+            variant,
+            Box::new(canonicalize(*inner, human_readable)?),
+        ),
+        Content::Seq(elements) => Content::Seq(canonicalize_all(elements, human_readable)?),
+        Content::Tuple(elements) => Content::Tuple(canonicalize_all(elements, human_readable)?),
+        Content::TupleStruct(name, fields) => {
+            Content::TupleStruct(name, canonicalize_all(fields, human_readable)?)
+        }
+        Content::TupleVariant(name, idx, variant, fields) => Content::TupleVariant(
+            name,
+            idx,
+            variant,
+            canonicalize_all(fields, human_readable)?,
+        ),
+        Content::Map(entries) => {
+            let mut entries = entries
+                .into_iter()
+                .map(|(key, value)| {
+                    Ok((
+                        canonicalize(key, human_readable)?,
+                        canonicalize(value, human_readable)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, E>>()?;
+            entries.sort_by(|(a, _), (b, _)| cmp_key(a, b, human_readable));
+            if entries
+                .windows(2)
+                .any(|pair| cmp_key(&pair[0].0, &pair[1].0, human_readable) == Ordering::Equal)
+            {
+                return Err(SerError::custom(
+                    "Canonical cannot serialize a map with duplicate keys",
+                ));
+            }
+            Content::Map(entries)
+        }
+        Content::Struct(name, fields) => {
+            Content::Struct(name, canonicalize_fields(fields, human_readable)?)
+        }
+        Content::StructVariant(name, idx, variant, fields) => Content::StructVariant(
+            name,
+            idx,
+            variant,
+            canonicalize_fields(fields, human_readable)?,
+        ),
+        content => content,
+    })
+}
+
+fn canonicalize_all<E>(elements: Vec<Content>, human_readable: bool) -> Result<Vec<Content>, E>
+where
+    E: SerError,
+{
+    elements
+        .into_iter()
+        .map(|element| canonicalize(element, human_readable))
+        .collect()
+}
+
+fn canonicalize_fields<E>(
+    fields: Vec<(&'static str, Content)>,
+    human_readable: bool,
+) -> Result<Vec<(&'static str, Content)>, E>
+where
+    E: SerError,
+{
+    let mut fields = fields
+        .into_iter()
+        .map(|(key, value)| Ok((key, canonicalize(value, human_readable)?)))
+        .collect::<Result<Vec<_>, E>>()?;
+    if human_readable {
+        fields.sort_by(|(a, _), (b, _)| cmp_utf16(a, b));
+    }
+    Ok(fields)
+}
+
+/// Reject non-finite floats and turn `-0.0` into `0.0`.
+fn canonical_float<F, E>(f: F, is_finite: fn(F) -> bool) -> Result<F, E>
+where
+    F: Copy + Default + PartialEq + Display,
+    E: SerError,
+{
+    if !is_finite(f) {
+        return Err(SerError::custom(format_args!(
+            "Canonical cannot serialize the non-finite float {f}"
+        )));
+    }
+    // `-0.0 == 0.0`, so this replaces both zeros by the positive zero
+    if f == F::default() {
+        Ok(F::default())
+    } else {
+        Ok(f)
+    }
+}
+
+/// Order of map keys.
+///
+/// Human-readable formats, like JSON, serialize map keys as strings.
+/// Like the JSON Canonicalization Scheme (RFC 8785), these keys are sorted by the UTF-16 code units of their string form, such that `"10"` comes before `"2"`.
+/// Keys without a string form are sorted after them using [`cmp_content`].
+/// Other formats keep the type of the keys, so they are sorted using [`cmp_content`].
+fn cmp_key(a: &Content, b: &Content, human_readable: bool) -> Ordering {
+    if human_readable {
+        match (key_string(a), key_string(b)) {
+            (Some(a), Some(b)) => return cmp_utf16(&a, &b),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            (None, None) => {}
+        }
+    }
+    cmp_content(a, b)
+}
+
+/// Compare two strings by their UTF-16 code units, as required by RFC 8785.
+fn cmp_utf16(a: &str, b: &str) -> Ordering {
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+/// The string form of a map key in human-readable formats.
+fn key_string(key: &Content) -> Option<Cow<'_, str>> {
+    Some(match key {
+        Content::String(s) => Cow::Borrowed(s),
+        Content::Char(c) => Cow::Owned(c.to_string()),
+        Content::Bool(b) => Cow::Borrowed(if *b { "true" } else { "false" }),
+        Content::U8(n) => Cow::Owned(n.to_string()),
+        Content::U16(n) => Cow::Owned(n.to_string()),
+        Content::U32(n) => Cow::Owned(n.to_string()),
+        Content::U64(n) => Cow::Owned(n.to_string()),
+        Content::U128(n) => Cow::Owned(n.to_string()),
+        Content::I8(n) => Cow::Owned(n.to_string()),
+        Content::I16(n) => Cow::Owned(n.to_string()),
+        Content::I32(n) => Cow::Owned(n.to_string()),
+        Content::I64(n) => Cow::Owned(n.to_string()),
+        Content::I128(n) => Cow::Owned(n.to_string()),
+        Content::F32(f) => Cow::Owned(alloc::format!("{f:?}")),
+        Content::F64(f) => Cow::Owned(alloc::format!("{f:?}")),
+        Content::UnitVariant(_, _, variant) => Cow::Borrowed(variant),
+        Content::Some(inner) | Content::NewtypeStruct(_, inner) => return key_string(inner),
+        _ => return None,
+    })
+}
+
+/// Total order over [`Content`], used to sort map keys.
+///
+/// Values of different kinds are ordered by their kind, values of the same kind by their value.
+fn cmp_content(a: &Content, b: &Content) -> Ordering {
+    fn rank(content: &Content) -> u8 {
+        match content {
+            Content::Bool(_) => 0,
+            Content::U8(_) => 1,
+            Content::U16(_) => 2,
+            Content::U32(_) => 3,
+            Content::U64(_) => 4,
+            Content::U128(_) => 5,
+            Content::I8(_) => 6,
+            Content::I16(_) => 7,
+            Content::I32(_) => 8,
+            Content::I64(_) => 9,
+            Content::I128(_) => 10,
+            Content::F32(_) => 11,
+            Content::F64(_) => 12,
+            Content::Char(_) => 13,
+            Content::String(_) => 14,
+            Content::Bytes(_) => 15,
+            Content::None => 16,
+            Content::Some(_) => 17,
+            Content::Unit => 18,
+            Content::UnitStruct(_) => 19,
+            Content::UnitVariant(..) => 20,
+            Content::NewtypeStruct(..) => 21,
+            Content::NewtypeVariant(..) => 22,
+            Content::Seq(_) => 23,
+            Content::Tuple(_) => 24,
+            Content::TupleStruct(..) => 25,
+            Content::TupleVariant(..) => 26,
+            Content::Map(_) => 27,
+            Content::Struct(..) => 28,
+            Content::StructVariant(..) => 29,
+        }
+    }
+
+    fn cmp_seq(a: &[Content], b: &[Content]) -> Ordering {
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| cmp_content(a, b))
+            .find(|ord| ord.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len()))
+    }
+
+    fn cmp_fields(a: &[(&str, Content)], b: &[(&str, Content)]) -> Ordering {
+        a.iter()
+            .zip(b)
+            .map(|((ak, av), (bk, bv))| ak.cmp(bk).then_with(|| cmp_content(av, bv)))
+            .find(|ord| ord.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len()))
+    }
+
+    match (a, b) {
+        (Content::Bool(a), Content::Bool(b)) => a.cmp(b),
+        (Content::U8(a), Content::U8(b)) => a.cmp(b),
+        (Content::U16(a), Content::U16(b)) => a.cmp(b),
+        (Content::U32(a), Content::U32(b)) => a.cmp(b),
+        (Content::U64(a), Content::U64(b)) => a.cmp(b),
+        (Content::U128(a), Content::U128(b)) => a.cmp(b),
+        (Content::I8(a), Content::I8(b)) => a.cmp(b),
+        (Content::I16(a), Content::I16(b)) => a.cmp(b),
+        (Content::I32(a), Content::I32(b)) => a.cmp(b),
+        (Content::I64(a), Content::I64(b)) => a.cmp(b),
+        (Content::I128(a), Content::I128(b)) => a.cmp(b),
+        (Content::F32(a), Content::F32(b)) => a.total_cmp(b),
+        (Content::F64(a), Content::F64(b)) => a.total_cmp(b),
+        (Content::Char(a), Content::Char(b)) => a.cmp(b),
+        (Content::String(a), Content::String(b)) => a.cmp(b),
+        (Content::Bytes(a), Content::Bytes(b)) => a.cmp(b),
+        (Content::Some(a), Content::Some(b)) => cmp_content(a, b),
+        (Content::UnitStruct(a), Content::UnitStruct(b)) => a.cmp(b),
+        (Content::UnitVariant(an, ai, _), Content::UnitVariant(bn, bi, _)) => {
+            an.cmp(bn).then(ai.cmp(bi))
+        }
+        (Content::NewtypeStruct(an, a), Content::NewtypeStruct(bn, b)) => {
+            an.cmp(bn).then_with(|| cmp_content(a, b))
+        }
+        (Content::NewtypeVariant(an, ai, _, a), Content::NewtypeVariant(bn, bi, _, b)) => {
+            an.cmp(bn).then(ai.cmp(bi)).then_with(|| cmp_content(a, b))
+        }
+        (Content::Seq(a), Content::Seq(b)) | (Content::Tuple(a), Content::Tuple(b)) => {
+            cmp_seq(a, b)
+        }
+        (Content::TupleStruct(an, a), Content::TupleStruct(bn, b)) => {
+            an.cmp(bn).then_with(|| cmp_seq(a, b))
+        }
+        (Content::TupleVariant(an, ai, _, a), Content::TupleVariant(bn, bi, _, b)) => {
+            an.cmp(bn).then(ai.cmp(bi)).then_with(|| cmp_seq(a, b))
+        }
+        (Content::Map(a), Content::Map(b)) => a
+            .iter()
+            .zip(b)
+            .map(|((ak, av), (bk, bv))| cmp_content(ak, bk).then_with(|| cmp_content(av, bv)))
+            .find(|ord| ord.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (Content::Struct(an, a), Content::Struct(bn, b)) => {
+            an.cmp(bn).then_with(|| cmp_fields(a, b))
+        }
+        (Content::StructVariant(an, ai, _, a), Content::StructVariant(bn, bi, _, b)) => {
+            an.cmp(bn).then(ai.cmp(bi)).then_with(|| cmp_fields(a, b))
+        }
+        // Equal ranks for the remaining pairs imply both are `None` or `Unit`
+        (a, b) => rank(a).cmp(&rank(b)),
+    }
+}
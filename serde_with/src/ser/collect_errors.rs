@@ -0,0 +1,45 @@
+use super::impls::macros::foreach_map;
+use crate::prelude::*;
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::HashMap as HashbrownMap014;
+#[cfg(feature = "hashbrown_0_15")]
+use hashbrown_0_15::HashMap as HashbrownMap015;
+#[cfg(feature = "hashbrown_0_16")]
+use hashbrown_0_16::HashMap as HashbrownMap016;
+#[cfg(feature = "hashbrown_0_17")]
+use hashbrown_0_17::HashMap as HashbrownMap017;
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::IndexMap;
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::IndexMap as IndexMap2;
+
+impl<T, U> SerializeAs<Vec<T>> for CollectErrors<U>
+where
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Vec::<U>::serialize_as(source, serializer)
+    }
+}
+
+macro_rules! map_collect_errors_handling {
+    ($tyorig:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
+        impl<K, KAs, V, VAs $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for MapCollectErrors<KAs, VAs>
+        where
+            KAs: SerializeAs<K>,
+            VAs: SerializeAs<V>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(value: &$tyorig<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                <$tyorig<KAs, VAs $(, $typaram)*>>::serialize_as(value, serializer)
+            }
+        }
+    }
+}
+foreach_map!(map_collect_errors_handling);
@@ -1,5 +1,9 @@
 pub(crate) use self::macros::*;
-use crate::{formats::Strictness, prelude::*};
+use crate::{
+    formats::{Duplicates, Flexible, Overflow, Reject, Rounding, Strict, Strictness, Truncate},
+    prelude::*,
+    utils,
+};
 #[cfg(feature = "hashbrown_0_14")]
 use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
 #[cfg(feature = "hashbrown_0_15")]
@@ -860,6 +864,30 @@ use_signed_duration!(
     }
 );
 
+impl<STRICTNESS> SerializeAs<Duration> for Iso8601Duration<STRICTNESS>
+where
+    STRICTNESS: Strictness,
+{
+    fn serialize_as<S>(source: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Iso8601Duration::<STRICTNESS>::serialize_as(&DurationSigned::from(source), serializer)
+    }
+}
+
+impl<STRICTNESS> SerializeAs<Duration> for HumanDuration<STRICTNESS>
+where
+    STRICTNESS: Strictness,
+{
+    fn serialize_as<S>(source: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        HumanDuration::<STRICTNESS>::serialize_as(&DurationSigned::from(source), serializer)
+    }
+}
+
 #[cfg(feature = "std")]
 use_signed_duration!(
     TimestampSeconds DurationSeconds,
@@ -978,9 +1006,10 @@ impl<'a, const N: usize> SerializeAs<Cow<'a, [u8; N]>> for Bytes {
 #[cfg(feature = "alloc")]
 macro_rules! one_or_many_impl {
     ($ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)* $(, $typaram:ident : $bound:ident )* >) => {
-        impl<T, U $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for OneOrMany<U, formats::PreferOne>
+        impl<T, U, DUPLICATES $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for OneOrMany<U, formats::PreferOne, DUPLICATES>
         where
             U: SerializeAs<T>,
+            DUPLICATES: Duplicates,
             $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
             $($typaram: ?Sized + $bound,)*
         {
@@ -996,9 +1025,10 @@ macro_rules! one_or_many_impl {
             }
         }
 
-        impl<T, U $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for OneOrMany<U, formats::PreferMany>
+        impl<T, U, DUPLICATES $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for OneOrMany<U, formats::PreferMany, DUPLICATES>
         where
             U: SerializeAs<T>,
+            DUPLICATES: Duplicates,
             $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
             $($typaram: ?Sized + $bound,)*
         {
@@ -1009,16 +1039,38 @@ macro_rules! one_or_many_impl {
                 serializer.collect_seq(source.iter().map(|item| SerializeAsWrap::<T, U>::new(item)))
             }
         }
+
+        impl<T, U, DUPLICATES $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for OneOrMany<U, formats::PreferNone, DUPLICATES>
+        where
+            U: SerializeAs<T>,
+            DUPLICATES: Duplicates,
+            $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match source.len() {
+                    0 => serializer.serialize_none(),
+                    1 => SerializeAsWrap::<T, U>::new(source.iter().next().expect("Cannot be empty"))
+                        .serialize(serializer),
+                    _ => serializer.collect_seq(source.iter().map(|item| SerializeAsWrap::<T, U>::new(item))),
+                }
+            }
+        }
     }
 }
 #[cfg(feature = "alloc")]
 foreach_seq!(one_or_many_impl);
 
 #[cfg(all(feature = "alloc", feature = "smallvec_1"))]
-impl<T, TAs, A> SerializeAs<SmallVec<A>> for OneOrMany<TAs, formats::PreferOne>
+impl<T, TAs, DUPLICATES, A> SerializeAs<SmallVec<A>>
+    for OneOrMany<TAs, formats::PreferOne, DUPLICATES>
 where
     A: smallvec_1::Array<Item = T>,
     TAs: SerializeAs<T>,
+    DUPLICATES: Duplicates,
 {
     fn serialize_as<S>(source: &SmallVec<A>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1033,10 +1085,12 @@ where
 }
 
 #[cfg(all(feature = "alloc", feature = "smallvec_1"))]
-impl<T, TAs, A> SerializeAs<SmallVec<A>> for OneOrMany<TAs, formats::PreferMany>
+impl<T, TAs, DUPLICATES, A> SerializeAs<SmallVec<A>>
+    for OneOrMany<TAs, formats::PreferMany, DUPLICATES>
 where
     A: smallvec_1::Array<Item = T>,
     TAs: SerializeAs<T>,
+    DUPLICATES: Duplicates,
 {
     fn serialize_as<S>(source: &SmallVec<A>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1046,6 +1100,27 @@ where
     }
 }
 
+#[cfg(all(feature = "alloc", feature = "smallvec_1"))]
+impl<T, TAs, DUPLICATES, A> SerializeAs<SmallVec<A>>
+    for OneOrMany<TAs, formats::PreferNone, DUPLICATES>
+where
+    A: smallvec_1::Array<Item = T>,
+    TAs: SerializeAs<T>,
+    DUPLICATES: Duplicates,
+{
+    fn serialize_as<S>(source: &SmallVec<A>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match source.len() {
+            0 => serializer.serialize_none(),
+            1 => SerializeAsWrap::<T, TAs>::new(source.iter().next().expect("Cannot be empty"))
+                .serialize(serializer),
+            _ => SerializeAsWrap::<&[T], &[TAs]>::new(&source.as_slice()).serialize(serializer),
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<T, TAs1> SerializeAs<T> for PickFirst<(TAs1,)>
 where
@@ -1198,4 +1273,150 @@ impl<STRICTNESS: Strictness> SerializeAs<bool> for BoolFromInt<STRICTNESS> {
     }
 }
 
+impl<T> SerializeAs<T> for IntFromFloat<Strict>
+where
+    T: Integer,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = source.to_f64();
+        if utils::is_exact(value, *source) {
+            serializer.serialize_f64(value)
+        } else {
+            Err(SerError::custom(format_args!(
+                "invalid value: integer `{source}`, expected an integer, which is exactly representable as f64"
+            )))
+        }
+    }
+}
+
+impl<T, ROUNDING, OVERFLOW> SerializeAs<T> for IntFromFloat<Flexible, ROUNDING, OVERFLOW>
+where
+    T: Integer,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(source.to_f64())
+    }
+}
+
+/// Convert the float into an `i64`, see [`FloatFromInt`].
+fn float_to_i64<F, ROUNDING, OVERFLOW, S>(
+    source: &F,
+    serializer: S,
+    strict: bool,
+) -> Result<S::Ok, S::Error>
+where
+    F: Float,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+    S: Serializer,
+{
+    match utils::float_to_int::<i64, ROUNDING, OVERFLOW>(source.to_f64(), strict) {
+        Some(value) => serializer.serialize_i64(value),
+        None => {
+            let expected = if strict {
+                "an integral value in the range of"
+            } else {
+                "a value in the range of"
+            };
+            Err(SerError::custom(format_args!(
+                "invalid value: {}, expected {expected} i64",
+                Unexpected::Float(source.to_f64())
+            )))
+        }
+    }
+}
+
+impl<T> SerializeAs<T> for FloatFromInt<Strict>
+where
+    T: Float,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        float_to_i64::<T, Truncate, Reject, S>(source, serializer, true)
+    }
+}
+
+impl<T, ROUNDING, OVERFLOW> SerializeAs<T> for FloatFromInt<Flexible, ROUNDING, OVERFLOW>
+where
+    T: Float,
+    ROUNDING: Rounding,
+    OVERFLOW: Overflow,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        float_to_i64::<T, ROUNDING, OVERFLOW, S>(source, serializer, false)
+    }
+}
+
+macro_rules! endian_bytes_serialize {
+    ($($ty:ty => $primitive:ty),* $(,)?) => {
+        $(
+            impl<BYTES> SerializeAs<$ty> for BigEndianBytes<BYTES>
+            where
+                BYTES: SerializeAs<[u8; core::mem::size_of::<$primitive>()]>,
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let value: $primitive = (*source).into();
+                    BYTES::serialize_as(&value.to_be_bytes(), serializer)
+                }
+            }
+
+            impl<BYTES> SerializeAs<$ty> for LittleEndianBytes<BYTES>
+            where
+                BYTES: SerializeAs<[u8; core::mem::size_of::<$primitive>()]>,
+            {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let value: $primitive = (*source).into();
+                    BYTES::serialize_as(&value.to_le_bytes(), serializer)
+                }
+            }
+        )*
+    };
+}
+
+endian_bytes_serialize! {
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    i128 => i128,
+    isize => isize,
+    core::num::NonZeroU8 => u8,
+    core::num::NonZeroU16 => u16,
+    core::num::NonZeroU32 => u32,
+    core::num::NonZeroU64 => u64,
+    core::num::NonZeroU128 => u128,
+    core::num::NonZeroUsize => usize,
+    core::num::NonZeroI8 => i8,
+    core::num::NonZeroI16 => i16,
+    core::num::NonZeroI32 => i32,
+    core::num::NonZeroI64 => i64,
+    core::num::NonZeroI128 => i128,
+    core::num::NonZeroIsize => isize,
+}
+
 // endregion
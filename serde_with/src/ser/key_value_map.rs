@@ -0,0 +1,94 @@
+use super::impls::macros::{foreach_map, foreach_set};
+use crate::{key_value_map::serialize_values, prelude::*};
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
+#[cfg(feature = "hashbrown_0_15")]
+use hashbrown_0_15::{HashMap as HashbrownMap015, HashSet as HashbrownSet015};
+#[cfg(feature = "hashbrown_0_16")]
+use hashbrown_0_16::{HashMap as HashbrownMap016, HashSet as HashbrownSet016};
+#[cfg(feature = "hashbrown_0_17")]
+use hashbrown_0_17::{HashMap as HashbrownMap017, HashSet as HashbrownSet017};
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
+
+macro_rules! map_impl {
+    ($tyorig:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
+        impl<K, V, VAs, KEY $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for KeyValueMap<VAs, KEY>
+        where
+            VAs: SerializeAs<V>,
+            KEY: formats::KeyField,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$tyorig<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_values::<S, V, VAs>(source.values(), KEY::NAME, serializer)
+            }
+        }
+
+        impl<K, V, VAs, KEY $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for KeyValueMapPreventDuplicates<VAs, KEY>
+        where
+            VAs: SerializeAs<V>,
+            KEY: formats::KeyField,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$tyorig<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_values::<S, V, VAs>(source.values(), KEY::NAME, serializer)
+            }
+        }
+
+        impl<K, V, VAs, KEY $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for KeyValueMapFirstKeyWins<VAs, KEY>
+        where
+            VAs: SerializeAs<V>,
+            KEY: formats::KeyField,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$tyorig<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_values::<S, V, VAs>(source.values(), KEY::NAME, serializer)
+            }
+        }
+    };
+}
+foreach_map!(map_impl);
+
+macro_rules! set_impl {
+    ($tyorig:ident < T $(, $typaram:ident : $bound:ident)* >) => {
+        impl<T, TAs, KEY $(, $typaram)*> SerializeAs<$tyorig<T $(, $typaram)*>> for KeyValueMap<TAs, KEY>
+        where
+            TAs: SerializeAs<T>,
+            KEY: formats::KeyField,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$tyorig<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_values::<S, T, TAs>(source.iter(), KEY::NAME, serializer)
+            }
+        }
+
+        impl<T, TAs, KEY $(, $typaram)*> SerializeAs<$tyorig<T $(, $typaram)*>> for KeyValueMapPreventDuplicates<TAs, KEY>
+        where
+            TAs: SerializeAs<T>,
+            KEY: formats::KeyField,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$tyorig<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_values::<S, T, TAs>(source.iter(), KEY::NAME, serializer)
+            }
+        }
+    };
+}
+foreach_set!(set_impl);
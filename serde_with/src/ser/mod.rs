@@ -7,11 +7,19 @@
 //!
 //! [user guide]: crate::guide
 
+#[cfg(feature = "alloc")]
+mod canonical;
+#[cfg(feature = "alloc")]
+mod collect_errors;
 #[cfg(feature = "alloc")]
 mod duplicates;
 mod impls;
 #[cfg(feature = "alloc")]
+mod key_value_map;
+#[cfg(feature = "alloc")]
 mod skip_error;
+#[cfg(feature = "alloc")]
+mod sorted;
 
 use crate::prelude::*;
 
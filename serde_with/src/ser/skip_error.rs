@@ -1,17 +1,20 @@
-use super::impls::macros::foreach_map;
+use super::impls::macros::{foreach_map, foreach_seq, foreach_set};
 use crate::prelude::*;
 #[cfg(feature = "hashbrown_0_14")]
-use hashbrown_0_14::HashMap as HashbrownMap014;
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
 #[cfg(feature = "hashbrown_0_15")]
-use hashbrown_0_15::HashMap as HashbrownMap015;
+use hashbrown_0_15::{HashMap as HashbrownMap015, HashSet as HashbrownSet015};
 #[cfg(feature = "hashbrown_0_16")]
-use hashbrown_0_16::HashMap as HashbrownMap016;
+use hashbrown_0_16::{HashMap as HashbrownMap016, HashSet as HashbrownSet016};
 #[cfg(feature = "hashbrown_0_17")]
-use hashbrown_0_17::HashMap as HashbrownMap017;
+use hashbrown_0_17::{HashMap as HashbrownMap017, HashSet as HashbrownSet017};
 #[cfg(feature = "indexmap_1")]
-use indexmap_1::IndexMap;
+use indexmap_1::{IndexMap, IndexSet};
 #[cfg(feature = "indexmap_2")]
-use indexmap_2::IndexMap as IndexMap2;
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
+
+type BoxedSlice<T> = Box<[T]>;
+type Slice<T> = [T];
 
 impl<T, U, I> SerializeAs<Vec<T>> for VecSkipError<U, I>
 where
@@ -25,6 +28,56 @@ where
     }
 }
 
+macro_rules! seq_skip_error_handling {
+    ($ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)* $(, $typaram:ident : $bound:ident )* >) => {
+        impl<T, U, I $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for SeqSkipError<U, I>
+        where
+            U: SerializeAs<T>,
+            $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                <$ty<U $(, $typaram)*>>::serialize_as(source, serializer)
+            }
+        }
+    }
+}
+foreach_seq!(seq_skip_error_handling);
+
+macro_rules! bounded_skip_error_handling {
+    ($ty:ident < T $(: $tbound1:ident $(+ $tbound2:ident)*)* $(, $typaram:ident : $bound:ident )* >) => {
+        impl<T, U, LIMIT, I $(, $typaram)*> SerializeAs<$ty<T $(, $typaram)*>> for BoundedSkipError<U, LIMIT, I>
+        where
+            U: SerializeAs<T>,
+            $(T: ?Sized + $tbound1 $(+ $tbound2)*,)*
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(source: &$ty<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                <$ty<U $(, $typaram)*>>::serialize_as(source, serializer)
+            }
+        }
+    }
+}
+foreach_seq!(bounded_skip_error_handling);
+
+impl<T, U, I, const N: usize> SerializeAs<[T; N]> for SeqSkipError<U, I>
+where
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &[T; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        <[U; N]>::serialize_as(source, serializer)
+    }
+}
+
 macro_rules! map_skip_error_handling {
     ($tyorig:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
         impl<K, KAs, V, VAs, I $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for MapSkipError<KAs, VAs, I>
@@ -0,0 +1,66 @@
+use super::impls::macros::{foreach_map, foreach_set};
+use crate::prelude::*;
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
+#[cfg(feature = "hashbrown_0_15")]
+use hashbrown_0_15::{HashMap as HashbrownMap015, HashSet as HashbrownSet015};
+#[cfg(feature = "hashbrown_0_16")]
+use hashbrown_0_16::{HashMap as HashbrownMap016, HashSet as HashbrownSet016};
+#[cfg(feature = "hashbrown_0_17")]
+use hashbrown_0_17::{HashMap as HashbrownMap017, HashSet as HashbrownSet017};
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
+
+macro_rules! sorted_set {
+    ($tyorig:ident < T $(, $typaram:ident : $bound:ident)* >) => {
+        impl<T, TAs, ORDER $(, $typaram)*> SerializeAs<$tyorig<T $(, $typaram)*>> for SortedSet<TAs, ORDER>
+        where
+            TAs: SerializeAs<T>,
+            ORDER: formats::SortOrder<T>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(value: &$tyorig<T $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut elements: Vec<&T> = value.iter().collect();
+                elements.sort_by(|a, b| ORDER::cmp(a, b));
+                serializer.collect_seq(
+                    elements
+                        .into_iter()
+                        .map(|elem| SerializeAsWrap::<T, TAs>::new(elem)),
+                )
+            }
+        }
+    }
+}
+foreach_set!(sorted_set);
+
+macro_rules! sorted_map {
+    ($tyorig:ident < K, V $(, $typaram:ident : $bound:ident)* >) => {
+        impl<K, KAs, V, VAs, ORDER $(, $typaram)*> SerializeAs<$tyorig<K, V $(, $typaram)*>> for SortedMap<KAs, VAs, ORDER>
+        where
+            KAs: SerializeAs<K>,
+            VAs: SerializeAs<V>,
+            ORDER: formats::SortOrder<K>,
+            $($typaram: ?Sized + $bound,)*
+        {
+            fn serialize_as<S>(value: &$tyorig<K, V $(, $typaram)*>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut entries: Vec<(&K, &V)> = value.iter().collect();
+                entries.sort_by(|(a, _), (b, _)| ORDER::cmp(a, b));
+                serializer.collect_map(entries.into_iter().map(|(k, v)| {
+                    (
+                        SerializeAsWrap::<K, KAs>::new(k),
+                        SerializeAsWrap::<V, VAs>::new(v),
+                    )
+                }))
+            }
+        }
+    }
+}
+foreach_map!(sorted_map);
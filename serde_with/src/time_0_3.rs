@@ -15,7 +15,7 @@
 use crate::{
     formats::{Flexible, Format, Strict, Strictness},
     prelude::*,
-    utils::duration::{use_duration_signed_de, use_duration_signed_ser},
+    utils::duration::{use_duration_signed_de, use_duration_signed_ser, use_duration_str},
 };
 #[cfg(feature = "std")]
 use ::time_0_3::format_description::well_known::{
@@ -331,3 +331,8 @@ impl<'de, const CONFIG: EncodedConfig> DeserializeAs<'de, OffsetDateTime> for Is
         deserializer.deserialize_str(Helper::<CONFIG>)
     }
 }
+
+use_duration_str!(
+    Iso8601Duration, HumanDuration
+    => Time03Duration; duration_into_duration_signed, duration_from_duration_signed
+);
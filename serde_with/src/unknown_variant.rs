@@ -0,0 +1,199 @@
+use crate::{
+    content::de::{Content as DeContent, ContentRefDeserializer},
+    prelude::*,
+    value::Value,
+};
+
+/// Enums with a catch-all variant for unknown enum variants
+///
+/// The trait connects an enum with the variant, which stores unknown enum variants for [`CatchUnknownVariant`].
+/// The catch-all variant should be marked with `#[serde(skip)]`, such that it is not part of the known variants.
+///
+/// Check the documentation of [`CatchUnknownVariant`] for an example.
+pub trait UnknownVariant: Sized {
+    /// Create the catch-all variant from the unmodified input value.
+    fn from_unknown(value: Value<'static>) -> Self;
+
+    /// Return the stored input value, if `self` is the catch-all variant.
+    fn as_unknown(&self) -> Option<&Value<'static>>;
+}
+
+/// Preserve unknown enum variants in a catch-all variant
+///
+/// New enum variants added by a remote API fail the deserialization of the derived [`Deserialize`] implementation.
+/// `#[serde(other)]` only works for unit variants and discards the data of the unknown variant.
+/// This adapter instead stores the whole value of an unknown variant in a designated variant of the enum, configured with the [`UnknownVariant`] trait.
+/// During serialization the stored value is emitted unchanged, such that the data round-trips even through older binaries.
+///
+/// The enum must use the default *externally tagged* representation.
+/// A variant is unknown if its name is not one of the variants of the derived [`Deserialize`] implementation.
+/// Errors in the content of known variants are still reported as errors.
+///
+/// The input is buffered, which requires a self-describing data format.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, value::Value, CatchUnknownVariant, UnknownVariant};
+///
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// enum Shape {
+///     Circle { radius: u32 },
+///     Square(u32),
+///     Empty,
+///     #[serde(skip)]
+///     Unknown(Value<'static>),
+/// }
+///
+/// impl UnknownVariant for Shape {
+///     fn from_unknown(value: Value<'static>) -> Self {
+///         Shape::Unknown(value)
+///     }
+///
+///     fn as_unknown(&self) -> Option<&Value<'static>> {
+///         match self {
+///             Shape::Unknown(value) => Some(value),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Serialize, Deserialize)]
+/// struct Drawing {
+///     #[serde_as(as = "Vec<CatchUnknownVariant>")]
+///     shapes: Vec<Shape>,
+/// }
+///
+/// let json = r#"{"shapes":[{"Circle":{"radius":1}},"Empty",{"Triangle":[1,2,3]},"Point"]}"#;
+/// let drawing: Drawing = serde_json::from_str(json).unwrap();
+/// assert_eq!(
+///     vec![
+///         Shape::Circle { radius: 1 },
+///         Shape::Empty,
+///         Shape::Unknown(Value::Map(vec![(
+///             Value::String("Triangle".into()),
+///             Value::Seq(vec![Value::U64(1), Value::U64(2), Value::U64(3)]),
+///         )])),
+///         Shape::Unknown(Value::String("Point".into())),
+///     ],
+///     drawing.shapes,
+/// );
+/// // The unknown variants are serialized unchanged
+/// assert_eq!(json, serde_json::to_string(&drawing).unwrap());
+///
+/// // Invalid data of known variants is still an error
+/// let res: Result<Drawing, _> = serde_json::from_str(r#"{"shapes":[{"Square":"big"}]}"#);
+/// assert!(res.is_err());
+/// # }
+/// ```
+pub struct CatchUnknownVariant;
+
+impl<T> SerializeAs<T> for CatchUnknownVariant
+where
+    T: Serialize + UnknownVariant,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match source.as_unknown() {
+            Some(value) => value.serialize(serializer),
+            None => source.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for CatchUnknownVariant
+where
+    T: Deserialize<'de> + UnknownVariant,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let is_human_readable = deserializer.is_human_readable();
+        let content = DeContent::deserialize(deserializer)?;
+        let is_unknown = Cell::new(false);
+        let res = T::deserialize(KnownVariantDeserializer {
+            content: &content,
+            is_human_readable,
+            is_unknown: &is_unknown,
+            phantom: PhantomData,
+        });
+        match res {
+            Err(_) if is_unknown.get() => Ok(T::from_unknown(content.into_owned())),
+            res => res,
+        }
+    }
+}
+
+/// Deserialize an enum, but reject variant names which the enum does not know.
+///
+/// Rejected variants set the `is_unknown` flag, such that the error can be distinguished from other errors.
+struct KnownVariantDeserializer<'a, 'de, E> {
+    content: &'a DeContent<'de>,
+    is_human_readable: bool,
+    is_unknown: &'a Cell<bool>,
+    phantom: PhantomData<E>,
+}
+
+impl<'de, E> Deserializer<'de> for KnownVariantDeserializer<'_, 'de, E>
+where
+    E: DeError,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        ContentRefDeserializer::new(self.content, self.is_human_readable).deserialize_any(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // Unit variants are a plain string, all others a map with a single entry
+        let variant = match self.content {
+            DeContent::Map(entries) if entries.len() == 1 => variant_name(&entries[0].0),
+            content => variant_name(content),
+        };
+        if let Some(variant) = variant {
+            if !variants.contains(&variant) {
+                self.is_unknown.set(true);
+                return Err(DeError::unknown_variant(variant, variants));
+            }
+        }
+        ContentRefDeserializer::new(self.content, self.is_human_readable)
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.is_human_readable
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+fn variant_name<'a>(content: &'a DeContent<'_>) -> Option<&'a str> {
+    match content {
+        DeContent::String(variant) => Some(variant),
+        DeContent::Str(variant) => Some(variant),
+        _ => None,
+    }
+}
@@ -248,6 +248,117 @@ pub(crate) fn get_unexpected_u128(value: u128, buf: &mut [u8; 58]) -> Unexpected
     Unexpected::Other(writer.into_str())
 }
 
+/// Integers supported by [`IntFromFloat`] and [`FloatFromInt`]
+///
+/// The trait is implemented for all primitive integer types.
+/// The conversions behave like the `as` operator.
+///
+/// [`IntFromFloat`]: crate::IntFromFloat
+/// [`FloatFromInt`]: crate::FloatFromInt
+pub trait Integer: Copy + Display + PartialEq + TryFrom<i128> + TryFrom<u128> {
+    /// Name of the type for error messages
+    const NAME: &'static str;
+    /// Smallest value of the type, the result of saturating negative overflows
+    const MIN: Self;
+    /// Largest value of the type, the result of saturating positive overflows
+    const MAX: Self;
+
+    /// Convert to the nearest float.
+    fn to_f64(self) -> f64;
+    /// Convert an integral float, which saturates for values outside of the range.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty)*) => {$(
+        impl Integer for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as Self
+            }
+        }
+    )*};
+}
+impl_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Floats supported by [`IntFromFloat`] and [`FloatFromInt`]
+///
+/// The trait is implemented for [`f32`] and [`f64`].
+/// The conversions behave like the `as` operator.
+///
+/// [`IntFromFloat`]: crate::IntFromFloat
+/// [`FloatFromInt`]: crate::FloatFromInt
+pub trait Float: Copy + Display {
+    /// Name of the type for error messages
+    const NAME: &'static str;
+    /// Largest finite value of the type, the result of saturating overflows
+    const MAX: Self;
+
+    /// Convert to an [`f64`], which is lossless.
+    fn to_f64(self) -> f64;
+    /// Convert to the nearest float.
+    fn from_i128(value: i128) -> Self;
+    /// Convert to the nearest float.
+    fn from_u128(value: u128) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($ty:ty)*) => {$(
+        impl Float for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const MAX: Self = <$ty>::MAX;
+
+            fn to_f64(self) -> f64 {
+                f64::from(self)
+            }
+
+            fn from_i128(value: i128) -> Self {
+                value as Self
+            }
+
+            fn from_u128(value: u128) -> Self {
+                value as Self
+            }
+        }
+    )*};
+}
+impl_float!(f32 f64);
+
+/// Convert a float into the integer `I`.
+///
+/// In strict mode only integral values in the range of `I` are converted.
+/// Otherwise, the value is rounded first and values outside of the range are clamped if `OVERFLOW` saturates.
+/// `NaN` is never converted.
+pub(crate) fn float_to_int<I, ROUNDING, OVERFLOW>(value: f64, strict: bool) -> Option<I>
+where
+    I: Integer,
+    ROUNDING: formats::Rounding,
+    OVERFLOW: formats::Overflow,
+{
+    let rounded = ROUNDING::round(value);
+    // `MAX + 1` is a power of two, which is exactly representable
+    let in_range = I::MIN.to_f64() <= rounded && rounded < I::MAX.to_f64() + 1.0;
+    if value.is_nan() || (strict && rounded != value) {
+        None
+    } else if in_range || (!strict && OVERFLOW::SATURATE) {
+        Some(I::from_f64(rounded))
+    } else {
+        None
+    }
+}
+
+/// Return `true` if the float has the exact value of the integer.
+pub(crate) fn is_exact<I: Integer>(float: f64, int: I) -> bool {
+    float_to_int::<I, formats::Truncate, formats::Reject>(float, true) == Some(int)
+}
+
 #[cfg(any(
     feature = "schemars_0_8",
     feature = "schemars_0_9",
@@ -345,3 +456,37 @@ impl NumberExt for serde_json::Number {
         unreachable!()
     }
 }
+
+/// Forward the `deserialize_*` methods of a [`Deserializer`] wrapper to the `delegate` field.
+///
+/// The optional `|visitor| expr` prefix wraps the visitor before passing it on.
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $crate::utils::forward_deserialize! {
+            |visitor| visitor;
+            $($method($($arg: $ty),*);)*
+        }
+    };
+    (|$visitor:ident| $wrap:expr; $($method:ident($($arg:ident: $ty:ty),*);)*) => {$(
+        fn $method<V>(self, $($arg: $ty,)* $visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.delegate.$method($($arg,)* $wrap)
+        }
+    )*};
+}
+pub(crate) use forward_deserialize;
+
+/// Forward the `visit_*` methods of a [`Visitor`] wrapper to the `delegate` field.
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {$(
+        fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            self.delegate.$method(v)
+        }
+    )*};
+}
+pub(crate) use forward_visit;
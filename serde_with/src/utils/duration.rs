@@ -485,6 +485,566 @@ where
     }
 }
 
+/// Write `value` as decimal fraction with `width` digits, omitting trailing zeros.
+///
+/// Nothing is written if `value` is zero.
+fn write_fraction(f: &mut fmt::Formatter<'_>, mut value: u32, mut width: usize) -> fmt::Result {
+    if value == 0 {
+        return Ok(());
+    }
+    while value.is_multiple_of(10) {
+        value /= 10;
+        width -= 1;
+    }
+    write!(f, ".{value:0width$}")
+}
+
+/// Format a [`DurationSigned`] as ISO 8601 duration, using hours, minutes, and seconds.
+struct Iso8601DurationDisplay<'a>(&'a DurationSigned);
+
+impl Display for Iso8601DurationDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.duration.as_secs();
+        let nanos = self.0.duration.subsec_nanos();
+
+        if self.0.sign.is_negative() && !self.0.duration.is_zero() {
+            f.write_str("-")?;
+        }
+        f.write_str("PT")?;
+
+        let hours = secs / 3600;
+        let minutes = (secs % 3600) / 60;
+        let seconds = secs % 60;
+        if hours > 0 {
+            write!(f, "{hours}H")?;
+        }
+        if minutes > 0 {
+            write!(f, "{minutes}M")?;
+        }
+        if seconds > 0 || nanos > 0 || secs == 0 {
+            write!(f, "{seconds}")?;
+            write_fraction(f, nanos, 9)?;
+            f.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+impl<STRICTNESS> SerializeAs<DurationSigned> for Iso8601Duration<STRICTNESS>
+where
+    STRICTNESS: Strictness,
+{
+    fn serialize_as<S>(source: &DurationSigned, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&Iso8601DurationDisplay(source))
+    }
+}
+
+struct Iso8601DurationVisitor<STRICTNESS>(PhantomData<STRICTNESS>);
+
+/// Convert the result of parsing `value` into a deserialization error.
+fn duration_from_str<E>(
+    value: &str,
+    parsed: Result<DurationSigned, ParseDurationStrError>,
+    expected: &dyn Expected,
+) -> Result<DurationSigned, E>
+where
+    E: DeError,
+{
+    match parsed {
+        Ok(dur) => Ok(dur),
+        Err(ParseDurationStrError::InvalidValue) => {
+            Err(DeError::invalid_value(Unexpected::Str(value), expected))
+        }
+        Err(ParseDurationStrError::Custom(msg)) => Err(DeError::custom(msg)),
+    }
+}
+
+impl Visitor<'_> for Iso8601DurationVisitor<Strict> {
+    type Value = DurationSigned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601 duration")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        duration_from_str(value, parse_iso8601_duration(value, false), &self)
+    }
+}
+
+impl Visitor<'_> for Iso8601DurationVisitor<Flexible> {
+    type Value = DurationSigned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601 duration or a number of seconds")
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_i64(value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_u64(value)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_f64(value)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        // Numeric strings are interpreted as seconds, like the other flexible duration formats.
+        if let Ok((sign, seconds, subseconds)) = parse_float_into_time_parts(value) {
+            return Ok(DurationSigned::new(sign, seconds, subseconds));
+        }
+        duration_from_str(value, parse_iso8601_duration(value, true), &self)
+    }
+}
+
+impl<'de> DeserializeAs<'de, DurationSigned> for Iso8601Duration<Strict> {
+    fn deserialize_as<D>(deserializer: D) -> Result<DurationSigned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Iso8601DurationVisitor::<Strict>(PhantomData))
+    }
+}
+
+impl<'de> DeserializeAs<'de, DurationSigned> for Iso8601Duration<Flexible> {
+    fn deserialize_as<D>(deserializer: D) -> Result<DurationSigned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(Iso8601DurationVisitor::<Flexible>(PhantomData))
+    }
+}
+
+/// Errors while parsing a duration from its string representation
+#[cfg_attr(test, derive(Debug, PartialEq))]
+enum ParseDurationStrError {
+    InvalidValue,
+    Custom(&'static str),
+}
+
+/// Parse an ISO 8601 duration like `PT1H30M12.5S` or `-P2W`.
+///
+/// The `flexible` mode accepts lowercase designators, signs on the individual components, and weeks combined with other units.
+fn parse_iso8601_duration(
+    value: &str,
+    flexible: bool,
+) -> Result<DurationSigned, ParseDurationStrError> {
+    const OVERFLOW: ParseDurationStrError =
+        ParseDurationStrError::Custom("ISO 8601 duration is outside the supported range");
+
+    let (sign, mut rest) = match value.as_bytes().first() {
+        Some(b'+') => (1i128, &value[1..]),
+        Some(b'-') => (-1, &value[1..]),
+        _ => (1, value),
+    };
+    rest = match rest.as_bytes().first() {
+        Some(b'P') => &rest[1..],
+        Some(b'p') if flexible => &rest[1..],
+        _ => return Err(ParseDurationStrError::InvalidValue),
+    };
+
+    let mut total_nanos: i128 = 0;
+    let mut in_time = false;
+    let mut has_fraction = false;
+    let mut has_weeks = false;
+    // Rank of the last seen unit, to enforce the order and uniqueness of the components
+    let mut last_rank = 0;
+    while !rest.is_empty() {
+        if rest.starts_with('T') || (flexible && rest.starts_with('t')) {
+            if in_time {
+                return Err(ParseDurationStrError::InvalidValue);
+            }
+            in_time = true;
+            rest = &rest[1..];
+            if rest.is_empty() {
+                return Err(ParseDurationStrError::InvalidValue);
+            }
+            continue;
+        }
+        // Only the last component may carry a fraction
+        if has_fraction {
+            return Err(ParseDurationStrError::InvalidValue);
+        }
+
+        let component_sign = match rest.as_bytes().first() {
+            Some(b'+') if flexible => {
+                rest = &rest[1..];
+                1i128
+            }
+            Some(b'-') if flexible => {
+                rest = &rest[1..];
+                -1
+            }
+            _ => 1,
+        };
+
+        let int_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if int_len == 0 {
+            return Err(ParseDurationStrError::InvalidValue);
+        }
+        let int_part = rest[..int_len]
+            .bytes()
+            .try_fold(0i128, |acc, digit| {
+                acc.checked_mul(10)?.checked_add(i128::from(digit - b'0'))
+            })
+            .ok_or(OVERFLOW)?;
+        rest = &rest[int_len..];
+
+        let mut frac_nanos: i128 = 0;
+        if rest.starts_with('.') || rest.starts_with(',') {
+            rest = &rest[1..];
+            let frac_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+            if frac_len == 0 {
+                return Err(ParseDurationStrError::InvalidValue);
+            }
+            if frac_len > 9 && !flexible {
+                return Err(ParseDurationStrError::Custom(
+                    "Duration and Timestamps with no more than 9 digits precision",
+                ));
+            }
+            // Flexible parsing truncates anything beyond nanosecond precision
+            let mut scale = 100_000_000;
+            for digit in rest[..frac_len].bytes().take(9) {
+                frac_nanos += i128::from(digit - b'0') * scale;
+                scale /= 10;
+            }
+            rest = &rest[frac_len..];
+            has_fraction = true;
+        }
+
+        let designator = match rest.as_bytes().first() {
+            Some(&c) if flexible => c.to_ascii_uppercase(),
+            Some(&c) => c,
+            None => return Err(ParseDurationStrError::InvalidValue),
+        };
+        let (rank, unit_secs): (u8, i128) = match (designator, in_time) {
+            (b'Y', false) => {
+                return Err(ParseDurationStrError::Custom(
+                    "ISO 8601 durations with years are not supported, since years have no fixed length",
+                ))
+            }
+            (b'M', false) => {
+                return Err(ParseDurationStrError::Custom(
+                    "ISO 8601 durations with months are not supported, since months have no fixed length",
+                ))
+            }
+            (b'W', false) => (1, 7 * 24 * 3600),
+            (b'D', false) => (2, 24 * 3600),
+            (b'H', true) => (3, 3600),
+            (b'M', true) => (4, 60),
+            (b'S', true) => (5, 1),
+            _ => return Err(ParseDurationStrError::InvalidValue),
+        };
+        // All valid designators are ASCII
+        rest = &rest[1..];
+        if rank <= last_rank || (has_weeks && !flexible) {
+            return Err(ParseDurationStrError::InvalidValue);
+        }
+        last_rank = rank;
+        has_weeks |= rank == 1;
+
+        let component = int_part
+            .checked_mul(unit_secs * 1_000_000_000)
+            .and_then(|nanos| nanos.checked_add(frac_nanos * unit_secs))
+            .ok_or(OVERFLOW)?;
+        total_nanos = total_nanos
+            .checked_add(component_sign * component)
+            .ok_or(OVERFLOW)?;
+    }
+    if last_rank == 0 {
+        return Err(ParseDurationStrError::InvalidValue);
+    }
+
+    let total_nanos = sign * total_nanos;
+    let sign = if total_nanos < 0 {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    let abs_nanos = total_nanos.unsigned_abs();
+    let secs = u64::try_from(abs_nanos / utils::NANOS_PER_SEC).map_err(|_| OVERFLOW)?;
+    let nanos = u32::try_from(abs_nanos % utils::NANOS_PER_SEC).map_err(|_| OVERFLOW)?;
+    Ok(DurationSigned::new(sign, secs, nanos))
+}
+
+/// Format a [`DurationSigned`] in the compact human-readable form, e.g., `1h30m5s` or `250ms`.
+struct HumanDurationDisplay<'a>(&'a DurationSigned);
+
+impl Display for HumanDurationDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.duration.as_secs();
+        let nanos = self.0.duration.subsec_nanos();
+
+        if self.0.duration.is_zero() {
+            return f.write_str("0s");
+        }
+        if self.0.sign.is_negative() {
+            f.write_str("-")?;
+        }
+
+        // Durations below one second use the largest sub-second unit, e.g., `250ms` instead of `0.25s`
+        if secs == 0 {
+            let (unit, unit_nanos, width) = match nanos {
+                1_000_000.. => ("ms", 1_000_000, 6),
+                1_000.. => ("us", 1_000, 3),
+                _ => ("ns", 1, 0),
+            };
+            write!(f, "{}", nanos / unit_nanos)?;
+            write_fraction(f, nanos % unit_nanos, width)?;
+            return f.write_str(unit);
+        }
+
+        let units = [
+            (secs / (7 * 24 * 3600), "w"),
+            ((secs / (24 * 3600)) % 7, "d"),
+            ((secs / 3600) % 24, "h"),
+            ((secs / 60) % 60, "m"),
+        ];
+        for (value, unit) in units {
+            if value > 0 {
+                write!(f, "{value}{unit}")?;
+            }
+        }
+        let seconds = secs % 60;
+        if seconds > 0 || nanos > 0 {
+            write!(f, "{seconds}")?;
+            write_fraction(f, nanos, 9)?;
+            f.write_str("s")?;
+        }
+        Ok(())
+    }
+}
+
+impl<STRICTNESS> SerializeAs<DurationSigned> for HumanDuration<STRICTNESS>
+where
+    STRICTNESS: Strictness,
+{
+    fn serialize_as<S>(source: &DurationSigned, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&HumanDurationDisplay(source))
+    }
+}
+
+struct HumanDurationVisitor<STRICTNESS>(PhantomData<STRICTNESS>);
+
+impl Visitor<'_> for HumanDurationVisitor<Strict> {
+    type Value = DurationSigned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a duration like `1h30m5s` or `250ms`")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        duration_from_str(value, parse_human_duration(value, false), &self)
+    }
+}
+
+impl Visitor<'_> for HumanDurationVisitor<Flexible> {
+    type Value = DurationSigned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a duration like `1h30m5s` or `250ms` or a number of seconds")
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_i64(value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_u64(value)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        DurationVisitorFlexible.visit_f64(value)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeError,
+    {
+        // Numeric strings are interpreted as seconds, like the other flexible duration formats.
+        if let Ok((sign, seconds, subseconds)) = parse_float_into_time_parts(value.trim()) {
+            return Ok(DurationSigned::new(sign, seconds, subseconds));
+        }
+        duration_from_str(value, parse_human_duration(value, true), &self)
+    }
+}
+
+impl<'de> DeserializeAs<'de, DurationSigned> for HumanDuration<Strict> {
+    fn deserialize_as<D>(deserializer: D) -> Result<DurationSigned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(HumanDurationVisitor::<Strict>(PhantomData))
+    }
+}
+
+impl<'de> DeserializeAs<'de, DurationSigned> for HumanDuration<Flexible> {
+    fn deserialize_as<D>(deserializer: D) -> Result<DurationSigned, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(HumanDurationVisitor::<Flexible>(PhantomData))
+    }
+}
+
+/// Parse a human-readable duration like `1h 30m 5s` or `250ms`.
+///
+/// The components consist of a number, optionally with a fraction, and one of the units `w`, `d`, `h`, `m`, `s`, `ms`, `us`, and `ns`.
+/// Whitespace is allowed between the components and between number and unit.
+/// The whole duration may be prefixed by a sign.
+///
+/// The `flexible` mode accepts units in any case, `µs`, surrounding whitespace, and components in any order.
+fn parse_human_duration(
+    value: &str,
+    flexible: bool,
+) -> Result<DurationSigned, ParseDurationStrError> {
+    const OVERFLOW: ParseDurationStrError =
+        ParseDurationStrError::Custom("Duration is outside the supported range");
+
+    let value = if flexible { value.trim() } else { value };
+    let (sign, mut rest) = match value.as_bytes().first() {
+        Some(b'+') => (1i128, &value[1..]),
+        Some(b'-') => (-1, &value[1..]),
+        _ => (1, value),
+    };
+
+    let mut total_nanos: i128 = 0;
+    // Rank of the last seen unit, to enforce the order and uniqueness of the components
+    let mut last_rank = 0;
+    loop {
+        if last_rank > 0 {
+            if rest.is_empty() {
+                break;
+            }
+            rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            // Trailing whitespace is only left over in strict mode
+            if rest.is_empty() {
+                return Err(ParseDurationStrError::InvalidValue);
+            }
+        }
+
+        let int_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if int_len == 0 {
+            return Err(ParseDurationStrError::InvalidValue);
+        }
+        let int_part = rest[..int_len]
+            .bytes()
+            .try_fold(0i128, |acc, digit| {
+                acc.checked_mul(10)?.checked_add(i128::from(digit - b'0'))
+            })
+            .ok_or(OVERFLOW)?;
+        rest = &rest[int_len..];
+
+        // Fraction as numerator and denominator, limited to nanosecond precision
+        let mut frac = (0i128, 1i128);
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let frac_len = after_dot.bytes().take_while(u8::is_ascii_digit).count();
+            if frac_len == 0 {
+                return Err(ParseDurationStrError::InvalidValue);
+            }
+            if frac_len > 9 && !flexible {
+                return Err(ParseDurationStrError::Custom(
+                    "Duration and Timestamps with no more than 9 digits precision",
+                ));
+            }
+            // Flexible parsing truncates anything beyond nanosecond precision
+            for digit in after_dot[..frac_len].bytes().take(9) {
+                frac = (frac.0 * 10 + i128::from(digit - b'0'), frac.1 * 10);
+            }
+            rest = &after_dot[frac_len..];
+        }
+
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        let unit_len = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let unit = &rest[..unit_len];
+        rest = &rest[unit_len..];
+        let unit_matches = |expected: &str| {
+            if flexible {
+                unit.eq_ignore_ascii_case(expected)
+            } else {
+                unit == expected
+            }
+        };
+        let (rank, unit_nanos): (u8, i128) = if unit_matches("w") {
+            (1, 7 * 24 * 3600 * 1_000_000_000)
+        } else if unit_matches("d") {
+            (2, 24 * 3600 * 1_000_000_000)
+        } else if unit_matches("h") {
+            (3, 3600 * 1_000_000_000)
+        } else if unit_matches("m") {
+            (4, 60 * 1_000_000_000)
+        } else if unit_matches("s") {
+            (5, 1_000_000_000)
+        } else if unit_matches("ms") {
+            (6, 1_000_000)
+        } else if unit_matches("us") || (flexible && (unit == "µs" || unit == "μs")) {
+            (7, 1_000)
+        } else if unit_matches("ns") {
+            (8, 1)
+        } else {
+            return Err(ParseDurationStrError::InvalidValue);
+        };
+        if rank <= last_rank && !flexible {
+            return Err(ParseDurationStrError::InvalidValue);
+        }
+        last_rank = rank;
+
+        let component = int_part
+            .checked_mul(unit_nanos)
+            .and_then(|nanos| nanos.checked_add(frac.0 * unit_nanos / frac.1))
+            .ok_or(OVERFLOW)?;
+        total_nanos = total_nanos.checked_add(component).ok_or(OVERFLOW)?;
+    }
+
+    let total_nanos = sign * total_nanos;
+    let sign = if total_nanos < 0 {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    let abs_nanos = total_nanos.unsigned_abs();
+    let secs = u64::try_from(abs_nanos / utils::NANOS_PER_SEC).map_err(|_| OVERFLOW)?;
+    let nanos = u32::try_from(abs_nanos % utils::NANOS_PER_SEC).map_err(|_| OVERFLOW)?;
+    Ok(DurationSigned::new(sign, secs, nanos))
+}
+
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub(crate) enum ParseFloatError {
     InvalidValue,
@@ -678,6 +1238,52 @@ macro_rules! use_duration_signed_de {
 #[cfg(any(feature = "chrono_0_4", feature = "jiff_0_2", feature = "time_0_3"))]
 pub(crate) use use_duration_signed_de;
 
+/// Implement `SerializeAs` and `DeserializeAs` of the string based duration formats for a duration type
+///
+/// ```rust,ignore
+/// use_duration_str!(
+///     Iso8601Duration, HumanDuration
+///     => SignedDuration; duration_into_duration_signed, duration_from_duration_signed
+/// );
+/// ```
+///
+/// The converters are the same functions used for `use_duration_signed_ser!` and `use_duration_signed_de!`.
+/// The formats must have a single `STRICTNESS` parameter and support both [`Strict`] and [`Flexible`].
+#[cfg(any(feature = "chrono_0_4", feature = "jiff_0_2", feature = "time_0_3"))]
+macro_rules! use_duration_str {
+    ($($format:ident),+ => $ty:ty; $into_converter:ident, $from_converter:ident) => {$(
+        impl<STRICTNESS> SerializeAs<$ty> for $format<STRICTNESS>
+        where
+            STRICTNESS: Strictness,
+        {
+            fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let dur: DurationSigned = $into_converter(source);
+                $format::<STRICTNESS>::serialize_as(&dur, serializer)
+            }
+        }
+
+        use_duration_str!(@de $format; $ty; $from_converter; Strict);
+        use_duration_str!(@de $format; $ty; $from_converter; Flexible);
+    )+};
+    (@de $format:ident; $ty:ty; $from_converter:ident; $strictness:ty) => {
+        impl<'de> DeserializeAs<'de, $ty> for $format<$strictness> {
+            fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let dur: DurationSigned = $format::<$strictness>::deserialize_as(deserializer)?;
+                $from_converter::<D>(dur)
+            }
+        }
+    };
+}
+// Make the macros available to the rest of the crate
+#[cfg(any(feature = "chrono_0_4", feature = "jiff_0_2", feature = "time_0_3"))]
+pub(crate) use use_duration_str;
+
 #[test]
 fn test_parse_float_into_time_parts() {
     // Test normal behavior
@@ -708,3 +1314,163 @@ fn test_parse_float_into_time_parts() {
         parse_float_into_time_parts("0.123456789")
     );
 }
+
+#[test]
+fn test_parse_iso8601_duration() {
+    fn parse(value: &str, flexible: bool) -> Result<(Sign, u64, u32), ParseDurationStrError> {
+        parse_iso8601_duration(value, flexible).map(|dur| {
+            (
+                dur.sign,
+                dur.duration.as_secs(),
+                dur.duration.subsec_nanos(),
+            )
+        })
+    }
+
+    // Test normal behavior
+    assert_eq!(
+        Ok((Sign::Positive, 5412, 500_000_000)),
+        parse("PT1H30M12.5S", false)
+    );
+    assert_eq!(Ok((Sign::Positive, 93_600, 0)), parse("P1DT2H", false));
+    assert_eq!(Ok((Sign::Positive, 1_209_600, 0)), parse("P2W", false));
+    assert_eq!(Ok((Sign::Negative, 90, 0)), parse("-PT1M30S", false));
+    assert_eq!(Ok((Sign::Positive, 1800, 0)), parse("PT0,5H", false));
+    assert_eq!(Ok((Sign::Positive, 0, 1)), parse("PT0.000000001S", false));
+    assert_eq!(Ok((Sign::Positive, 0, 0)), parse("-PT0S", false));
+
+    // Syntax errors
+    for invalid in [
+        "",
+        "P",
+        "PT",
+        "1H",
+        "P1H",
+        "PT1D",
+        "P1DT",
+        "PT1M1H",
+        "PT1H1H",
+        "PT1.5H30M",
+        "PT.5S",
+        "PT1S ",
+        "P1W1D",
+        "pt1s",
+        "PT-1S",
+        "PTÄ",
+        "PT1Ä",
+    ] {
+        assert_eq!(
+            Err(ParseDurationStrError::InvalidValue),
+            parse(invalid, false),
+            "{invalid:?}"
+        );
+    }
+    assert!(matches!(
+        parse("P1Y", true),
+        Err(ParseDurationStrError::Custom(_))
+    ));
+    assert!(matches!(
+        parse("P1M", true),
+        Err(ParseDurationStrError::Custom(_))
+    ));
+    assert!(matches!(
+        parse("PT0.1234567891S", false),
+        Err(ParseDurationStrError::Custom(_))
+    ));
+    assert!(matches!(
+        parse("PT99999999999999999999999999999999999999999H", false),
+        Err(ParseDurationStrError::Custom(_))
+    ));
+
+    // Flexible extensions
+    assert_eq!(Ok((Sign::Negative, 90, 0)), parse("PT-1M-30S", true));
+    assert_eq!(Ok((Sign::Positive, 30, 0)), parse("PT1M-30S", true));
+    assert_eq!(Ok((Sign::Positive, 691_200, 0)), parse("p1w1d", true));
+    assert_eq!(
+        Ok((Sign::Positive, 0, 123_456_789)),
+        parse("PT0.1234567891S", true)
+    );
+}
+
+#[test]
+fn test_parse_human_duration() {
+    fn parse(value: &str, flexible: bool) -> Result<(Sign, u64, u32), ParseDurationStrError> {
+        parse_human_duration(value, flexible).map(|dur| {
+            (
+                dur.sign,
+                dur.duration.as_secs(),
+                dur.duration.subsec_nanos(),
+            )
+        })
+    }
+
+    // Test normal behavior
+    assert_eq!(Ok((Sign::Positive, 5405, 0)), parse("1h30m5s", false));
+    assert_eq!(Ok((Sign::Positive, 5405, 0)), parse("1h 30m 5s", false));
+    assert_eq!(Ok((Sign::Positive, 5405, 0)), parse("1 h 30 m 5 s", false));
+    assert_eq!(Ok((Sign::Positive, 0, 250_000_000)), parse("250ms", false));
+    assert_eq!(
+        Ok((Sign::Positive, 1, 2_003_004)),
+        parse("1s2ms3us4ns", false)
+    );
+    assert_eq!(Ok((Sign::Positive, 694_800, 0)), parse("1w1d1h", false));
+    assert_eq!(Ok((Sign::Positive, 5400, 0)), parse("1.5h", false));
+    assert_eq!(Ok((Sign::Positive, 0, 1_500)), parse("1.5us", false));
+    assert_eq!(Ok((Sign::Positive, 0, 1)), parse("1.9ns", false));
+    assert_eq!(Ok((Sign::Negative, 90, 0)), parse("-1m30s", false));
+    assert_eq!(Ok((Sign::Positive, 90, 0)), parse("+1m30s", false));
+    assert_eq!(Ok((Sign::Positive, 0, 0)), parse("-0s", false));
+
+    // Syntax errors
+    for invalid in [
+        "", "1", "s", "-", "- 1s", " 1s", "1s ", "1x", "1.s", ".5s", "1s1m", "1s1s", "1H", "1µs",
+        "1m,5s", "1sÄ", "Ä",
+    ] {
+        assert_eq!(
+            Err(ParseDurationStrError::InvalidValue),
+            parse(invalid, false),
+            "{invalid:?}"
+        );
+    }
+    assert!(matches!(
+        parse("0.1234567891s", false),
+        Err(ParseDurationStrError::Custom(_))
+    ));
+    assert!(matches!(
+        parse("99999999999999999999999999999999999999999w", false),
+        Err(ParseDurationStrError::Custom(_))
+    ));
+    assert!(matches!(
+        parse("9999999999999999999999w", false),
+        Err(ParseDurationStrError::Custom(_))
+    ));
+
+    // Flexible extensions
+    assert_eq!(Ok((Sign::Positive, 5405, 0)), parse(" 1H30M5S ", true));
+    assert_eq!(Ok((Sign::Positive, 0, 1_000)), parse("1µs", true));
+    assert_eq!(Ok((Sign::Positive, 90, 0)), parse("30s1m", true));
+    assert_eq!(Ok((Sign::Positive, 2, 0)), parse("1s 1s", true));
+    assert_eq!(
+        Ok((Sign::Positive, 0, 123_456_789)),
+        parse("0.1234567891s", true)
+    );
+}
+
+#[test]
+fn test_human_duration_display() {
+    fn display(sign: Sign, secs: u64, nanos: u32) -> String {
+        HumanDurationDisplay(&DurationSigned::new(sign, secs, nanos)).to_string()
+    }
+
+    assert_eq!("0s", display(Sign::Positive, 0, 0));
+    assert_eq!("0s", display(Sign::Negative, 0, 0));
+    assert_eq!("1ns", display(Sign::Positive, 0, 1));
+    assert_eq!("1.5us", display(Sign::Positive, 0, 1_500));
+    assert_eq!("250ms", display(Sign::Positive, 0, 250_000_000));
+    assert_eq!("999.999999ms", display(Sign::Positive, 0, 999_999_999));
+    assert_eq!("1.25s", display(Sign::Positive, 1, 250_000_000));
+    assert_eq!("1m", display(Sign::Positive, 60, 0));
+    assert_eq!("-1h30m5s", display(Sign::Negative, 5405, 0));
+    assert_eq!("1w1d2h0.5s", display(Sign::Positive, 698_400, 500_000_000));
+    assert_eq!("4w2d12h", display(Sign::Positive, 2_635_200, 0));
+}
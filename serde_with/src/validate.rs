@@ -0,0 +1,479 @@
+use crate::prelude::*;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+#[cfg(feature = "hashbrown_0_14")]
+use hashbrown_0_14::{HashMap as HashbrownMap014, HashSet as HashbrownSet014};
+#[cfg(feature = "hashbrown_0_15")]
+use hashbrown_0_15::{HashMap as HashbrownMap015, HashSet as HashbrownSet015};
+#[cfg(feature = "hashbrown_0_16")]
+use hashbrown_0_16::{HashMap as HashbrownMap016, HashSet as HashbrownSet016};
+#[cfg(feature = "hashbrown_0_17")]
+use hashbrown_0_17::{HashMap as HashbrownMap017, HashSet as HashbrownSet017};
+#[cfg(feature = "indexmap_1")]
+use indexmap_1::{IndexMap, IndexSet};
+#[cfg(feature = "indexmap_2")]
+use indexmap_2::{IndexMap as IndexMap2, IndexSet as IndexSet2};
+
+/// Only deserialize numbers within the inclusive range `MIN..=MAX`
+///
+/// The value is deserialized using the `T` adapter, which by default is [`Same`], and afterwards compared to the bounds.
+/// Values outside of the range are a deserialization error, which names the value and the range.
+/// All primitive integer and float types and the `NonZero*` types are supported, see [`BoundedNumber`].
+/// Float values are compared to the integer bounds, and `NaN` is always outside of the range.
+///
+/// Use `i128::MIN` or `i128::MAX` for ranges without a lower or upper bound.
+/// `MIN` must not be larger than `MAX`, which is checked at compile time.
+/// Serialization is unchanged and does not check the bounds.
+///
+/// The `JsonSchemaAs` implementations add the `minimum` and `maximum` keywords to the schema of `T`, if it is of type `integer` or `number`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, Bounded, DisplayFromStr};
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Config {
+///     #[serde_as(as = "Bounded<1, 65535>")]
+///     port: u32,
+///     #[serde_as(as = "Bounded<0, 100, DisplayFromStr>")]
+///     percent: f64,
+/// }
+///
+/// let json = r#"{"port": 8080, "percent": "12.5"}"#;
+/// assert_eq!(
+///     Config {
+///         port: 8080,
+///         percent: 12.5,
+///     },
+///     serde_json::from_str(json).unwrap(),
+/// );
+///
+/// let json = r#"{"port": 0, "percent": "12.5"}"#;
+/// let err = serde_json::from_str::<Config>(json).unwrap_err();
+/// assert_eq!(
+///     "invalid value: `0`, expected a number between 1 and 65535 at line 1 column 10",
+///     err.to_string(),
+/// );
+/// # }
+/// ```
+pub struct Bounded<const MIN: i128, const MAX: i128, T = Same>(PhantomData<T>);
+
+/// Only deserialize strings and collections with a length within the inclusive range `MIN..=MAX`
+///
+/// The value is deserialized using the `T` adapter, which by default is [`Same`], and afterwards its length is compared to the bounds.
+/// Strings count their characters, not their bytes.
+/// Collections, like [`Vec`], [`BTreeMap`], or [`HashSet`], count their elements.
+/// Other types are supported by implementing [`HasLength`].
+/// Values with a length outside of the range are a deserialization error, which names the length and the range.
+///
+/// Use `usize::MAX` for ranges without an upper bound.
+/// `MIN` must not be larger than `MAX`, which is checked at compile time.
+/// Serialization is unchanged and does not check the length.
+///
+/// The `JsonSchemaAs` implementations add the `minLength`/`maxLength`, `minItems`/`maxItems`, or `minProperties`/`maxProperties` keywords to the schema of `T`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, DisplayFromStr, Length};
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct User {
+///     #[serde_as(as = "Length<3, 16>")]
+///     name: String,
+///     #[serde_as(as = "Length<1, 3, Vec<DisplayFromStr>>")]
+///     scores: Vec<u32>,
+/// }
+///
+/// let json = r#"{"name": "Zoë", "scores": ["1", "2"]}"#;
+/// assert_eq!(
+///     User {
+///         name: "Zoë".into(),
+///         scores: vec![1, 2],
+///     },
+///     serde_json::from_str(json).unwrap(),
+/// );
+///
+/// let json = r#"{"name": "Zoë", "scores": ["1", "2", "3", "4"]}"#;
+/// let err = serde_json::from_str::<User>(json).unwrap_err();
+/// assert_eq!(
+///     "invalid length 4, expected a length between 1 and 3 at line 1 column 48",
+///     err.to_string(),
+/// );
+/// # }
+/// ```
+pub struct Length<const MIN: usize, const MAX: usize, T = Same>(PhantomData<T>);
+
+/// Only deserialize strings and collections, which are not empty
+///
+/// This is a shorthand for `Length<1, { usize::MAX }, T>`, see [`Length`] for details.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, NonEmpty};
+/// use std::collections::BTreeMap;
+///
+/// #[serde_as]
+/// # #[derive(Debug, PartialEq)]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "NonEmpty")]
+///     id: String,
+///     #[serde_as(as = "NonEmpty")]
+///     labels: BTreeMap<String, String>,
+/// }
+///
+/// let json = r#"{"id": "", "labels": {"a": "b"}}"#;
+/// let err = serde_json::from_str::<Data>(json).unwrap_err();
+/// assert_eq!(
+///     "invalid length 0, expected a length of at least 1 at line 1 column 9",
+///     err.to_string(),
+/// );
+/// # }
+/// ```
+pub struct NonEmpty<T = Same>(PhantomData<T>);
+
+/// Numbers, which can be compared to the bounds of [`Bounded`]
+///
+/// The trait is implemented for all primitive integer and float types and the `NonZero*` types.
+/// Implement it for custom number types to use them with [`Bounded`].
+pub trait BoundedNumber: Display {
+    /// Return `true` if the number is within `min..=max`.
+    fn is_within(&self, min: i128, max: i128) -> bool;
+}
+
+macro_rules! bounded_number_int {
+    ($($ty:ty),*) => {$(
+        impl BoundedNumber for $ty {
+            #[inline]
+            fn is_within(&self, min: i128, max: i128) -> bool {
+                i128::try_from(*self).is_ok_and(|value| min <= value && value <= max)
+            }
+        }
+    )*};
+}
+bounded_number_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl BoundedNumber for u128 {
+    #[inline]
+    fn is_within(&self, min: i128, max: i128) -> bool {
+        // Values larger than `i128::MAX` are larger than every bound
+        i128::try_from(*self).map_or(max == i128::MAX, |value| min <= value && value <= max)
+    }
+}
+
+macro_rules! bounded_number_float {
+    ($($ty:ty),*) => {$(
+        impl BoundedNumber for $ty {
+            #[inline]
+            fn is_within(&self, min: i128, max: i128) -> bool {
+                // The sentinels are no bounds, even for values outside of the `i128` range
+                !self.is_nan()
+                    && (min == i128::MIN || min as $ty <= *self)
+                    && (max == i128::MAX || *self <= max as $ty)
+            }
+        }
+    )*};
+}
+bounded_number_float!(f32, f64);
+
+macro_rules! bounded_number_non_zero {
+    ($($ty:ty),*) => {$(
+        impl BoundedNumber for $ty {
+            #[inline]
+            fn is_within(&self, min: i128, max: i128) -> bool {
+                self.get().is_within(min, max)
+            }
+        }
+    )*};
+}
+bounded_number_non_zero!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+/// Strings and collections, whose length [`Length`] and [`NonEmpty`] can check
+///
+/// The trait is implemented for the string types of the standard library, arrays, and all collections supported by `serde_with`.
+/// Implement it for custom collection types to use them with [`Length`] and [`NonEmpty`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// # use serde::Deserialize;
+/// use serde_with::{serde_as, HasLength, NonEmpty};
+///
+/// #[derive(Deserialize)]
+/// struct Path(Vec<String>);
+///
+/// impl HasLength for Path {
+///     fn length(&self) -> usize {
+///         self.0.len()
+///     }
+/// }
+///
+/// #[serde_as]
+/// #[derive(Deserialize)]
+/// struct Route {
+///     #[serde_as(as = "NonEmpty")]
+///     path: Path,
+/// }
+///
+/// let route: Route = serde_json::from_str(r#"{"path": ["a"]}"#).unwrap();
+/// assert_eq!(vec!["a"], route.path.0);
+/// assert!(serde_json::from_str::<Route>(r#"{"path": []}"#).is_err());
+/// # }
+/// ```
+pub trait HasLength {
+    /// Return the number of characters or elements.
+    fn length(&self) -> usize;
+}
+
+#[cfg(feature = "alloc")]
+impl HasLength for String {
+    #[inline]
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl HasLength for Box<str> {
+    #[inline]
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl HasLength for Cow<'_, str> {
+    #[inline]
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> HasLength for Box<[T]> {
+    #[inline]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> HasLength for [T; N] {
+    #[inline]
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+macro_rules! has_length_collection {
+    ($($(#[$attr:meta])* $ty:ident <$($param:ident),*>;)*) => {$(
+        $(#[$attr])*
+        impl<$($param),*> HasLength for $ty<$($param),*> {
+            #[inline]
+            fn length(&self) -> usize {
+                self.len()
+            }
+        }
+    )*};
+}
+has_length_collection! {
+    #[cfg(feature = "alloc")]
+    Vec<T>;
+    #[cfg(feature = "alloc")]
+    VecDeque<T>;
+    #[cfg(feature = "alloc")]
+    LinkedList<T>;
+    #[cfg(feature = "alloc")]
+    BinaryHeap<T>;
+    #[cfg(feature = "alloc")]
+    BTreeSet<T>;
+    #[cfg(feature = "alloc")]
+    BTreeMap<K, V>;
+    #[cfg(feature = "std")]
+    HashSet<T, H>;
+    #[cfg(feature = "std")]
+    HashMap<K, V, H>;
+    #[cfg(feature = "hashbrown_0_14")]
+    HashbrownSet014<T, H>;
+    #[cfg(feature = "hashbrown_0_14")]
+    HashbrownMap014<K, V, H>;
+    #[cfg(feature = "hashbrown_0_15")]
+    HashbrownSet015<T, H>;
+    #[cfg(feature = "hashbrown_0_15")]
+    HashbrownMap015<K, V, H>;
+    #[cfg(feature = "hashbrown_0_16")]
+    HashbrownSet016<T, H>;
+    #[cfg(feature = "hashbrown_0_16")]
+    HashbrownMap016<K, V, H>;
+    #[cfg(feature = "hashbrown_0_17")]
+    HashbrownSet017<T, H>;
+    #[cfg(feature = "hashbrown_0_17")]
+    HashbrownMap017<K, V, H>;
+    #[cfg(feature = "indexmap_1")]
+    IndexSet<T, H>;
+    #[cfg(feature = "indexmap_1")]
+    IndexMap<K, V, H>;
+    #[cfg(feature = "indexmap_2")]
+    IndexSet2<T, H>;
+    #[cfg(feature = "indexmap_2")]
+    IndexMap2<K, V, H>;
+}
+
+impl<T, U, const MIN: i128, const MAX: i128> SerializeAs<T> for Bounded<MIN, MAX, U>
+where
+    T: ?Sized,
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        U::serialize_as(source, serializer)
+    }
+}
+
+impl<'de, T, U, const MIN: i128, const MAX: i128> DeserializeAs<'de, T> for Bounded<MIN, MAX, U>
+where
+    T: BoundedNumber,
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const { assert!(MIN <= MAX, "`MIN` must not be larger than `MAX`") };
+        let value = U::deserialize_as(deserializer)?;
+        if value.is_within(MIN, MAX) {
+            Ok(value)
+        } else {
+            Err(DeError::custom(format_args!(
+                "invalid value: `{value}`, expected {}",
+                Range {
+                    what: "a number",
+                    min: (MIN != i128::MIN).then_some(MIN),
+                    max: (MAX != i128::MAX).then_some(MAX),
+                }
+            )))
+        }
+    }
+}
+
+impl<T, U, const MIN: usize, const MAX: usize> SerializeAs<T> for Length<MIN, MAX, U>
+where
+    T: ?Sized,
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        U::serialize_as(source, serializer)
+    }
+}
+
+impl<'de, T, U, const MIN: usize, const MAX: usize> DeserializeAs<'de, T> for Length<MIN, MAX, U>
+where
+    T: HasLength,
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const { assert!(MIN <= MAX, "`MIN` must not be larger than `MAX`") };
+        let value = U::deserialize_as(deserializer)?;
+        let length = value.length();
+        if MIN <= length && length <= MAX {
+            Ok(value)
+        } else {
+            Err(DeError::invalid_length(
+                length,
+                &Range {
+                    what: "a length",
+                    min: (MIN != 0).then_some(MIN),
+                    max: (MAX != usize::MAX).then_some(MAX),
+                },
+            ))
+        }
+    }
+}
+
+impl<T, U> SerializeAs<T> for NonEmpty<U>
+where
+    T: ?Sized,
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        U::serialize_as(source, serializer)
+    }
+}
+
+impl<'de, T, U> DeserializeAs<'de, T> for NonEmpty<U>
+where
+    T: HasLength,
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Length::<1, { usize::MAX }, U>::deserialize_as(deserializer)
+    }
+}
+
+/// Describe the allowed range of values in error messages.
+struct Range<T> {
+    what: &'static str,
+    min: Option<T>,
+    max: Option<T>,
+}
+
+impl<T: Display + PartialEq> Display for Range<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = self.what;
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) if min == max => write!(formatter, "{what} of exactly {min}"),
+            (Some(min), Some(max)) => write!(formatter, "{what} between {min} and {max}"),
+            (Some(min), None) => write!(formatter, "{what} of at least {min}"),
+            (None, Some(max)) => write!(formatter, "{what} of at most {max}"),
+            (None, None) => formatter.write_str(what),
+        }
+    }
+}
+
+impl<T: Display + PartialEq> Expected for Range<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
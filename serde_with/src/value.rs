@@ -0,0 +1,689 @@
+//! Buffer arbitrary values in a format independent representation
+//!
+//! This module is only available when using the `alloc` feature of the crate.
+//!
+//! The [`Value`] type can hold any value of the serde data model.
+//! It is the same buffer, which powers [`PickFirst`], [`OneOrMany`], or [`KeyValueMap`].
+//! Deserializing into a [`Value`] first allows attempting multiple deserializations of the same input.
+//!
+//! * [`Value`] implements [`Deserialize`] and [`Serialize`].
+//! * [`ValueDeserializer`] and [`ValueRefDeserializer`] deserialize other types from an owned or borrowed [`Value`].
+//! * [`ValueSerializer`] serializes any type into a [`Value`].
+//!
+//! All of them pass the `is_human_readable` value through, such that types can pick the same representation as for the original data format.
+//!
+//! # Examples
+//!
+//! A custom adapter, which accepts either a number or a string containing a number.
+//!
+//! ```rust
+//! # #[cfg(feature = "macros")] {
+//! # use serde::{Deserialize, Deserializer};
+//! # use serde_json::json;
+//! use serde_with::{serde_as, value::Value, DeserializeAs, DisplayFromStr};
+//!
+//! struct NumberOrString;
+//!
+//! impl<'de> DeserializeAs<'de, u32> for NumberOrString {
+//!     fn deserialize_as<D>(deserializer: D) -> Result<u32, D::Error>
+//!     where
+//!         D: Deserializer<'de>,
+//!     {
+//!         let is_human_readable = deserializer.is_human_readable();
+//!         let value = Value::deserialize(deserializer)?;
+//!
+//!         // The first attempt only borrows the value
+//!         if let Ok(number) = u32::deserialize(value.as_deserializer::<D::Error>(is_human_readable)) {
+//!             return Ok(number);
+//!         }
+//!         // The last attempt can consume it
+//!         <DisplayFromStr as DeserializeAs<'de, u32>>::deserialize_as(
+//!             value.into_deserializer(is_human_readable),
+//!         )
+//!     }
+//! }
+//!
+//! #[serde_as]
+//! # #[derive(Debug, PartialEq)]
+//! #[derive(Deserialize)]
+//! struct Data {
+//!     #[serde_as(as = "Vec<NumberOrString>")]
+//!     values: Vec<u32>,
+//! }
+//!
+//! let data: Data = serde_json::from_value(json!({"values": [1, "2", 3]})).unwrap();
+//! assert_eq!(vec![1, 2, 3], data.values);
+//! # }
+//! ```
+
+use crate::prelude::*;
+
+pub use crate::content::de::{
+    ContentDeserializer as ValueDeserializer, ContentRefDeserializer as ValueRefDeserializer,
+};
+
+/// A buffered value of the serde data model
+///
+/// The value can be created by deserializing it from any self-describing format or by serializing a type with the [`ValueSerializer`].
+/// Strings and bytes exist as borrowed and owned variants.
+/// Deserializing a [`Value`] borrows from the input, if the data format supports it.
+/// [`Value::into_owned`] turns all borrowed variants into owned ones.
+///
+/// Deserializing other types from a [`Value`] works with the [`ValueDeserializer`] and the [`ValueRefDeserializer`].
+/// Check the [module documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'de> {
+    /// A `bool`
+    Bool(bool),
+
+    /// A `u8`
+    U8(u8),
+    /// A `u16`
+    U16(u16),
+    /// A `u32`
+    U32(u32),
+    /// A `u64`
+    U64(u64),
+    /// A `u128`
+    U128(u128),
+
+    /// An `i8`
+    I8(i8),
+    /// An `i16`
+    I16(i16),
+    /// An `i32`
+    I32(i32),
+    /// An `i64`
+    I64(i64),
+    /// An `i128`
+    I128(i128),
+
+    /// An `f32`
+    F32(f32),
+    /// An `f64`
+    F64(f64),
+
+    /// A `char`
+    Char(char),
+    /// An owned string
+    String(String),
+    /// A string borrowed from the input
+    Str(&'de str),
+    /// An owned byte buffer
+    ByteBuf(Vec<u8>),
+    /// Bytes borrowed from the input
+    Bytes(&'de [u8]),
+
+    /// An absent optional value
+    None,
+    /// A present optional value
+    Some(Box<Value<'de>>),
+
+    /// The unit value `()`
+    Unit,
+    /// The content of a newtype struct
+    Newtype(Box<Value<'de>>),
+    /// A sequence of values
+    Seq(Vec<Value<'de>>),
+    /// A map of key-value pairs, in the order they appeared in the input
+    Map(Vec<(Value<'de>, Value<'de>)>),
+}
+
+impl<'de> Value<'de> {
+    /// Convert all borrowed strings and bytes into owned variants.
+    ///
+    /// The resulting value no longer borrows from the input.
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::Bool(v) => Value::Bool(v),
+            Value::U8(v) => Value::U8(v),
+            Value::U16(v) => Value::U16(v),
+            Value::U32(v) => Value::U32(v),
+            Value::U64(v) => Value::U64(v),
+            Value::U128(v) => Value::U128(v),
+            Value::I8(v) => Value::I8(v),
+            Value::I16(v) => Value::I16(v),
+            Value::I32(v) => Value::I32(v),
+            Value::I64(v) => Value::I64(v),
+            Value::I128(v) => Value::I128(v),
+            Value::F32(v) => Value::F32(v),
+            Value::F64(v) => Value::F64(v),
+            Value::Char(v) => Value::Char(v),
+            Value::String(v) => Value::String(v),
+            Value::Str(v) => Value::String(v.into()),
+            Value::ByteBuf(v) => Value::ByteBuf(v),
+            Value::Bytes(v) => Value::ByteBuf(v.into()),
+            Value::None => Value::None,
+            Value::Some(v) => Value::Some(Box::new(v.into_owned())),
+            Value::Unit => Value::Unit,
+            Value::Newtype(v) => Value::Newtype(Box::new(v.into_owned())),
+            Value::Seq(v) => Value::Seq(v.into_iter().map(Value::into_owned).collect()),
+            Value::Map(v) => Value::Map(
+                v.into_iter()
+                    .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Create a [`ValueDeserializer`] consuming this value.
+    ///
+    /// `is_human_readable` is reported to all deserialized types.
+    /// Usually, it is the value of the original [`Deserializer`].
+    pub fn into_deserializer<E>(self, is_human_readable: bool) -> ValueDeserializer<'de, E> {
+        ValueDeserializer::new(self, is_human_readable)
+    }
+
+    /// Create a [`ValueRefDeserializer`] borrowing this value.
+    ///
+    /// The value can be deserialized multiple times, e.g., to try different types.
+    /// `is_human_readable` is reported to all deserialized types.
+    /// Usually, it is the value of the original [`Deserializer`].
+    pub fn as_deserializer<E>(&self, is_human_readable: bool) -> ValueRefDeserializer<'_, 'de, E> {
+        ValueRefDeserializer::new(self, is_human_readable)
+    }
+}
+
+impl Serialize for Value<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Value::Bool(v) => serializer.serialize_bool(v),
+            Value::U8(v) => serializer.serialize_u8(v),
+            Value::U16(v) => serializer.serialize_u16(v),
+            Value::U32(v) => serializer.serialize_u32(v),
+            Value::U64(v) => serializer.serialize_u64(v),
+            Value::U128(v) => serializer.serialize_u128(v),
+            Value::I8(v) => serializer.serialize_i8(v),
+            Value::I16(v) => serializer.serialize_i16(v),
+            Value::I32(v) => serializer.serialize_i32(v),
+            Value::I64(v) => serializer.serialize_i64(v),
+            Value::I128(v) => serializer.serialize_i128(v),
+            Value::F32(v) => serializer.serialize_f32(v),
+            Value::F64(v) => serializer.serialize_f64(v),
+            Value::Char(v) => serializer.serialize_char(v),
+            Value::String(ref v) => serializer.serialize_str(v),
+            Value::Str(v) => serializer.serialize_str(v),
+            Value::ByteBuf(ref v) => serializer.serialize_bytes(v),
+            Value::Bytes(v) => serializer.serialize_bytes(v),
+            Value::None => serializer.serialize_none(),
+            Value::Some(ref v) => serializer.serialize_some(&**v),
+            Value::Unit => serializer.serialize_unit(),
+            Value::Newtype(ref v) => serializer.serialize_newtype_struct("", &**v),
+            Value::Seq(ref v) => serializer.collect_seq(v),
+            Value::Map(ref v) => serializer.collect_map(v.iter().map(|(k, v)| (k, v))),
+        }
+    }
+}
+
+/// Serializer producing a [`Value`]
+///
+/// The serializer uses the same representation as most self-describing formats.
+///
+/// * Newtype structs are transparent and produce the value of their field.
+/// * Unit structs produce [`Value::Unit`].
+/// * Tuples and tuple structs produce a [`Value::Seq`].
+/// * Structs produce a [`Value::Map`] with the field names as keys.
+/// * Enum variants are externally tagged.
+///   Unit variants produce the variant name, all other variants a map with the variant name as single key.
+///
+/// The names of structs and fields are borrowed as `&'static str`, all other strings and bytes are owned.
+///
+/// ```rust
+/// # use serde::Serialize;
+/// use serde_with::value::{Value, ValueSerializer};
+///
+/// #[derive(Serialize)]
+/// enum Shape {
+///     Circle { radius: u32 },
+/// }
+///
+/// let value = Shape::Circle { radius: 3 }
+///     .serialize(ValueSerializer::<serde::de::value::Error>::new(true))
+///     .unwrap();
+/// let expected = Value::Map(vec![(
+///     Value::Str("Circle"),
+///     Value::Map(vec![(Value::Str("radius"), Value::U32(3))]),
+/// )]);
+/// assert_eq!(expected, value);
+/// ```
+pub struct ValueSerializer<E> {
+    is_human_readable: bool,
+    error: PhantomData<E>,
+}
+
+impl<E> ValueSerializer<E> {
+    /// Create a serializer, which reports `is_human_readable` to the serialized types.
+    pub fn new(is_human_readable: bool) -> Self {
+        ValueSerializer {
+            is_human_readable,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<E> Default for ValueSerializer<E> {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl<E> Serializer for ValueSerializer<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    type SerializeSeq = SerializeValueSeq<E>;
+    type SerializeTuple = SerializeValueSeq<E>;
+    type SerializeTupleStruct = SerializeValueSeq<E>;
+    type SerializeTupleVariant = SerializeValueSeq<E>;
+    type SerializeMap = SerializeValueMap<E>;
+    type SerializeStruct = SerializeValueMap<E>;
+    type SerializeStructVariant = SerializeValueMap<E>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, E> {
+        Ok(Value::I8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, E> {
+        Ok(Value::I16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, E> {
+        Ok(Value::I32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, E> {
+        Ok(Value::I64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, E> {
+        Ok(Value::I128(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, E> {
+        Ok(Value::U8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, E> {
+        Ok(Value::U16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, E> {
+        Ok(Value::U32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, E> {
+        Ok(Value::U64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, E> {
+        Ok(Value::U128(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, E> {
+        Ok(Value::F32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, E> {
+        Ok(Value::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, E> {
+        Ok(Value::Char(v))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Self::Ok, E> {
+        Ok(Value::String(value.to_owned()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok, E> {
+        Ok(Value::ByteBuf(value.to_owned()))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, E> {
+        Ok(Value::None)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, E>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(Value::Some(Box::new(value.serialize(self)?)))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, E> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, E> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, E> {
+        Ok(Value::Str(variant))
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Self::Ok, E>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, E>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(Value::Map(alloc::vec![(
+            Value::Str(variant),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, E> {
+        Ok(SerializeValueSeq {
+            is_human_readable: self.is_human_readable,
+            variant: None,
+            elements: utils::vec_with_capacity_cautious(len),
+            error: PhantomData,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, E> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, E> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, E> {
+        let mut seq = self.serialize_seq(Some(len))?;
+        seq.variant = Some(variant);
+        Ok(seq)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, E> {
+        Ok(SerializeValueMap {
+            is_human_readable: self.is_human_readable,
+            variant: None,
+            entries: utils::vec_with_capacity_cautious(len),
+            key: None,
+            error: PhantomData,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct, E> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, E> {
+        let mut map = self.serialize_map(Some(len))?;
+        map.variant = Some(variant);
+        Ok(map)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.is_human_readable
+    }
+}
+
+/// Wrap the value of an enum variant in a map with the variant name as key.
+fn tag_variant(variant: Option<&'static str>, value: Value<'static>) -> Value<'static> {
+    match variant {
+        Some(variant) => Value::Map(alloc::vec![(Value::Str(variant), value)]),
+        None => value,
+    }
+}
+
+/// Serialize sequences, tuples, and tuple variants into a [`Value`]
+///
+/// Returned by [`ValueSerializer`].
+pub struct SerializeValueSeq<E> {
+    is_human_readable: bool,
+    variant: Option<&'static str>,
+    elements: Vec<Value<'static>>,
+    error: PhantomData<E>,
+}
+
+impl<E> SerializeValueSeq<E>
+where
+    E: SerError,
+{
+    fn push<T>(&mut self, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer::new(self.is_human_readable))?;
+        self.elements.push(value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value<'static>, E> {
+        Ok(tag_variant(self.variant, Value::Seq(self.elements)))
+    }
+}
+
+impl<E> SerializeSeq for SerializeValueSeq<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, E> {
+        self.finish()
+    }
+}
+
+impl<E> SerializeTuple for SerializeValueSeq<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, E> {
+        self.finish()
+    }
+}
+
+impl<E> SerializeTupleStruct for SerializeValueSeq<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, E> {
+        self.finish()
+    }
+}
+
+impl<E> SerializeTupleVariant for SerializeValueSeq<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, E> {
+        self.finish()
+    }
+}
+
+/// Serialize maps, structs, and struct variants into a [`Value`]
+///
+/// Returned by [`ValueSerializer`].
+pub struct SerializeValueMap<E> {
+    is_human_readable: bool,
+    variant: Option<&'static str>,
+    entries: Vec<(Value<'static>, Value<'static>)>,
+    key: Option<Value<'static>>,
+    error: PhantomData<E>,
+}
+
+impl<E> SerializeValueMap<E>
+where
+    E: SerError,
+{
+    fn push_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer::new(self.is_human_readable))?;
+        self.entries.push((Value::Str(key), value));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value<'static>, E> {
+        Ok(tag_variant(self.variant, Value::Map(self.entries)))
+    }
+}
+
+impl<E> SerializeMap for SerializeValueMap<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = key.serialize(ValueSerializer::new(self.is_human_readable))?;
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value called before serialize_key");
+        let value = value.serialize(ValueSerializer::new(self.is_human_readable))?;
+        self.entries.push((key, value));
+        Ok(())
+    }
+
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), E>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        let key = key.serialize(ValueSerializer::new(self.is_human_readable))?;
+        let value = value.serialize(ValueSerializer::new(self.is_human_readable))?;
+        self.entries.push((key, value));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, E> {
+        self.finish()
+    }
+}
+
+impl<E> SerializeStruct for SerializeValueMap<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push_field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, E> {
+        self.finish()
+    }
+}
+
+impl<E> SerializeStructVariant for SerializeValueMap<E>
+where
+    E: SerError,
+{
+    type Ok = Value<'static>;
+    type Error = E;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), E>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push_field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, E> {
+        self.finish()
+    }
+}
@@ -0,0 +1,159 @@
+//! Test Cases
+#![allow(
+    // This allows the tests to be written more uniform and not have to special case the last clone().
+    clippy::redundant_clone,
+)]
+
+mod utils;
+
+use crate::utils::{check_deserialization, check_error_deserialization, is_equal};
+use expect_test::expect;
+use serde::{Deserialize, Serialize};
+use serde_with::{
+    base32::{Base32, Crockford, ExtendedHex, Standard, ZBase32},
+    formats::{Padded, Unpadded},
+    serde_as,
+};
+
+#[test]
+fn base32_vec() {
+    let check_equal = vec![vec![0, 1, 2, 13], vec![14, 5, 6, 7]];
+    let check_deser = vec![vec![0xaa, 0xbc, 0xff], vec![0xe0, 0x7d], vec![0xe0, 0x7d]];
+    let check_deser_from = r#"["VK6P6===","4B6Q====","4b6q"]"#;
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct BDefault(#[serde_as(as = "Vec<Base32>")] Vec<Vec<u8>>);
+
+    is_equal(
+        BDefault(check_equal.clone()),
+        expect![[r#"
+            [
+              "AAAQEDI=",
+              "BYCQMBY="
+            ]"#]],
+    );
+
+    // Check mixed padding deserialization
+    check_deserialization(BDefault(check_deser.clone()), check_deser_from);
+
+    check_error_deserialization::<BDefault>(
+        r#"["0"]"#,
+        expect!["invalid length at 0 at line 1 column 4"],
+    );
+    check_error_deserialization::<BDefault>(
+        r#"["AAA"]"#,
+        expect!["invalid length at 2 at line 1 column 6"],
+    );
+    check_error_deserialization::<BDefault>(
+        r#"["AB"]"#,
+        expect!["non-zero trailing bits at 1 at line 1 column 5"],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct BPadded(#[serde_as(as = "Vec<Base32<Standard, Padded>>")] Vec<Vec<u8>>);
+
+    is_equal(
+        BPadded(check_equal.clone()),
+        expect![[r#"
+            [
+              "AAAQEDI=",
+              "BYCQMBY="
+            ]"#]],
+    );
+    check_deserialization(BPadded(check_deser.clone()), check_deser_from);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct BUnpadded(#[serde_as(as = "Vec<Base32<Standard, Unpadded>>")] Vec<Vec<u8>>);
+
+    is_equal(
+        BUnpadded(check_equal.clone()),
+        expect![[r#"
+            [
+              "AAAQEDI",
+              "BYCQMBY"
+            ]"#]],
+    );
+    check_deserialization(BUnpadded(check_deser.clone()), check_deser_from);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct BArray(#[serde_as(as = "Base32")] [u8; 4]);
+
+    is_equal(BArray([0, 1, 2, 13]), expect![[r#""AAAQEDI=""#]]);
+    check_error_deserialization::<BArray>(
+        r#""AAAQ====""#,
+        expect!["Can't convert a Byte Vector of length 2 to the output type. at line 1 column 10"],
+    );
+}
+
+#[test]
+fn base32_different_charsets() {
+    let bytes = [
+        0x00_u8, 0x44, 0x32, 0x14, 0xc7, 0x42, 0x54, 0xb6, 0x35, 0xcf, 0x84, 0x65, 0x3a, 0x56,
+        0xd7, 0xc6, 0x75, 0xbe, 0x77, 0xdf,
+    ];
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct B32Standard(#[serde_as(as = "Base32<Standard, Padded>")] Vec<u8>);
+
+    is_equal(
+        B32Standard(bytes.to_vec()),
+        expect![[r#""ABCDEFGHIJKLMNOPQRSTUVWXYZ234567""#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct B32ExtendedHex(#[serde_as(as = "Base32<ExtendedHex, Padded>")] Vec<u8>);
+
+    is_equal(
+        B32ExtendedHex(bytes.to_vec()),
+        expect![[r#""0123456789ABCDEFGHIJKLMNOPQRSTUV""#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct B32Crockford(#[serde_as(as = "Base32<Crockford, Padded>")] Vec<u8>);
+
+    is_equal(
+        B32Crockford(bytes.to_vec()),
+        expect![[r#""0123456789ABCDEFGHJKMNPQRSTVWXYZ""#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct B32ZBase32(#[serde_as(as = "Base32<ZBase32, Padded>")] Vec<u8>);
+
+    is_equal(
+        B32ZBase32(bytes.to_vec()),
+        expect![[r#""ybndrfg8ejkmcpqxot1uwisza345h769""#]],
+    );
+}
+
+#[test]
+fn base32_crockford_decoding() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct B32Crockford(#[serde_as(as = "Base32<Crockford>")] Vec<u8>);
+
+    let bytes = b"Hello World".to_vec();
+    is_equal(
+        B32Crockford(bytes.clone()),
+        expect![[r#""91JPRV3F41BPYWKCCG""#]],
+    );
+    // Lowercase characters and the ambiguous characters `I`, `L`, and `O`
+    check_deserialization(B32Crockford(bytes.clone()), r#""9ljprv3f4IbpywkccG""#);
+    check_deserialization(B32Crockford(bytes.clone()), r#""91JP-RV3F-41BP-YWKC-CG""#);
+    check_deserialization(B32Crockford(vec![0, 0]), r#""ooOo""#);
+    check_error_deserialization::<B32Crockford>(
+        r#""91JPRV3F41BPYWKCCU""#,
+        expect!["invalid symbol at 17 at line 1 column 20"],
+    );
+    check_error_deserialization::<B32Crockford>(
+        r#""91JPRV3F41BPYWKCCG======""#,
+        expect!["invalid symbol at 18 at line 1 column 26"],
+    );
+}
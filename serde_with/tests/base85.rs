@@ -0,0 +1,133 @@
+//! Test Cases
+
+mod utils;
+
+use crate::utils::{
+    check_deserialization, check_error_deserialization, check_error_serialization, is_equal,
+};
+use alloc::borrow::Cow;
+use expect_test::expect;
+use serde::{Deserialize, Serialize};
+use serde_with::{
+    base85::{Ascii85, Base85, Z85},
+    serde_as,
+};
+
+extern crate alloc;
+
+#[test]
+fn base85_z85() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct BVec(#[serde_as(as = "Base85<Z85>")] Vec<u8>);
+
+    // Test vector from the Z85 specification
+    is_equal(
+        BVec(vec![0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]),
+        expect![[r#""HelloWorld""#]],
+    );
+    is_equal(BVec(vec![]), expect![[r#""""#]]);
+    is_equal(BVec(vec![0; 4]), expect![[r#""00000""#]]);
+    is_equal(BVec(vec![0xff; 4]), expect![[r#""%nSc0""#]]);
+
+    check_error_serialization(
+        BVec(vec![1, 2, 3]),
+        expect!["Z85 can only encode data with a length that is a multiple of 4"],
+    );
+    check_error_deserialization::<BVec>(
+        r#""Hello~orld""#,
+        expect!["Invalid base85 character '~' at position 5 at line 1 column 12"],
+    );
+    check_error_deserialization::<BVec>(
+        r#""Hell""#,
+        expect![
+            "Z85 can only decode data with a length that is a multiple of 5 at line 1 column 6"
+        ],
+    );
+    check_error_deserialization::<BVec>(
+        r#""Hello Worl""#,
+        expect!["Invalid base85 character ' ' at position 5 at line 1 column 12"],
+    );
+    check_error_deserialization::<BVec>(
+        r#""%%%%%""#,
+        expect!["Invalid base85 group, the value does not fit into 4 bytes at line 1 column 7"],
+    );
+    check_error_deserialization::<BVec>(
+        r#""Hellö""#,
+        expect!["Invalid base85 character 'ö' at position 4 at line 1 column 8"],
+    );
+}
+
+#[test]
+fn base85_ascii85() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct BVec(#[serde_as(as = "Base85<Ascii85>")] Vec<u8>);
+
+    is_equal(BVec(b"Man ".to_vec()), expect![[r#""9jqo^""#]]);
+    is_equal(BVec(b"sure.".to_vec()), expect![[r#""F*2M7/c""#]]);
+    is_equal(BVec(vec![]), expect![[r#""""#]]);
+    is_equal(BVec(vec![0]), expect![[r#""!!""#]]);
+    is_equal(BVec(vec![0; 9]), expect![[r#""zz!!""#]]);
+
+    check_deserialization(BVec(b"sure.".to_vec()), r#""<~F*2M7/c~>""#);
+    check_deserialization(BVec(b"sure.".to_vec()), r#""F*2M7 /c""#);
+    check_deserialization(BVec(vec![0; 8]), r#""zz""#);
+    check_error_deserialization::<BVec>(
+        r#""9jzqo^""#,
+        expect![[
+            r#"Invalid base85 character 'z' at position 2, it is only allowed between groups at line 1 column 8"#
+        ]],
+    );
+    check_error_deserialization::<BVec>(
+        r#""9jqo^v""#,
+        expect!["Invalid base85 character 'v' at position 5 at line 1 column 8"],
+    );
+    check_error_deserialization::<BVec>(
+        r#""9jqo^F""#,
+        expect![[
+            r#"Invalid base85 length, the last group consists of a single character at line 1 column 8"#
+        ]],
+    );
+    check_error_deserialization::<BVec>(
+        r#""uuuuu""#,
+        expect!["Invalid base85 group, the value does not fit into 4 bytes at line 1 column 7"],
+    );
+}
+
+#[test]
+fn base85_containers() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct Containers<'a> {
+        #[serde_as(as = "Base85<Z85>")]
+        array: [u8; 8],
+        #[serde_as(as = "Base85<Ascii85>")]
+        boxed: Box<[u8]>,
+        #[serde_as(as = "Base85<Ascii85>")]
+        cow: Cow<'a, [u8]>,
+    }
+
+    is_equal(
+        Containers {
+            array: [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B],
+            boxed: b"Man ".to_vec().into_boxed_slice(),
+            cow: Cow::Borrowed(b"sure."),
+        },
+        expect![[r#"
+            {
+              "array": "HelloWorld",
+              "boxed": "9jqo^",
+              "cow": "F*2M7/c"
+            }"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct BArray(#[serde_as(as = "Base85<Z85>")] [u8; 4]);
+
+    check_error_deserialization::<BArray>(
+        r#""HelloWorld""#,
+        expect!["Can't convert a Byte Vector of length 8 to the output type. at line 1 column 12"],
+    );
+}
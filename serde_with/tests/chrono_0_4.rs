@@ -15,9 +15,10 @@ use serde::{Deserialize, Serialize};
 use serde_with::{
     formats::Flexible, serde_as, DurationMicroSeconds, DurationMicroSecondsWithFrac,
     DurationMilliSeconds, DurationMilliSecondsWithFrac, DurationNanoSeconds,
-    DurationNanoSecondsWithFrac, DurationSeconds, DurationSecondsWithFrac, TimestampMicroSeconds,
-    TimestampMicroSecondsWithFrac, TimestampMilliSeconds, TimestampMilliSecondsWithFrac,
-    TimestampNanoSeconds, TimestampNanoSecondsWithFrac, TimestampSeconds, TimestampSecondsWithFrac,
+    DurationNanoSecondsWithFrac, DurationSeconds, DurationSecondsWithFrac, HumanDuration,
+    Iso8601Duration, TimestampMicroSeconds, TimestampMicroSecondsWithFrac, TimestampMilliSeconds,
+    TimestampMilliSecondsWithFrac, TimestampNanoSeconds, TimestampNanoSecondsWithFrac,
+    TimestampSeconds, TimestampSecondsWithFrac,
 };
 
 fn new_datetime(secs: i64, nsecs: u32) -> DateTime<Utc> {
@@ -743,3 +744,66 @@ fn test_naive_datetime_smoketest() {
         NaiveDateTime, "TimestampSecondsWithFrac", zero - Duration::seconds(1), {expect![[r#"-1.0"#]]};
     };
 }
+
+#[test]
+fn test_iso8601_duration() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Strict(#[serde_as(as = "Iso8601Duration")] Duration);
+
+    is_equal(Strict(Duration::zero()), expect![[r#""PT0S""#]]);
+    is_equal(
+        Strict(Duration::seconds(5412) + Duration::milliseconds(500)),
+        expect![[r#""PT1H30M12.5S""#]],
+    );
+    is_equal(Strict(-Duration::seconds(90)), expect![[r#""-PT1M30S""#]]);
+    check_deserialization(Strict(Duration::seconds(93_600)), r#""P1DT2H""#);
+    check_deserialization(Strict(Duration::zero()), r#""-PT0S""#);
+    check_error_deserialization::<Strict>(
+        r#""P1M""#,
+        expect![[
+            r#"ISO 8601 durations with months are not supported, since months have no fixed length at line 1 column 5"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Flexible(#[serde_as(as = "Iso8601Duration<serde_with::formats::Flexible>")] Duration);
+
+    is_equal(Flexible(-Duration::seconds(90)), expect![[r#""-PT1M30S""#]]);
+    check_deserialization(Flexible(-Duration::seconds(90)), r#"-90"#);
+    check_deserialization(Flexible(-Duration::seconds(90)), r#""pt-1m-30s""#);
+}
+
+#[test]
+fn test_human_duration() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Strict(#[serde_as(as = "HumanDuration")] Duration);
+
+    is_equal(Strict(Duration::zero()), expect![[r#""0s""#]]);
+    is_equal(Strict(Duration::seconds(5405)), expect![[r#""1h30m5s""#]]);
+    is_equal(
+        Strict(-Duration::milliseconds(250)),
+        expect![[r#""-250ms""#]],
+    );
+    check_deserialization(Strict(Duration::seconds(5405)), r#""1h 30m 5s""#);
+    check_deserialization(Strict(-Duration::milliseconds(250)), r#""-0.25s""#);
+    check_error_deserialization::<Strict>(
+        r#""1y""#,
+        expect![[
+            r#"invalid value: string "1y", expected a duration like `1h30m5s` or `250ms` at line 1 column 4"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Flexible(#[serde_as(as = "HumanDuration<serde_with::formats::Flexible>")] Duration);
+
+    is_equal(
+        Flexible(-Duration::milliseconds(250)),
+        expect![[r#""-250ms""#]],
+    );
+    check_deserialization(Flexible(-Duration::milliseconds(250)), r#"-0.25"#);
+    check_deserialization(Flexible(-Duration::milliseconds(250)), r#""-250MS""#);
+}
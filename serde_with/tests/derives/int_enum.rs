@@ -0,0 +1,118 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use serde_with::{formats::Flexible, serde_as, EnumAsInt, IntEnum};
+
+#[derive(Debug, PartialEq, IntEnum)]
+#[repr(u8)]
+enum Status {
+    Active = 1,
+    Suspended = 2,
+    Deleted = 4,
+}
+
+#[derive(Debug, PartialEq, IntEnum)]
+enum Implicit {
+    A = -1,
+    B,
+    C,
+}
+
+#[derive(Debug, PartialEq, IntEnum)]
+#[serde_with(crate = "::serde_with")]
+#[repr(i16)]
+enum WithFallback {
+    Low = 10,
+    High = 20,
+    #[serde_with(other)]
+    Unknown = -1,
+}
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct S<T: IntEnum>(#[serde_as(as = "EnumAsInt")] T);
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SFlexible<T: IntEnum>(#[serde_as(as = "EnumAsInt<Flexible>")] T);
+
+#[test]
+fn test_int_enum_trait() {
+    assert_eq!(
+        &[("Active", 1), ("Suspended", 2), ("Deleted", 4)],
+        Status::VARIANTS
+    );
+    assert_eq!(&[("A", -1), ("B", 0), ("C", 1)], Implicit::VARIANTS);
+    assert_eq!(
+        [false, true],
+        [Status::HAS_FALLBACK, WithFallback::HAS_FALLBACK]
+    );
+    assert_eq!(Some(Implicit::B), Implicit::from_repr(0));
+    assert_eq!(Some(WithFallback::Unknown), WithFallback::from_repr(99));
+    assert_eq!(Some(WithFallback::High), WithFallback::from_name("High"));
+    assert_eq!(
+        Some(WithFallback::Unknown),
+        WithFallback::from_name("Middle")
+    );
+}
+
+#[test]
+fn test_enum_as_int() {
+    is_equal(S(Status::Active), expect!["1"]);
+    is_equal(S(Status::Deleted), expect!["4"]);
+    is_equal(S(Implicit::A), expect!["-1"]);
+    is_equal(S(Implicit::C), expect!["1"]);
+    is_equal(S(WithFallback::Unknown), expect!["-1"]);
+
+    check_deserialization(S(WithFallback::Unknown), "15");
+    check_error_deserialization::<S<Status>>(
+        "3",
+        expect!["invalid value: integer `3`, expected one of `1`, `2`, `4`"],
+    );
+    check_error_deserialization::<S<Status>>(
+        "300",
+        expect!["invalid value: integer `300`, expected u8 at line 1 column 3"],
+    );
+    check_error_deserialization::<S<Status>>(
+        r#""Active""#,
+        expect![[r#"invalid type: string "Active", expected u8 at line 1 column 8"#]],
+    );
+}
+
+#[test]
+fn test_enum_as_int_flexible() {
+    is_equal(SFlexible(Status::Suspended), expect!["2"]);
+    check_deserialization(SFlexible(Status::Suspended), r#""Suspended""#);
+    check_deserialization(SFlexible(WithFallback::Unknown), r#""Middle""#);
+    check_deserialization(SFlexible(WithFallback::Unknown), "300");
+
+    check_error_deserialization::<SFlexible<Status>>(
+        "3",
+        expect![[
+            r#"invalid value: integer `3`, expected one of `1`, `2`, `4` or `Active`, `Suspended`, `Deleted` at line 1 column 1"#
+        ]],
+    );
+    check_error_deserialization::<SFlexible<Status>>(
+        r#""Archived""#,
+        expect![[
+            r#"invalid value: string "Archived", expected one of `1`, `2`, `4` or `Active`, `Suspended`, `Deleted` at line 1 column 10"#
+        ]],
+    );
+}
+
+#[test]
+fn test_enum_as_int_flexible_128_bit() {
+    use serde::de::{value::Error, IntoDeserializer};
+    use serde_with::DeserializeAs;
+
+    let value: Result<Status, Error> =
+        EnumAsInt::<Flexible>::deserialize_as(2_i128.into_deserializer());
+    assert_eq!(Ok(Status::Suspended), value);
+    let value: Result<Status, Error> =
+        EnumAsInt::<Flexible>::deserialize_as(4_u128.into_deserializer());
+    assert_eq!(Ok(Status::Deleted), value);
+
+    let value: Result<Status, Error> =
+        EnumAsInt::<Flexible>::deserialize_as(u128::MAX.into_deserializer());
+    expect![[r#"invalid value: integer `340282366920938463463374607431768211455` as u128, expected one of `1`, `2`, `4` or `Active`, `Suspended`, `Deleted`"#]]
+        .assert_eq(&value.unwrap_err().to_string());
+}
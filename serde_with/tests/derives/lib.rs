@@ -1,6 +1,7 @@
 //! Test Cases
 
 mod deserialize_fromstr;
+mod int_enum;
 mod serialize_display;
 #[path = "../utils.rs"]
 mod utils;
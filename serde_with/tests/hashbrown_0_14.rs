@@ -386,3 +386,37 @@ fn test_map_skip_error_hashmap_flatten() {
             }"#]],
     );
 }
+
+#[test]
+fn test_sorted() {
+    use serde_with::{SortedMap, SortedSet};
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "SortedMap<DisplayFromStr, _>")]
+        map: HashMap<u8, u8>,
+        #[serde_as(as = "SortedSet<_>")]
+        set: HashSet<u8>,
+    }
+
+    is_equal(
+        S {
+            map: [(10, 1), (2, 2), (1, 3)].into_iter().collect(),
+            set: [3, 1, 2].into_iter().collect(),
+        },
+        expect![[r#"
+            {
+              "map": {
+                "1": 3,
+                "2": 2,
+                "10": 1
+              },
+              "set": [
+                1,
+                2,
+                3
+              ]
+            }"#]],
+    );
+}
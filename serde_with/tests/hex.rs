@@ -6,8 +6,10 @@ use crate::utils::{check_deserialization, check_error_deserialization, is_equal}
 use expect_test::expect;
 use serde::{Deserialize, Serialize};
 use serde_with::{
-    formats::{Lowercase, Uppercase},
-    hex::Hex,
+    formats::{
+        ColonSeparator, Lowercase, NoSeparator, Prefixed, SpaceSeparator, Unprefixed, Uppercase,
+    },
+    hex::{Hex, HexInt},
     serde_as,
 };
 
@@ -85,3 +87,156 @@ fn hex_vec_uppercase() {
         r#"["aaBCff","E07d"]"#,
     );
 }
+
+#[test]
+fn hex_separator() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct B(
+        #[serde_as(as = "Hex<Uppercase, ColonSeparator>")] Vec<u8>,
+        #[serde_as(as = "Hex<Lowercase, SpaceSeparator>")] Vec<u8>,
+    );
+
+    is_equal(
+        B(vec![0x00, 0x1a, 0x2b, 0xff], vec![0xde, 0xad, 0xbe, 0xef]),
+        expect![[r#"
+            [
+              "00:1A:2B:FF",
+              "de ad be ef"
+            ]"#]],
+    );
+    is_equal(
+        B(vec![], vec![0x01]),
+        expect![[r#"
+            [
+              "",
+              "01"
+            ]"#]],
+    );
+
+    // Groups may contain multiple bytes
+    check_deserialization(
+        B(vec![0x00, 0x1a, 0x2b, 0xff], vec![0xde, 0xad, 0xbe, 0xef]),
+        r#"["001a:2BfF","dead beef"]"#,
+    );
+
+    check_error_deserialization::<B>(
+        r#"["00::1A","00"]"#,
+        expect![[
+            r#"Invalid hex string, expected bytes between two separators ":" at line 1 column 10"#
+        ]],
+    );
+    check_error_deserialization::<B>(
+        r#"["00:1A:","00"]"#,
+        expect![[
+            r#"Invalid hex string, expected bytes between two separators ":" at line 1 column 10"#
+        ]],
+    );
+    check_error_deserialization::<B>(
+        r#"["00:1","00"]"#,
+        expect![[r#"Odd number of digits at line 1 column 8"#]],
+    );
+}
+
+#[test]
+fn hex_prefix() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct B(
+        #[serde_as(as = "Hex<Lowercase, NoSeparator, Prefixed>")] Vec<u8>,
+        #[serde_as(as = "Hex<Uppercase, SpaceSeparator, Prefixed>")] Vec<u8>,
+        #[serde_as(as = "Hex<Lowercase, NoSeparator, Unprefixed>")] Vec<u8>,
+    );
+
+    is_equal(
+        B(vec![0xca, 0xfe], vec![0xca, 0xfe], vec![0xca, 0xfe]),
+        expect![[r#"
+            [
+              "0xcafe",
+              "0xCA FE",
+              "cafe"
+            ]"#]],
+    );
+
+    // With `Prefixed` the prefix is optional during deserialization and accepted in either case
+    check_deserialization(
+        B(vec![0xca, 0xfe], vec![0xca, 0xfe], vec![0xca, 0xfe]),
+        r#"["CAFE","0Xca fe","cafe"]"#,
+    );
+    check_deserialization(B(vec![], vec![], vec![]), r#"["0x","",""]"#);
+
+    // `Unprefixed` rejects the prefix
+    check_error_deserialization::<B>(
+        r#"["","","0xcafe"]"#,
+        expect!["Invalid character 'x' at position 1 at line 1 column 16"],
+    );
+}
+
+#[test]
+fn hex_int() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    pub struct Ints {
+        #[serde_as(as = "HexInt")]
+        default: u32,
+        #[serde_as(as = "HexInt<Prefixed, Uppercase, 4>")]
+        padded: u16,
+        #[serde_as(as = "HexInt<Unprefixed>")]
+        unprefixed: u64,
+        #[serde_as(as = "HexInt")]
+        signed: i8,
+        #[serde_as(as = "HexInt<Prefixed, Lowercase, 32>")]
+        large: u128,
+    }
+
+    is_equal(
+        Ints {
+            default: 0xdead_beef,
+            padded: 0x2a,
+            unprefixed: 0,
+            signed: -1,
+            large: 1,
+        },
+        expect![[r#"
+            {
+              "default": "0xdeadbeef",
+              "padded": "0x002A",
+              "unprefixed": "0",
+              "signed": "0xff",
+              "large": "0x00000000000000000000000000000001"
+            }"#]],
+    );
+
+    // The prefix is optional and digits are case-insensitive
+    check_deserialization(
+        Ints {
+            default: 0xdead_beef,
+            padded: 0x2a,
+            unprefixed: 0xff,
+            signed: i8::MIN,
+            large: u128::MAX,
+        },
+        r#"{
+            "default": "DeadBeef",
+            "padded": "0X2a",
+            "unprefixed": "0xff",
+            "signed": "80",
+            "large": "0xffffffffffffffffffffffffffffffff"
+        }"#,
+    );
+
+    check_error_deserialization::<Ints>(
+        r#"{"default": "0x100000000", "padded": "0", "unprefixed": "0", "signed": "0", "large": "0"}"#,
+        expect![[
+            r#"invalid value: string "0x100000000", expected a hex encoded u32 at line 1 column 25"#
+        ]],
+    );
+    check_error_deserialization::<Ints>(
+        r#"{"default": "+1", "padded": "0", "unprefixed": "0", "signed": "0", "large": "0"}"#,
+        expect![[r#"invalid value: string "+1", expected a hex encoded u32 at line 1 column 16"#]],
+    );
+    check_error_deserialization::<Ints>(
+        r#"{"default": "0x", "padded": "0", "unprefixed": "0", "signed": "0", "large": "0"}"#,
+        expect![[r#"invalid value: string "0x", expected a hex encoded u32 at line 1 column 16"#]],
+    );
+}
@@ -346,3 +346,138 @@ fn test_map_skip_error_indexmap_flatten() {
         }"#]],
     );
 }
+
+#[test]
+fn test_seq_skip_error_indexset() {
+    use serde_with::SeqSkipError;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "SeqSkipError<DisplayFromStr>")] IndexSet<u8>);
+
+    check_deserialization(
+        S([3, 1].into_iter().collect()),
+        r#"["3", "x", "1", "300", "3"]"#,
+    );
+    is_equal(
+        S([3, 1].into_iter().collect()),
+        expect![[r#"
+            [
+              "3",
+              "1"
+            ]"#]],
+    );
+}
+
+#[test]
+fn test_sorted() {
+    use serde_with::{formats::Descending, SortedMap, SortedSet};
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "SortedMap<DisplayFromStr, _>")]
+        map: IndexMap<u8, u8>,
+        #[serde_as(as = "SortedSet<_, Descending>")]
+        set: IndexSet<u8>,
+    }
+
+    // The insertion order is ignored
+    let s = S {
+        map: [(10, 1), (2, 2), (1, 3)].into_iter().collect(),
+        set: [1, 3, 2].into_iter().collect(),
+    };
+    is_equal(
+        s,
+        expect![[r#"
+        {
+          "map": {
+            "1": 3,
+            "2": 2,
+            "10": 1
+          },
+          "set": [
+            3,
+            2,
+            1
+          ]
+        }"#]],
+    );
+    check_deserialization(
+        S {
+            map: [(2, 2), (1, 3)].into_iter().collect(),
+            set: [1, 2].into_iter().collect(),
+        },
+        r#"{"map": {"2": 2, "1": 3}, "set": [1, 2]}"#,
+    );
+}
+
+#[test]
+fn test_one_or_many_indexset() {
+    use serde_with::{
+        formats::{PreferNone, PreventDuplicates},
+        OneOrMany,
+    };
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S(#[serde_as(as = "OneOrMany<_, PreferNone, PreventDuplicates>")] IndexSet<u32>);
+
+    is_equal(S(IndexSet::new()), expect![[r#"null"#]]);
+    is_equal(S(IndexSet::from([3])), expect![[r#"3"#]]);
+    is_equal(
+        S(IndexSet::from([3, 1])),
+        expect![[r#"
+            [
+              3,
+              1
+            ]"#]],
+    );
+    check_deserialization(S(IndexSet::new()), r#"null"#);
+    check_error_deserialization::<S>(
+        r#"[3, 1, 3]"#,
+        expect![[r#"
+            OneOrMany could not deserialize any variant:
+              One: invalid type: sequence, expected u32
+              Many: invalid entry: found duplicate value"#]],
+    );
+}
+
+#[test]
+fn test_key_value_map_indexmap() {
+    use serde_with::KeyValueMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Server {
+        #[serde(rename = "$key$")]
+        name: String,
+        port: u16,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "KeyValueMap<_>")] IndexMap<String, Server>);
+
+    let server = |name: &str, port| {
+        (
+            name.to_string(),
+            Server {
+                name: name.to_string(),
+                port,
+            },
+        )
+    };
+    // The insertion order is kept
+    is_equal(
+        S(IndexMap::from([server("web", 80), server("db", 5432)])),
+        expect![[r#"
+            {
+              "web": {
+                "port": 80
+              },
+              "db": {
+                "port": 5432
+              }
+            }"#]],
+    );
+}
@@ -5,7 +5,7 @@ use expect_test::expect_file;
 use schemars::JsonSchema;
 use serde::Serialize;
 use serde_json::json;
-use serde_with::{base58::*, base64::*, hex::*, *};
+use serde_with::{base32::*, base58::*, base64::*, hex::*, *};
 use std::collections::{BTreeMap, BTreeSet};
 
 // This avoids us having to add `#[schemars(crate = "::schemars_0_8")]` all
@@ -99,6 +99,15 @@ fn schemars_basic() {
         #[serde_as(as = "Hex<formats::Uppercase>")]
         uppercase_hex: Vec<u8>,
 
+        /// A vector of bytes that's serialized as a base32 string.
+        #[serde_as(as = "Base32")]
+        base32: Vec<u8>,
+
+        /// A vector of bytes that's serialized as a base32 string in
+        /// `Crockford` charset.
+        #[serde_as(as = "Base32<Crockford>")]
+        base32_crockford: Vec<u8>,
+
         /// A vector of bytes that's serialized as a base58 string.
         #[serde_as(as = "Base58")]
         base58: Vec<u8>,
@@ -647,6 +656,60 @@ mod bool_from_int {
     }
 }
 
+mod int_from_float {
+    use super::*;
+    use serde_with::formats::{Flexible, Nearest};
+
+    #[serde_as]
+    #[derive(Serialize, JsonSchema)]
+    struct Test {
+        #[serde_as(as = "IntFromFloat")]
+        strict: u8,
+        #[serde_as(as = "IntFromFloat<Flexible, Nearest>")]
+        flexible: i32,
+        #[serde_as(as = "FloatFromInt")]
+        float: f64,
+    }
+
+    #[test]
+    fn test_serialized_is_valid() {
+        check_valid_json_schema(&Test {
+            strict: 200,
+            flexible: -5,
+            float: 42.0,
+        });
+    }
+
+    #[test]
+    fn test_integral_floats() {
+        check_matches_schema::<Test>(&json!({
+            "strict": 3.0,
+            "flexible": 2.5,
+            "float": 7,
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strict_fractional() {
+        check_matches_schema::<Test>(&json!({
+            "strict": 2.5,
+            "flexible": 2.5,
+            "float": 7,
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_float_fractional() {
+        check_matches_schema::<Test>(&json!({
+            "strict": 3,
+            "flexible": 2.5,
+            "float": 7.5,
+        }));
+    }
+}
+
 mod bytes_or_string {
     use super::*;
 
@@ -687,6 +750,80 @@ mod bytes_or_string {
     }
 }
 
+mod hex {
+    use super::*;
+
+    #[serde_as]
+    #[derive(Serialize, JsonSchema)]
+    struct Test {
+        #[serde_as(as = "Hex")]
+        plain: Vec<u8>,
+        #[serde_as(as = "Hex<formats::Uppercase, formats::ColonSeparator>")]
+        mac: Vec<u8>,
+        #[serde_as(as = "Hex<formats::Lowercase, formats::NoSeparator, formats::Prefixed>")]
+        prefixed: Vec<u8>,
+    }
+
+    #[test]
+    fn test_serialized_is_valid() {
+        check_valid_json_schema(&Test {
+            plain: vec![0xde, 0xad],
+            mac: vec![0x00, 0x1a, 0x2b],
+            prefixed: vec![0xbe, 0xef],
+        });
+    }
+
+    #[test]
+    fn test_deserializable_is_valid_json() {
+        // Groups may contain multiple bytes and the prefix is optional if `Prefixed`
+        check_matches_schema::<Test>(&json!({
+            "plain": "DEADbeef",
+            "mac": "00:1A2B:3C",
+            "prefixed": "beef",
+        }));
+        check_matches_schema::<Test>(&json!({
+            "plain": "",
+            "mac": "",
+            "prefixed": "0XBEEF",
+        }));
+        check_matches_schema::<Test>(&json!({
+            "plain": "",
+            "mac": "",
+            "prefixed": "0x",
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_group_not_valid_json() {
+        check_matches_schema::<Test>(&json!({
+            "plain": "",
+            "mac": "00::1A",
+            "prefixed": "",
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unprefixed_rejects_prefix() {
+        check_matches_schema::<Test>(&json!({
+            "plain": "0xdead",
+            "mac": "",
+            "prefixed": "",
+        }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_odd_length_not_valid_json() {
+        check_matches_schema::<Test>(&json!({
+            "plain": "abc",
+            "mac": "",
+            "prefixed": "",
+        }));
+    }
+}
+
 mod enum_map {
     use super::*;
 
@@ -901,6 +1038,119 @@ fn test_if_is_human_readable() {
     check_matches_schema::<Test>(&json!({ "data": "5" }));
 }
 
+#[test]
+fn test_number_as_string() {
+    #[serde_as]
+    #[derive(Serialize, JsonSchema)]
+    struct Test {
+        #[serde_as(as = "NumberAsString")]
+        id: u64,
+        #[serde_as(as = "NumberAsString<formats::LargeAsString>")]
+        balance: i64,
+        #[serde_as(as = "NumberAsString<formats::AlwaysNumber>")]
+        rate: f64,
+    }
+
+    check_matches_schema::<Test>(&json!({
+        "id": "18446744073709551615",
+        "balance": 42,
+        "rate": 0.5,
+    }));
+    check_matches_schema::<Test>(&json!({
+        "id": 1,
+        "balance": "-9007199254740993",
+        "rate": "0.5",
+    }));
+    // All strings accepted by `FromStr` match the pattern
+    check_matches_schema::<Test>(&json!({
+        "id": "+42",
+        "balance": "+7",
+        "rate": "-1.5E+3",
+    }));
+    for rate in ["1e3", ".5", "5.", "+inf", "-Infinity", "NaN", "nan"] {
+        check_matches_schema::<Test>(&json!({ "id": 1, "balance": 1, "rate": rate }));
+    }
+    let schema = serde_json::to_value(schemars::schema_for!(Test)).unwrap();
+    let keyword = |pointer: &str| schema.pointer(pointer).cloned();
+    assert_eq!(
+        Some(json!("integer")),
+        keyword("/properties/id/oneOf/0/type")
+    );
+    assert_eq!(
+        Some(json!("^\\+?[0-9]+$")),
+        keyword("/properties/id/oneOf/1/pattern")
+    );
+    assert_eq!(
+        Some(json!("^[+-]?[0-9]+$")),
+        keyword("/properties/balance/oneOf/1/pattern")
+    );
+    assert_eq!(
+        Some(json!("number")),
+        keyword("/properties/rate/oneOf/0/type")
+    );
+}
+
+#[test]
+fn test_redacted() {
+    #[serde_as]
+    #[derive(Serialize, JsonSchema)]
+    struct Test {
+        #[serde_as(as = "Redacted")]
+        password: String,
+    }
+
+    check_matches_schema::<Test>(&json!({ "password": "hunter2" }));
+    let schema = serde_json::to_value(schemars::schema_for!(Test)).unwrap();
+    assert_eq!(
+        Some(&json!(true)),
+        schema.pointer("/properties/password/writeOnly")
+    );
+}
+
+#[test]
+fn test_validate() {
+    #[serde_as]
+    #[derive(Serialize, JsonSchema)]
+    struct Test {
+        #[serde_as(as = "Bounded<1, 65535>")]
+        port: u32,
+        #[serde_as(as = "Bounded<0, { i128::MAX }>")]
+        offset: i64,
+        #[serde_as(as = "Bounded<1, 65535, DisplayFromStr>")]
+        port_string: u32,
+        #[serde_as(as = "Length<3, 16>")]
+        name: String,
+        #[serde_as(as = "Option<Length<0, 4>>")]
+        tags: Option<Vec<String>>,
+        #[serde_as(as = "NonEmpty")]
+        labels: BTreeMap<String, String>,
+    }
+
+    check_matches_schema::<Test>(&json!({
+        "port": 8080,
+        "offset": 0,
+        "port_string": "8080",
+        "name": "alice",
+        "tags": ["a", "b"],
+        "labels": { "a": "b" },
+    }));
+    let schema = serde_json::to_value(schemars::schema_for!(Test)).unwrap();
+    let keyword = |pointer: &str| schema.pointer(pointer).cloned();
+    assert_eq!(Some(json!(1.0)), keyword("/properties/port/minimum"));
+    assert_eq!(Some(json!(65535.0)), keyword("/properties/port/maximum"));
+    assert_eq!(Some(json!(0.0)), keyword("/properties/offset/minimum"));
+    assert_eq!(None, keyword("/properties/offset/maximum"));
+    // Bounds only apply to numbers, not to strings
+    assert_eq!(None, keyword("/properties/port_string/minimum"));
+    assert_eq!(None, keyword("/properties/port_string/maximum"));
+    assert_eq!(Some(json!(3)), keyword("/properties/name/minLength"));
+    assert_eq!(Some(json!(16)), keyword("/properties/name/maxLength"));
+    assert_eq!(None, keyword("/properties/tags/minItems"));
+    assert_eq!(Some(json!(4)), keyword("/properties/tags/maxItems"));
+    assert_eq!(Some(json!(1)), keyword("/properties/labels/minProperties"));
+    assert_eq!(None, keyword("/properties/labels/maxProperties"));
+}
+
 #[test]
 fn test_set_last_value_wins_with_duplicates() {
     #[serde_as]
@@ -1053,6 +1303,21 @@ mod key_value_map {
 
         check_valid_json_schema(&value);
     }
+
+    #[test]
+    fn test_schema_id() {
+        struct Id;
+        impl formats::KeyField for Id {
+            const NAME: &'static str = "id";
+        }
+
+        let inner = <Schema<String, Same> as JsonSchema>::schema_id();
+        let default = <Schema<Vec<String>, KeyValueMap<Same>> as JsonSchema>::schema_id();
+        let custom = <Schema<Vec<String>, KeyValueMap<Same, Id>> as JsonSchema>::schema_id();
+        // The default key keeps the id from before the key was configurable
+        assert_eq!(format!("serde_with::KeyValueMap({inner})"), default);
+        assert_eq!(format!("serde_with::KeyValueMap({inner},id)"), custom);
+    }
 }
 
 mod one_or_many {
@@ -1164,6 +1429,16 @@ fn test_map_prevent_duplicates() {
     check_valid_json_schema(&S(BTreeMap::from_iter([(1, 10), (2, 20), (3, 30)])));
 }
 
+#[test]
+fn test_map_collect_errors() {
+    #[serde_as]
+    #[derive(JsonSchema, Serialize)]
+    #[serde(transparent)]
+    struct S(#[serde_as(as = "MapCollectErrors<DisplayFromStr, _>")] BTreeMap<u32, u32>);
+
+    check_valid_json_schema(&S(BTreeMap::from_iter([(1, 10), (2, 20), (3, 30)])));
+}
+
 #[test]
 fn test_set_last_value_wins() {
     #[serde_as]
@@ -0,0 +1,142 @@
+use super::*;
+use serde_with::{formats::AnyCaseConvention, CaseInsensitive};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Status {
+    Active,
+    #[serde(alias = "Disabled")]
+    Inactive,
+    Pending(u32),
+    Moved {
+        new_id: u32,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Account {
+    account_id: u32,
+    status: Status,
+}
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct S(#[serde_as(as = "CaseInsensitive")] Status);
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SVec(#[serde_as(as = "Vec<CaseInsensitive>")] Vec<Status>);
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SAccount(#[serde_as(as = "CaseInsensitive<_, AnyCaseConvention>")] Account);
+
+#[test]
+fn test_enum_variants() {
+    // Serialization is unchanged
+    is_equal(S(Status::Active), expect![[r#""Active""#]]);
+    is_equal(
+        S(Status::Moved { new_id: 1 }),
+        expect![[r#"
+            {
+              "Moved": {
+                "new_id": 1
+              }
+            }"#]],
+    );
+
+    check_deserialization(
+        SVec(vec![
+            Status::Active,
+            Status::Active,
+            Status::Active,
+            Status::Inactive,
+        ]),
+        r#"["ACTIVE", "active", "Active", "inACTIVE"]"#,
+    );
+    check_deserialization(S(Status::Pending(2)), r#"{"PENDING": 2}"#);
+    // Fields of struct variants are matched too
+    check_deserialization(
+        S(Status::Moved { new_id: 1 }),
+        r#"{"moved": {"NEW_ID": 1}}"#,
+    );
+    // Aliases are matched
+    check_deserialization(S(Status::Inactive), r#""DISABLED""#);
+
+    check_error_deserialization::<S>(
+        r#""Deleted""#,
+        expect!["unknown variant `Deleted`, expected one of `Active`, `Disabled`, `Inactive`, `Pending`, `Moved` at line 1 column 9"],
+    );
+    // Other spellings do not match with the default
+    check_error_deserialization::<S>(
+        r#"{"Moved": {"newId": 1}}"#,
+        expect!["missing field `new_id` at line 1 column 22"],
+    );
+}
+
+#[test]
+fn test_struct_fields() {
+    is_equal(
+        SAccount(Account {
+            account_id: 1,
+            status: Status::Active,
+        }),
+        expect![[r#"
+            {
+              "account_id": 1,
+              "status": "Active"
+            }"#]],
+    );
+
+    check_deserialization(
+        SAccount(Account {
+            account_id: 1,
+            status: Status::Active,
+        }),
+        r#"{"accountId": 1, "status": "Active"}"#,
+    );
+    check_deserialization(
+        SAccount(Account {
+            account_id: 1,
+            status: Status::Active,
+        }),
+        r#"{"ACCOUNT_ID": 1, "Status": "Active"}"#,
+    );
+    check_deserialization(
+        SAccount(Account {
+            account_id: 1,
+            status: Status::Inactive,
+        }),
+        r#"{"account-id": 1, "status": "Inactive"}"#,
+    );
+
+    // Only the outermost names are matched
+    check_error_deserialization::<SAccount>(
+        r#"{"accountId": 1, "status": "ACTIVE"}"#,
+        expect!["unknown variant `ACTIVE`, expected one of `Active`, `Disabled`, `Inactive`, `Pending`, `Moved` at line 1 column 35"],
+    );
+    // Unknown fields keep their name
+    check_error_deserialization::<SAccount>(
+        r#"{"accountId": 1, "owner": 2}"#,
+        expect![[
+            r#"unknown field `owner`, expected `account_id` or `status` at line 1 column 24"#
+        ]],
+    );
+}
+
+#[test]
+fn test_exact_match_precedence() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Ambiguous {
+        value: u32,
+        #[serde(rename = "VALUE")]
+        upper: u32,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct S(#[serde_as(as = "CaseInsensitive")] Ambiguous);
+
+    let s: S = serde_json::from_str(r#"{"VALUE": 1, "Value": 2}"#).unwrap();
+    assert_eq!(S(Ambiguous { value: 2, upper: 1 }), s);
+}
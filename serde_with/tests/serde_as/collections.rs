@@ -42,3 +42,116 @@ fn test_fnv_hashmap() {
     );
     is_equal(S(FnvHashMap::default()), expect![[r#"{}"#]]);
 }
+
+#[test]
+fn test_sorted_set() {
+    use serde_with::{formats::Descending, SortedSet};
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S {
+        #[serde_as(as = "SortedSet<DisplayFromStr>")]
+        ascending: FnvHashSet<u32>,
+        #[serde_as(as = "SortedSet<_, Descending>")]
+        descending: HashSet<u32>,
+        #[serde_as(as = "SortedSet<_, Descending>")]
+        btree: BTreeSet<u32>,
+    }
+
+    is_equal(
+        S {
+            ascending: [5, 100, 1, 20].into_iter().collect(),
+            descending: [5, 100, 1, 20].into_iter().collect(),
+            btree: [1, 2, 3].into_iter().collect(),
+        },
+        expect![[r#"
+            {
+              "ascending": [
+                "1",
+                "5",
+                "20",
+                "100"
+              ],
+              "descending": [
+                100,
+                20,
+                5,
+                1
+              ],
+              "btree": [
+                3,
+                2,
+                1
+              ]
+            }"#]],
+    );
+    is_equal(
+        S {
+            ascending: FnvHashSet::default(),
+            descending: HashSet::new(),
+            btree: BTreeSet::new(),
+        },
+        expect![[r#"
+            {
+              "ascending": [],
+              "descending": [],
+              "btree": []
+            }"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"ascending": [1], "descending": ["1"], "btree": []}"#,
+        expect!["invalid type: integer `1`, expected a string at line 1 column 16"],
+    );
+}
+
+#[test]
+fn test_sorted_map() {
+    use serde_with::{formats::SortOrder, SortedMap};
+
+    /// Sort by the absolute value
+    struct Abs;
+
+    impl SortOrder<i32> for Abs {
+        fn cmp(a: &i32, b: &i32) -> core::cmp::Ordering {
+            a.unsigned_abs().cmp(&b.unsigned_abs())
+        }
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S {
+        // Sorted by the numerical value, not the string
+        #[serde_as(as = "SortedMap<DisplayFromStr, _>")]
+        numbers: FnvHashMap<u32, bool>,
+        #[serde_as(as = "SortedMap<_, _, Abs>")]
+        custom: HashMap<i32, ()>,
+    }
+
+    is_equal(
+        S {
+            numbers: [(10, true), (2, false), (1, true), (100, false)]
+                .into_iter()
+                .collect(),
+            custom: [(-3, ()), (2, ()), (-1, ()), (4, ())].into_iter().collect(),
+        },
+        expect![[r#"
+            {
+              "numbers": {
+                "1": true,
+                "2": false,
+                "10": true,
+                "100": false
+              },
+              "custom": {
+                "-1": null,
+                "2": null,
+                "-3": null,
+                "4": null
+              }
+            }"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"numbers": {"x": true}, "custom": {}}"#,
+        expect!["invalid digit found in string at line 1 column 16"],
+    );
+}
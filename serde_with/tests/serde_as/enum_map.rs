@@ -1,7 +1,10 @@
 use super::*;
 use core::{fmt::Write as _, str::FromStr};
 use serde_test::Configure;
-use serde_with::EnumMap;
+use serde_with::{
+    formats::{AdjacentlyTagged, InternallyTagged, KeyField},
+    EnumMap,
+};
 use std::net::IpAddr;
 
 fn bytes_debug_readable(bytes: &[u8]) -> String {
@@ -456,3 +459,133 @@ fn yaml_round_trip() {
     let deser_values: VecEnumValues = yaml_serde::from_str(&yaml).unwrap();
     assert_eq!(values, deser_values);
 }
+
+struct Type;
+impl KeyField for Type {
+    const NAME: &'static str = "type";
+}
+
+struct Data;
+impl KeyField for Data {
+    const NAME: &'static str = "data";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Payload {
+    id: u32,
+}
+
+#[test]
+fn internally_tagged() {
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    enum Event {
+        Unit,
+        Newtype(Payload),
+        Struct { a: i32, b: String },
+    }
+
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "EnumMap<InternallyTagged<Type>>")] Vec<Event>);
+
+    is_equal(
+        S(vec![
+            Event::Struct {
+                a: 1,
+                b: "x".to_string(),
+            },
+            Event::Unit,
+            Event::Newtype(Payload { id: 7 }),
+            Event::Struct {
+                a: 2,
+                b: "y".to_string(),
+            },
+        ]),
+        expect![[r#"
+            {
+              "Struct": {
+                "a": 1,
+                "b": "x"
+              },
+              "Unit": {},
+              "Newtype": {
+                "id": 7
+              },
+              "Struct": {
+                "a": 2,
+                "b": "y"
+              }
+            }"#]],
+    );
+    // Unit variants can also be written as `null`
+    check_deserialization(S(vec![Event::Unit]), r#"{"Unit": null}"#);
+    check_error_deserialization::<S>(
+        r#"{"Struct": 123}"#,
+        expect!["invalid type: integer `123`, expected a map at line 1 column 15"],
+    );
+    check_error_deserialization::<S>(
+        r#"{"Other": {}}"#,
+        expect!["unknown variant `Other`, expected one of `Unit`, `Newtype`, `Struct` at line 1 column 13"],
+    );
+
+    // The enum is not internally tagged with the `type` field
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct Wrong(#[serde_as(as = "EnumMap<InternallyTagged<Type>>")] Vec<EnumValue>);
+    check_error_serialization(
+        Wrong(vec![EnumValue::Int(1)]),
+        expect!["wrong type for EnumMap"],
+    );
+}
+
+#[test]
+fn adjacently_tagged() {
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(tag = "type", content = "data")]
+    enum Event {
+        Unit,
+        Newtype(Payload),
+        Tuple(i32, String),
+        Struct { a: i32, b: String },
+    }
+
+    #[serde_as]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "EnumMap<AdjacentlyTagged<Type, Data>>")] Vec<Event>);
+
+    is_equal(
+        S(vec![
+            Event::Unit,
+            Event::Newtype(Payload { id: 7 }),
+            Event::Tuple(1, "x".to_string()),
+            Event::Struct {
+                a: 2,
+                b: "y".to_string(),
+            },
+            Event::Unit,
+        ]),
+        expect![[r#"
+            {
+              "Unit": null,
+              "Newtype": {
+                "id": 7
+              },
+              "Tuple": [
+                1,
+                "x"
+              ],
+              "Struct": {
+                "a": 2,
+                "b": "y"
+              },
+              "Unit": null
+            }"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"Tuple": 123}"#,
+        expect![
+            "invalid type: integer `123`, expected tuple variant Event::Tuple at line 1 column 14"
+        ],
+    );
+}
@@ -0,0 +1,188 @@
+use super::*;
+use serde_with::{
+    formats::{Ceil, Floor, Nearest, Saturate, Truncate},
+    FloatFromInt, IntFromFloat,
+};
+
+#[test]
+fn test_int_from_float_strict() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "IntFromFloat")] u8);
+
+    is_equal(S(3), expect!["3.0"]);
+    check_deserialization(S(3), "3");
+    check_deserialization(S(200), "2e2");
+    check_error_deserialization::<S>(
+        "3.5",
+        expect![
+            "invalid value: floating point `3.5`, expected an integral value in the range of u8"
+        ],
+    );
+    check_error_deserialization::<S>(
+        "256.0",
+        expect![
+            "invalid value: floating point `256.0`, expected an integral value in the range of u8"
+        ],
+    );
+    check_error_deserialization::<S>(
+        "-1",
+        expect!["invalid value: integer `-1`, expected an integral value in the range of u8"],
+    );
+    check_error_deserialization::<S>(
+        "\"3\"",
+        expect![[r#"invalid type: string "3", expected a number at line 1 column 3"#]],
+    );
+}
+
+#[test]
+fn test_int_from_float_strict_precision() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "IntFromFloat<Strict>")] i64);
+
+    is_equal(S(-9_007_199_254_740_992), expect!["-9007199254740992.0"]);
+    // f64 cannot represent the value exactly
+    check_error_serialization(S(9_007_199_254_740_993), expect!["invalid value: integer `9007199254740993`, expected an integer, which is exactly representable as f64"]);
+    check_error_serialization(S(i64::MAX), expect!["invalid value: integer `9223372036854775807`, expected an integer, which is exactly representable as f64"]);
+    is_equal(S(i64::MIN), expect!["-9.223372036854776e18"]);
+}
+
+#[test]
+fn test_int_from_float_rounding() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "IntFromFloat<Flexible>")] i32,
+        #[serde_as(as = "IntFromFloat<Flexible, Truncate>")] i32,
+        #[serde_as(as = "IntFromFloat<Flexible, Nearest>")] i32,
+        #[serde_as(as = "IntFromFloat<Flexible, Floor>")] i32,
+        #[serde_as(as = "IntFromFloat<Flexible, Ceil>")] i32,
+    );
+
+    check_deserialization(S(2, 2, 3, 2, 3), "[2.5, 2.5, 2.5, 2.5, 2.5]");
+    check_deserialization(S(-2, -2, -3, -3, -2), "[-2.5, -2.5, -2.5, -2.5, -2.5]");
+    check_deserialization(S(0, 0, 0, -1, 0), "[-0.2, -0.2, -0.2, -0.2, -0.2]");
+    check_deserialization(S(7, 7, 7, 7, 7), "[7, 7, 7, 7, 7]");
+    check_deserialization(
+        S(1 << 30, 1 << 30, 1 << 30, 1 << 30, 1 << 30),
+        "[1073741824.0, 1073741824.0, 1073741824.0, 1073741824.0, 1073741824.0]",
+    );
+}
+
+#[test]
+fn test_int_from_float_overflow() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "IntFromFloat<Flexible, Nearest>")] i8,
+        #[serde_as(as = "IntFromFloat<Flexible, Nearest, Saturate>")] i8,
+    );
+
+    check_deserialization(S(127, 127), "[127.4, 127.4]");
+    check_deserialization(S(-128, -128), "[-128, -128]");
+    check_error_deserialization::<S>("[127.5, 0]", expect!["invalid value: floating point `127.5`, expected a value in the range of i8 at line 1 column 8"]);
+    check_error_deserialization::<S>(
+        "[128, 0]",
+        expect![
+            "invalid value: integer `128`, expected a value in the range of i8 at line 1 column 6"
+        ],
+    );
+    check_deserialization(S(0, 127), "[0, 1e300]");
+    check_deserialization(S(0, -128), "[0, -300]");
+    check_deserialization(S(0, 127), "[0, 18446744073709551615]");
+}
+
+#[test]
+fn test_int_from_float_non_finite() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "IntFromFloat<Flexible, Truncate, Saturate>")] u16);
+
+    // NaN is rejected even when saturating, while infinity saturates
+    let bytes = rmp_serde::to_vec(&f64::NAN).unwrap();
+    assert!(rmp_serde::from_slice::<S>(&bytes).is_err());
+    let bytes = rmp_serde::to_vec(&f64::INFINITY).unwrap();
+    assert_eq!(S(u16::MAX), rmp_serde::from_slice(&bytes).unwrap());
+    let bytes = rmp_serde::to_vec(&f64::NEG_INFINITY).unwrap();
+    assert_eq!(S(0), rmp_serde::from_slice(&bytes).unwrap());
+}
+
+#[test]
+fn test_float_from_int_strict() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "FloatFromInt")] f64);
+
+    is_equal(S(3.0), expect!["3"]);
+    is_equal(S(-1e15), expect!["-1000000000000000"]);
+    check_error_serialization(
+        S(3.5),
+        expect![
+            "invalid value: floating point `3.5`, expected an integral value in the range of i64"
+        ],
+    );
+    check_error_serialization(S(1e19), expect!["invalid value: floating point `10000000000000000000.0`, expected an integral value in the range of i64"]);
+    check_error_serialization(
+        S(f64::NAN),
+        expect![
+            "invalid value: floating point `NaN`, expected an integral value in the range of i64"
+        ],
+    );
+    check_deserialization(S(9_007_199_254_740_992.0), "9007199254740992");
+    check_error_deserialization::<S>("9007199254740993", expect!["invalid value: integer `9007199254740993`, expected an integer, which is exactly representable as f64"]);
+    check_error_deserialization::<S>(
+        "3.0",
+        expect!["invalid type: floating point `3.0`, expected an integer"],
+    );
+}
+
+#[test]
+fn test_float_from_int_flexible() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "FloatFromInt<Flexible>")] f32,
+        #[serde_as(as = "FloatFromInt<Flexible, Nearest>")] f64,
+        #[serde_as(as = "FloatFromInt<Flexible, Nearest, Saturate>")] f64,
+    );
+
+    check_serialization(
+        S(2.7, 2.7, 2.7),
+        expect![[r#"
+        [
+          2,
+          3,
+          3
+        ]"#]],
+    );
+    check_serialization(
+        S(-2.7, -2.5, 1e300),
+        expect![[r#"
+        [
+          -2,
+          -3,
+          9223372036854775807
+        ]"#]],
+    );
+    check_error_serialization(S(0.0, 1e20, 0.0), expect!["invalid value: floating point `100000000000000000000.0`, expected a value in the range of i64"]);
+    // Integers are converted to the nearest float
+    check_deserialization(
+        S(16_777_216.0, 9_007_199_254_740_992.0, 1.0),
+        "[16777217, 9007199254740993, 1]",
+    );
+}
+
+#[test]
+fn test_binary_format() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "IntFromFloat")] u32,
+        #[serde_as(as = "FloatFromInt")] f32,
+    );
+
+    let value = S(42, -7.0);
+    let bytes = rmp_serde::to_vec(&value).unwrap();
+    assert_eq!(value, rmp_serde::from_slice(&bytes).unwrap());
+}
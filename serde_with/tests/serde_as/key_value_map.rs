@@ -348,3 +348,193 @@ fn test_kvmap_complex_key_yaml() {
     let deser_values = yaml_serde::from_str(&yaml).unwrap();
     assert_eq!(kvmap, deser_values);
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Server {
+    #[serde(rename = "$key$")]
+    name: String,
+    port: u16,
+}
+
+fn server(name: &str, port: u16) -> Server {
+    Server {
+        name: name.to_string(),
+        port,
+    }
+}
+
+#[test]
+fn test_kvmap_btreemap() {
+    use serde_with::{KeyValueMapFirstKeyWins, KeyValueMapPreventDuplicates};
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "KeyValueMap<_>")] BTreeMap<String, Server>);
+
+    is_equal(
+        S(BTreeMap::from([
+            ("db".to_string(), server("db", 5432)),
+            ("web".to_string(), server("web", 80)),
+        ])),
+        expect![[r#"
+            {
+              "db": {
+                "port": 5432
+              },
+              "web": {
+                "port": 80
+              }
+            }"#]],
+    );
+    // The last entry wins
+    check_deserialization(
+        S(BTreeMap::from([("db".to_string(), server("db", 2))])),
+        r#"{"db": {"port": 1}, "db": {"port": 2}}"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct FirstWins(#[serde_as(as = "KeyValueMapFirstKeyWins<_>")] BTreeMap<String, Server>);
+
+    check_deserialization(
+        FirstWins(BTreeMap::from([("db".to_string(), server("db", 1))])),
+        r#"{"db": {"port": 1}, "db": {"port": 2}}"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Prevent(#[serde_as(as = "KeyValueMapPreventDuplicates<_>")] HashMap<String, Server>);
+
+    check_deserialization(
+        Prevent(HashMap::from([("db".to_string(), server("db", 1))])),
+        r#"{"db": {"port": 1}}"#,
+    );
+    check_error_deserialization::<Prevent>(
+        r#"{"db": {"port": 1}, "db": {"port": 2}}"#,
+        expect!["invalid entry: found duplicate key"],
+    );
+}
+
+#[test]
+fn test_kvmap_btreeset() {
+    use serde_with::KeyValueMapPreventDuplicates;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "KeyValueMap<_>")] BTreeSet<Server>);
+
+    is_equal(
+        S(BTreeSet::from([server("db", 5432), server("web", 80)])),
+        expect![[r#"
+            {
+              "db": {
+                "port": 5432
+              },
+              "web": {
+                "port": 80
+              }
+            }"#]],
+    );
+    // Equal values are only stored once
+    check_deserialization(
+        S(BTreeSet::from([server("db", 1)])),
+        r#"{"db": {"port": 1}, "db": {"port": 1}}"#,
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Prevent(#[serde_as(as = "KeyValueMapPreventDuplicates<_>")] BTreeSet<Server>);
+
+    check_error_deserialization::<Prevent>(
+        r#"{"db": {"port": 1}, "db": {"port": 1}}"#,
+        expect!["invalid entry: found duplicate value"],
+    );
+}
+
+#[test]
+fn test_kvmap_key_field() {
+    use serde_with::{formats::KeyField, KeyValueMapPreventDuplicates};
+
+    struct Id;
+    impl KeyField for Id {
+        const NAME: &'static str = "id";
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    struct User {
+        id: String,
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Pair(String, u32);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "KeyValueMap<_, Id>")]
+        users: Vec<User>,
+        #[serde_as(as = "KeyValueMapPreventDuplicates<_, Id>")]
+        by_id: BTreeMap<String, User>,
+        // Tuples use the first element as the key, independent of the key field
+        #[serde_as(as = "KeyValueMap<_, Id>")]
+        pairs: Vec<Pair>,
+    }
+
+    let user = User {
+        id: "1".to_string(),
+        name: "alice".to_string(),
+    };
+    // The struct itself keeps its field name
+    is_equal(
+        user.clone(),
+        expect![[r#"
+            {
+              "id": "1",
+              "name": "alice"
+            }"#]],
+    );
+    is_equal(
+        S {
+            users: vec![user.clone()],
+            by_id: BTreeMap::from([("1".to_string(), user.clone())]),
+            pairs: vec![Pair("a".to_string(), 1)],
+        },
+        expect![[r#"
+            {
+              "users": {
+                "1": {
+                  "name": "alice"
+                }
+              },
+              "by_id": {
+                "1": {
+                  "name": "alice"
+                }
+              },
+              "pairs": {
+                "a": [
+                  1
+                ]
+              }
+            }"#]],
+    );
+
+    // The `$key$` name has no special meaning anymore
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Dollar(#[serde_as(as = "KeyValueMap<_, Id>")] Vec<Struct>);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Struct {
+        #[serde(rename = "$key$")]
+        key: String,
+    }
+
+    check_error_serialization(
+        Dollar(vec![Struct {
+            key: "a".to_string(),
+        }]),
+        expect!["missing value for `id` field"],
+    );
+}
@@ -2,19 +2,26 @@
 
 extern crate alloc;
 
+mod case_insensitive;
 mod collections;
 mod default_on;
 mod enum_map;
 mod frominto;
 mod fromintoref;
+mod int_from_float;
 mod key_value_map;
 mod map_tuple_list;
+mod normalize;
+mod number_as_string;
 mod pickfirst;
+mod redacted;
 mod serde_as_macro;
 mod serde_conv;
 mod time;
+mod unknown_variant;
 #[path = "../utils.rs"]
 mod utils;
+mod validate;
 
 use crate::utils::*;
 use alloc::{
@@ -679,6 +686,169 @@ fn test_vec_skip_error() {
     );
 }
 
+#[test]
+fn test_seq_skip_error() {
+    use serde_with::SeqSkipError;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "SeqSkipError<_>")]
+        vec_deque: VecDeque<u8>,
+        #[serde_as(as = "SeqSkipError<_>")]
+        linked_list: LinkedList<u8>,
+        #[serde_as(as = "SeqSkipError<_>")]
+        boxed_slice: Box<[u8]>,
+        #[serde_as(as = "SeqSkipError<_>")]
+        btree_set: BTreeSet<u8>,
+        #[serde_as(as = "SeqSkipError<DisplayFromStr>")]
+        hash_set: HashSet<u8>,
+    }
+
+    check_deserialization(
+        S {
+            vec_deque: [0, 1].into_iter().collect(),
+            linked_list: [2].into_iter().collect(),
+            boxed_slice: Box::new([3, 4]),
+            btree_set: [5, 6].into_iter().collect(),
+            hash_set: [7].into_iter().collect(),
+        },
+        r#"{
+          "vec_deque": [0, "str", 1, -2],
+          "linked_list": [{}, 2, 300],
+          "boxed_slice": [null, 3, 4],
+          "btree_set": [6, [], 5, 6],
+          "hash_set": ["7", "x", 7]
+        }"#,
+    );
+    is_equal(
+        S {
+            vec_deque: [0, 1].into_iter().collect(),
+            linked_list: LinkedList::new(),
+            boxed_slice: Box::new([3]),
+            btree_set: [6, 5].into_iter().collect(),
+            hash_set: [7].into_iter().collect(),
+        },
+        expect![[r#"
+            {
+              "vec_deque": [
+                0,
+                1
+              ],
+              "linked_list": [],
+              "boxed_slice": [
+                3
+              ],
+              "btree_set": [
+                5,
+                6
+              ],
+              "hash_set": [
+                "7"
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_seq_skip_error_array() {
+    use serde_with::SeqSkipError;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "SeqSkipError<_>")] [u8; 3]);
+
+    check_deserialization(S([1, 2, 3]), r#"[1, "str", 2, -1, 3]"#);
+    check_deserialization(S([1, 2, 3]), r#"[1, 2, 3]"#);
+    check_error_deserialization::<S>(r#"[1, "str", 2, -1]"#, expect!["expected 3 valid elements, but only 2 of 4 elements could be deserialized at line 1 column 17"]);
+    check_error_deserialization::<S>(
+        r#"[1, 2, 3, 4]"#,
+        expect!["invalid length 4, expected a sequence with 3 valid elements at line 1 column 12"],
+    );
+    check_error_deserialization::<S>(
+        r#"{}"#,
+        expect!["invalid type: map, expected a sequence with 3 valid elements at line 1 column 0"],
+    );
+    is_equal(
+        S([1, 2, 3]),
+        expect![[r#"
+        [
+          1,
+          2,
+          3
+        ]"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Empty(#[serde_as(as = "SeqSkipError<_>")] [u8; 0]);
+
+    check_deserialization(Empty([]), r#"["str", null]"#);
+}
+
+#[test]
+fn test_bounded_skip_error() {
+    use serde_with::{
+        formats::{MaxSkipped, MaxSkippedPercent},
+        BoundedSkipError,
+    };
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Count(#[serde_as(as = "BoundedSkipError<_, MaxSkipped<2>>")] Vec<u8>);
+
+    check_deserialization(Count(vec![]), r#"[]"#);
+    check_deserialization(Count(vec![1, 2]), r#"[1, "str", 2, -1]"#);
+    check_error_deserialization::<Count>(
+        r#"[1, "str", 2, -1, null]"#,
+        expect![[r#"
+            skipped 3 of 5 elements, but at most 2 may be skipped:
+            [1]: invalid type: string "str", expected u8
+            [3]: invalid value: integer `-1`, expected u8
+            [4]: invalid type: null, expected u8 at line 1 column 23"#]],
+    );
+    is_equal(
+        Count(vec![1, 2]),
+        expect![[r#"
+            [
+              1,
+              2
+            ]"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Ratio(#[serde_as(as = "BoundedSkipError<_, MaxSkippedPercent<25>>")] BTreeSet<u8>);
+
+    check_deserialization(Ratio(BTreeSet::new()), r#"[]"#);
+    check_deserialization(Ratio([1, 2, 3].into()), r#"[3, 1, "str", 2]"#);
+    check_error_deserialization::<Ratio>(
+        r#"[1, "str", 2, -1]"#,
+        expect![[r#"
+            skipped 2 of 4 elements, but at most 25% may be skipped:
+            [1]: invalid type: string "str", expected u8
+            [3]: invalid value: integer `-1`, expected u8 at line 1 column 17"#]],
+    );
+
+    // Only the first errors are listed
+    check_error_deserialization::<Ratio>(
+        r#"[null, null, null, null, null, null, null, null, null, null, null, null]"#,
+        expect![[r#"
+            skipped 12 of 12 elements, but at most 25% may be skipped:
+            [0]: invalid type: null, expected u8
+            [1]: invalid type: null, expected u8
+            [2]: invalid type: null, expected u8
+            [3]: invalid type: null, expected u8
+            [4]: invalid type: null, expected u8
+            [5]: invalid type: null, expected u8
+            [6]: invalid type: null, expected u8
+            [7]: invalid type: null, expected u8
+            [8]: invalid type: null, expected u8
+            [9]: invalid type: null, expected u8
+            ... and 2 more at line 1 column 72"#]],
+    );
+}
+
 #[test]
 fn test_map_skip_error_btreemap() {
     use serde_with::MapSkipError;
@@ -861,6 +1031,394 @@ fn test_map_skip_error_hashmap_flatten() {
     );
 }
 
+#[test]
+fn test_skip_error_inspect_context() {
+    use serde_with::{InspectError, MapSkipError, SkipErrorContext, VecSkipError};
+
+    thread_local! {
+        static ERRORS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct Inspector;
+
+    impl InspectError for Inspector {
+        const WANTS_CONTEXT: bool = true;
+
+        fn inspect_error_with_context(
+            error: impl serde::de::Error,
+            context: &SkipErrorContext<'_, '_>,
+        ) {
+            let message = format!(
+                "{} {:?} key_error={} {:?}: {error}",
+                context.index(),
+                context.key(),
+                context.is_key_error(),
+                context.value(),
+            );
+            ERRORS.with(|errors| errors.borrow_mut().push(message));
+        }
+    }
+
+    fn take_errors() -> String {
+        ERRORS.with(|errors| errors.take().join("\n"))
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct S {
+        #[serde_as(as = "VecSkipError<_, Inspector>")]
+        values: Vec<u8>,
+        #[serde_as(as = "MapSkipError<DisplayFromStr, _, Inspector>")]
+        map: BTreeMap<u8, u8>,
+    }
+
+    check_deserialization(
+        S {
+            values: vec![0, 1],
+            map: [(0, 1)].into_iter().collect(),
+        },
+        r#"{"values": [0, "str", 1, [2]], "map": {"0": 1, "str": 2, "3": null, "x": {}}}"#,
+    );
+    expect![[r#"
+        1 None key_error=false Str("str"): invalid type: string "str", expected u8
+        3 None key_error=false Seq([U64(2)]): invalid type: sequence, expected u8
+        1 Some("str") key_error=true U64(2): invalid digit found in string
+        2 Some("3") key_error=false Unit: invalid type: null, expected u8
+        3 Some("x") key_error=true Map([]): invalid digit found in string
+        3 Some("x") key_error=false Map([]): invalid type: map, expected u8"#]]
+    .assert_eq(&take_errors());
+
+    // Inspectors implementing only `inspect_error` keep working
+    struct OnlyError;
+
+    impl InspectError for OnlyError {
+        fn inspect_error(error: impl serde::de::Error) {
+            ERRORS.with(|errors| errors.borrow_mut().push(error.to_string()));
+        }
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct S2(#[serde_as(as = "VecSkipError<_, OnlyError>")] Vec<u8>);
+
+    check_deserialization(S2(vec![1]), r#"[1, -1]"#);
+    expect!["invalid value: integer `-1`, expected u8"].assert_eq(&take_errors());
+}
+
+#[test]
+fn test_collect_errors() {
+    use serde_with::CollectErrors;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        tag: String,
+        #[serde_as(as = "CollectErrors<_>")]
+        values: Vec<u8>,
+    }
+
+    check_deserialization(
+        S {
+            tag: "type".into(),
+            values: vec![0, 1, 255],
+        },
+        r#"{"tag":"type","values":[0, 1, 255]}"#,
+    );
+    check_error_deserialization::<S>(
+        r#"{"tag":"type","values":[0, "str", 1, [10, 11], -2, {}, 300]}"#,
+        expect![[r#"
+            [1]: invalid type: string "str", expected u8
+            [3]: invalid type: sequence, expected u8
+            [4]: invalid value: integer `-2`, expected u8
+            [5]: invalid type: map, expected u8
+            [6]: invalid value: integer `300`, expected u8 at line 1 column 59"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"tag":"type", "values":[0, "str", 1, , 300]}"#,
+        expect!["expected value at line 1 column 39"],
+    );
+    is_equal(
+        S {
+            tag: "round-trip".into(),
+            values: vec![0, 255],
+        },
+        expect![[r#"
+        {
+          "tag": "round-trip",
+          "values": [
+            0,
+            255
+          ]
+        }"#]],
+    );
+}
+
+#[test]
+fn test_map_collect_errors() {
+    use serde_with::{CollectErrors, MapCollectErrors};
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S {
+        #[serde_as(as = "MapCollectErrors<DisplayFromStr, _>")]
+        values: BTreeMap<u8, u8>,
+    }
+
+    check_deserialization(
+        S {
+            values: [(0, 1), (2, 3)].into_iter().collect(),
+        },
+        r#"{"values": {"0": 1, "2": 3}}"#,
+    );
+    check_error_deserialization::<S>(
+        r#"{"values": {"0": 1, "str": 2, "3": "str", "-1": -1, "5": {}}}"#,
+        expect![[r#"
+            ["str"]: invalid digit found in string
+            ["3"]: invalid type: string "str", expected u8
+            ["-1"]: invalid digit found in string
+            ["-1"]: invalid value: integer `-1`, expected u8
+            ["5"]: invalid type: map, expected u8 at line 1 column 60"#]],
+    );
+
+    // Nested adapters flatten the inner errors into the message
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Nested {
+        #[serde_as(as = "MapCollectErrors<_, CollectErrors<MapCollectErrors<_, _>>>")]
+        values: HashMap<String, Vec<BTreeMap<String, bool>>>,
+    }
+
+    check_error_deserialization::<Nested>(
+        r#"{"values": {"a": [{"x": true}, {"y": 1, "z": false}], "b": [{"x": "no"}, null]}}"#,
+        expect![[r#"
+            ["a"]: [1]: ["y"]: invalid type: integer `1`, expected a boolean
+            ["b"]: [0]: ["x"]: invalid type: string "no", expected a boolean
+            [1]: invalid type: null, expected a map at line 1 column 79"#]],
+    );
+    // Keys looking like a path separator are kept as part of the message
+    check_error_deserialization::<Nested>(
+        r#"{"values": {"a": [{"x]: y": 1}]}}"#,
+        expect![[
+            r#"["a"]: [0]: ["x]: y"]: invalid type: integer `1`, expected a boolean at line 1 column 32"#
+        ]],
+    );
+    is_equal(
+        Nested {
+            values: [("a".into(), vec![[("x".into(), true)].into_iter().collect()])]
+                .into_iter()
+                .collect(),
+        },
+        expect![[r#"
+            {
+              "values": {
+                "a": [
+                  {
+                    "x": true
+                  }
+                ]
+              }
+            }"#]],
+    );
+}
+
+#[test]
+fn test_collected_errors() {
+    use serde::de::value::Error;
+    use serde_with::{CollectedError, CollectedErrors};
+
+    let mut errors = CollectedErrors::new();
+    assert!(errors.is_empty());
+    assert_eq!(Some(1), errors.record("[0]", Ok::<_, &str>(1)));
+    assert_eq!(None, errors.record("[1]", Err::<u8, _>("first")));
+    let mut nested = CollectedErrors::new();
+    nested.push("[0]", "second");
+    nested.push("[2][\"x\"]", "third");
+    errors.nest("[\"key\"]", nested);
+    // Messages are never split, even if they look like collected errors
+    errors.push("[3]", "[4]: fourth\n[multi-line]");
+    assert_eq!(4, errors.len());
+
+    let paths: Vec<_> = errors.iter().map(CollectedError::path).collect();
+    assert_eq!(
+        vec!["[1]", "[\"key\"][0]", "[\"key\"][2][\"x\"]", "[3]"],
+        paths
+    );
+    let messages: Vec<_> = errors.iter().map(CollectedError::message).collect();
+    assert_eq!(
+        vec!["first", "second", "third", "[4]: fourth\n[multi-line]"],
+        messages
+    );
+
+    expect![[r#"
+        [1]: first
+        ["key"][0]: second
+        ["key"][2]["x"]: third
+        [3]: [4]: fourth
+        [multi-line]"#]]
+    .assert_eq(
+        &errors
+            .clone()
+            .into_result::<(), Error>(())
+            .unwrap_err()
+            .to_string(),
+    );
+    assert_eq!(Ok(5), CollectedErrors::new().into_result::<_, Error>(5));
+}
+
+#[test]
+fn test_canonical() {
+    use serde_with::Canonical;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    enum Kind {
+        Unit,
+        Struct { z: f32, a: u8 },
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Inner {
+        zeta: f64,
+        alpha: HashMap<i32, Option<HashMap<String, u8>>>,
+        kinds: Vec<Kind>,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "Canonical")] Inner);
+
+    let value = S(Inner {
+        zeta: -0.0,
+        alpha: [
+            (
+                10,
+                Some([("b".into(), 2), ("a".into(), 1)].into_iter().collect()),
+            ),
+            (-5, None),
+            (2, Some(HashMap::new())),
+        ]
+        .into_iter()
+        .collect(),
+        kinds: vec![Kind::Struct { z: -0.0, a: 1 }, Kind::Unit],
+    });
+    check_serialization(
+        value,
+        expect![[r#"
+            {
+              "alpha": {
+                "-5": null,
+                "10": {
+                  "a": 1,
+                  "b": 2
+                },
+                "2": {}
+              },
+              "kinds": [
+                {
+                  "Struct": {
+                    "a": 1,
+                    "z": 0.0
+                  }
+                },
+                "Unit"
+              ],
+              "zeta": 0.0
+            }"#]],
+    );
+    check_deserialization(
+        S(Inner {
+            zeta: 1.5,
+            alpha: HashMap::new(),
+            kinds: vec![Kind::Unit],
+        }),
+        r#"{"zeta": 1.5, "alpha": {}, "kinds": ["Unit"]}"#,
+    );
+
+    // Non-finite floats
+    check_error_serialization(
+        S(Inner {
+            zeta: f64::INFINITY,
+            alpha: HashMap::new(),
+            kinds: vec![],
+        }),
+        expect!["Canonical cannot serialize the non-finite float inf"],
+    );
+    check_error_serialization(
+        S(Inner {
+            zeta: 0.0,
+            alpha: HashMap::new(),
+            kinds: vec![Kind::Struct { z: f32::NAN, a: 0 }],
+        }),
+        expect!["Canonical cannot serialize the non-finite float NaN"],
+    );
+
+    // Duplicate keys
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize)]
+    struct Duplicates(#[serde_as(as = "Canonical<Map<_, _>>")] Vec<(String, u8)>);
+
+    check_serialization(
+        Duplicates(vec![("b".into(), 1), ("a".into(), 2)]),
+        expect![[r#"
+            {
+              "a": 2,
+              "b": 1
+            }"#]],
+    );
+    check_error_serialization(
+        Duplicates(vec![("a".into(), 1), ("a".into(), 2)]),
+        expect!["Canonical cannot serialize a map with duplicate keys"],
+    );
+
+    // JSON keys are strings and sorted by their UTF-16 code units, like in RFC 8785
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize)]
+    struct NumberKeys(#[serde_as(as = "Canonical")] BTreeMap<u32, u8>);
+
+    check_serialization(
+        NumberKeys(BTreeMap::from([(2, 0), (10, 1)])),
+        expect![[r#"
+            {
+              "10": 1,
+              "2": 0
+            }"#]],
+    );
+
+    // U+FF61 sorts before U+1F600 in UTF-8, but after it in UTF-16
+    check_serialization(
+        Duplicates(vec![("\u{1F600}".into(), 1), ("\u{FF61}".into(), 2)]),
+        expect![[r#"
+            {
+              "😀": 1,
+              "｡": 2
+            }"#]],
+    );
+}
+
+#[test]
+fn test_canonical_binary_format() {
+    use serde_with::Canonical;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Inner {
+        zeta: u32,
+        alpha: u32,
+        map: BTreeMap<u8, u8>,
+    }
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct S(#[serde_as(as = "Canonical")] Inner);
+
+    // Compact formats identify the fields by position, so the declared order must be kept
+    let value = S(Inner {
+        zeta: 1,
+        alpha: 2,
+        map: BTreeMap::from([(2, 0), (1, 0)]),
+    });
+    let bytes = rmp_serde::to_vec(&value).unwrap();
+    assert_eq!(value, rmp_serde::from_slice(&bytes).unwrap());
+}
+
 #[test]
 fn test_serialize_reference() {
     #[serde_as]
@@ -1171,6 +1729,118 @@ fn test_bytes() {
     );
 }
 
+#[test]
+fn test_endian_bytes() {
+    use core::num::{NonZeroI32, NonZeroU64};
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+    use serde_with::{BigEndianBytes, LittleEndianBytes};
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Test {
+        #[serde_as(as = "BigEndianBytes")]
+        be_u32: u32,
+        #[serde_as(as = "LittleEndianBytes")]
+        le_u32: u32,
+        #[serde_as(as = "BigEndianBytes")]
+        be_i16: i16,
+        #[serde_as(as = "LittleEndianBytes")]
+        le_u128: u128,
+        #[serde_as(as = "BigEndianBytes")]
+        be_nonzero: NonZeroU64,
+        #[serde_as(as = "LittleEndianBytes")]
+        le_nonzero: NonZeroI32,
+    }
+
+    let test = Test {
+        be_u32: 0x0102_0304,
+        le_u32: 0x0102_0304,
+        be_i16: -2,
+        le_u128: 1,
+        be_nonzero: NonZeroU64::new(0x0102).unwrap(),
+        le_nonzero: NonZeroI32::new(-1).unwrap(),
+    };
+
+    assert_tokens(
+        &test,
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 6,
+            },
+            Token::Str("be_u32"),
+            Token::BorrowedBytes(&[1, 2, 3, 4]),
+            Token::Str("le_u32"),
+            Token::BorrowedBytes(&[4, 3, 2, 1]),
+            Token::Str("be_i16"),
+            Token::BorrowedBytes(&[0xff, 0xfe]),
+            Token::Str("le_u128"),
+            Token::BorrowedBytes(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Token::Str("be_nonzero"),
+            Token::BorrowedBytes(&[0, 0, 0, 0, 0, 0, 1, 2]),
+            Token::Str("le_nonzero"),
+            Token::BorrowedBytes(&[0xff, 0xff, 0xff, 0xff]),
+            Token::StructEnd,
+        ],
+    );
+
+    // Byte sequences are accepted too
+    assert_de_tokens(
+        &Test { le_u128: 0, ..test },
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 6,
+            },
+            Token::Str("be_u32"),
+            Token::Seq { len: Some(4) },
+            Token::U8(1),
+            Token::U8(2),
+            Token::U8(3),
+            Token::U8(4),
+            Token::SeqEnd,
+            Token::Str("le_u32"),
+            Token::Bytes(&[4, 3, 2, 1]),
+            Token::Str("be_i16"),
+            Token::Bytes(&[0xff, 0xfe]),
+            Token::Str("le_u128"),
+            Token::Bytes(&[0; 16]),
+            Token::Str("be_nonzero"),
+            Token::Bytes(&[0, 0, 0, 0, 0, 0, 1, 2]),
+            Token::Str("le_nonzero"),
+            Token::Bytes(&[0xff, 0xff, 0xff, 0xff]),
+            Token::StructEnd,
+        ],
+    );
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct NonZero(#[serde_as(as = "BigEndianBytes")] NonZeroU64);
+
+    is_equal(
+        NonZero(NonZeroU64::new(258).unwrap()),
+        expect![[r#"
+            [
+              0,
+              0,
+              0,
+              0,
+              0,
+              0,
+              1,
+              2
+            ]"#]],
+    );
+    check_error_deserialization::<NonZero>(
+        "[0,0,0,0,0,0,0,0]",
+        expect![[r#"invalid value: integer `0`, expected a non-zero integer"#]],
+    );
+    check_error_deserialization::<NonZero>(
+        "[1,2,3]",
+        expect![[r#"invalid length 3, expected an byte array of size 8 at line 1 column 7"#]],
+    );
+}
+
 #[test]
 fn test_one_or_many_prefer_one() {
     #[serde_as]
@@ -1439,6 +2109,107 @@ fn test_one_or_many_btreeset_prefer_many() {
     check_deserialization(S1(BTreeSet::from([1, 2, 3])), r#"[1, 2, 3]"#);
 }
 
+#[test]
+fn test_one_or_many_prefer_none() {
+    use serde_with::formats::PreferNone;
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S1(#[serde_as(as = "OneOrMany<_, PreferNone>")] Vec<u32>);
+
+    is_equal(S1(vec![]), expect![[r#"null"#]]);
+    is_equal(S1(vec![1]), expect![[r#"1"#]]);
+    is_equal(
+        S1(vec![1, 2]),
+        expect![[r#"
+            [
+              1,
+              2
+            ]"#]],
+    );
+    check_deserialization(S1(vec![]), r#"[]"#);
+    check_deserialization(S1(vec![1]), r#"[1]"#);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S2 {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde_as(as = "OneOrMany<_, PreferNone>")]
+        values: Vec<u32>,
+    }
+
+    is_equal(S2 { values: vec![] }, expect![[r#"{}"#]]);
+    is_equal(
+        S2 { values: vec![1] },
+        expect![[r#"
+            {
+              "values": 1
+            }"#]],
+    );
+    check_deserialization(S2 { values: vec![] }, r#"{"values": null}"#);
+
+    // `null` is a valid element, so it is not turned into an empty list
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S3(#[serde_as(as = "OneOrMany<_>")] Vec<Option<u32>>);
+
+    check_deserialization(S3(vec![None]), r#"null"#);
+    check_deserialization(S3(vec![]), r#"[]"#);
+}
+
+#[test]
+fn test_one_or_many_null() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S1Vec(#[serde_as(as = "OneOrMany<_>")] Vec<u32>);
+
+    check_deserialization(S1Vec(vec![]), r#"null"#);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S2Set(#[serde_as(as = "OneOrMany<_>")] BTreeSet<u32>);
+
+    check_deserialization(S2Set(BTreeSet::new()), r#"null"#);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S3Missing {
+        #[serde(default)]
+        #[serde_as(as = "OneOrMany<_>")]
+        values: Vec<u32>,
+    }
+
+    check_deserialization(S3Missing { values: vec![] }, r#"{}"#);
+}
+
+#[test]
+fn test_one_or_many_btreeset_prevent_duplicates() {
+    use serde_with::formats::{PreferOne, PreventDuplicates};
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S1(#[serde_as(as = "OneOrMany<_>")] BTreeSet<u32>);
+
+    // Duplicates are ignored by default
+    check_deserialization(S1(BTreeSet::from([1, 2])), r#"[1, 2, 1]"#);
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct S2(#[serde_as(as = "OneOrMany<_, PreferOne, PreventDuplicates>")] BTreeSet<u32>);
+
+    is_equal(S2(BTreeSet::from([1])), expect![[r#"1"#]]);
+    check_deserialization(S2(BTreeSet::from([1])), r#"[1]"#);
+    check_deserialization(S2(BTreeSet::from([1, 2])), r#"[2, 1]"#);
+    check_deserialization(S2(BTreeSet::new()), r#"null"#);
+    check_error_deserialization::<S2>(
+        r#"[1, 2, 1]"#,
+        expect![[r#"
+            OneOrMany could not deserialize any variant:
+              One: invalid type: sequence, expected u32
+              Many: invalid entry: found duplicate value"#]],
+    );
+}
+
 /// Test that Cow borrows from the input
 #[test]
 fn test_borrow_cow_str() {
@@ -0,0 +1,131 @@
+use super::*;
+use alloc::borrow::Cow;
+use serde_with::{BorrowCow, Lowercased, NoneAsEmptyString, PickFirst, Trimmed};
+
+#[test]
+fn test_trimmed() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Trimmed")] String);
+
+    // Serialization is unchanged
+    is_equal(S("foo".into()), expect![[r#""foo""#]]);
+    check_serialization(S(" foo ".into()), expect![[r#"" foo ""#]]);
+
+    check_deserialization(S("foo".into()), r#"" foo ""#);
+    check_deserialization(S("foo bar".into()), r#""\t foo bar\n""#);
+    // Byte order marks and Unicode whitespace are removed
+    check_deserialization(S("bar".into()), r#""\ufeffbar""#);
+    check_deserialization(S("bar".into()), r#""\u00a0bar\u3000""#);
+    check_deserialization(S(String::new()), r#""   ""#);
+    check_error_deserialization::<S>(
+        "42",
+        expect!["invalid type: integer `42`, expected a string at line 1 column 2"],
+    );
+}
+
+#[test]
+fn test_trimmed_inner_adapter() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Trimmed<DisplayFromStr>")] u32);
+
+    is_equal(S(42), expect![[r#""42""#]]);
+    check_deserialization(S(42), r#"" 42 ""#);
+    check_error_deserialization::<S>(
+        r#"" 4 2 ""#,
+        expect!["invalid digit found in string at line 1 column 7"],
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SNone(#[serde_as(as = "Trimmed<NoneAsEmptyString>")] Option<String>);
+
+    check_deserialization(SNone(None), r#""""#);
+    check_deserialization(SNone(None), r#""  \t ""#);
+    check_deserialization(SNone(None), "null");
+    check_deserialization(SNone(Some("foo".into())), r#"" foo ""#);
+
+    // Values which are not strings are passed on unchanged
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SPick(#[serde_as(as = "Trimmed<PickFirst<(_, DisplayFromStr)>>")] u32);
+
+    check_deserialization(SPick(42), "42");
+    check_deserialization(SPick(42), r#"" 42""#);
+}
+
+#[test]
+fn test_trimmed_option_and_borrow() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Trimmed")] Option<String>);
+
+    check_deserialization(S(None), "null");
+    check_deserialization(S(Some("foo".into())), r#"" foo ""#);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SVec(#[serde_as(as = "Vec<Option<Trimmed>>")] Vec<Option<String>>);
+
+    check_deserialization(
+        SVec(vec![Some("a".into()), None, Some("b".into())]),
+        r#"[" a", null, "b "]"#,
+    );
+
+    // Trimming keeps borrowing from the input
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SBorrow<'a>(#[serde_as(as = "Trimmed<BorrowCow>")] Cow<'a, str>);
+
+    let s: SBorrow<'_> = serde_json::from_str(r#"" foo ""#).unwrap();
+    assert_eq!("foo", s.0);
+    assert!(matches!(s.0, Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_lowercased() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Lowercased")] String);
+
+    is_equal(S("foo".into()), expect![[r#""foo""#]]);
+    check_serialization(S("FOO".into()), expect![[r#""FOO""#]]);
+
+    check_deserialization(S("foo".into()), r#""FOO""#);
+    check_deserialization(S("straße über".into()), r#""STRAßE ÜBER""#);
+    check_deserialization(S("ὀδυσσεύς".into()), r#""ὈΔΥΣΣΕΎΣ""#);
+
+    // Combined with trimming
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SCombined(#[serde_as(as = "Trimmed<Lowercased<DisplayFromStr>>")] bool);
+
+    check_deserialization(SCombined(true), r#"" TRUE ""#);
+    check_deserialization(SCombined(false), r#""False""#);
+}
+
+#[test]
+fn test_normalize_owned_string() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "Trimmed")] String,
+        #[serde_as(as = "Lowercased")] String,
+    );
+
+    // `serde_json::Value` passes owned strings, which are reused if they are already normalized
+    let trimmed = String::from("foo");
+    let lowercased = String::from("bar");
+    let pointers = (trimmed.as_ptr(), lowercased.as_ptr());
+    let value = serde_json::Value::Array(vec![trimmed.into(), lowercased.into()]);
+    let s: S = serde_json::from_value(value).unwrap();
+    assert_eq!(S("foo".into(), "bar".into()), s);
+    assert_eq!(pointers, (s.0.as_ptr(), s.1.as_ptr()));
+
+    let value = serde_json::json!([" foo ", "BAR"]);
+    assert_eq!(
+        S("foo".into(), "bar".into()),
+        serde_json::from_value(value).unwrap()
+    );
+}
@@ -0,0 +1,157 @@
+use super::*;
+use core::num::NonZeroU64;
+use serde_with::{
+    formats::{AlwaysNumber, AlwaysString, LargeAsString},
+    NumberAsString,
+};
+
+#[test]
+fn test_always_string() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "NumberAsString")] u64,
+        #[serde_as(as = "NumberAsString<AlwaysString>")] i8,
+        #[serde_as(as = "NumberAsString")] f64,
+    );
+
+    is_equal(
+        S(u64::MAX, -5, 1.5),
+        expect![[r#"
+        [
+          "18446744073709551615",
+          "-5",
+          "1.5"
+        ]"#]],
+    );
+    check_deserialization(S(1, 2, 3.0), "[1, 2, 3]");
+    check_deserialization(S(1, 2, 3.0), r#"["1", "2", "3"]"#);
+    check_error_deserialization::<S>(
+        r#"["1", "300", "3"]"#,
+        expect![[
+            r#"invalid value: string "300", expected i8 as a number or a string at line 1 column 11"#
+        ]],
+    );
+    check_error_deserialization::<S>(
+        r#"["1", 300, "3"]"#,
+        expect![
+            "invalid value: integer `300`, expected i8 as a number or a string at line 1 column 9"
+        ],
+    );
+    check_error_deserialization::<S>(
+        r#"["-1", "2", "3"]"#,
+        expect![[
+            r#"invalid value: string "-1", expected u64 as a number or a string at line 1 column 5"#
+        ]],
+    );
+    check_error_deserialization::<S>(r#"[1.5, "2", "3"]"#, expect!["invalid type: floating point `1.5`, expected u64 as a number or a string at line 1 column 4"]);
+    check_error_deserialization::<S>(
+        r#"[true, "2", "3"]"#,
+        expect![
+            "invalid type: boolean `true`, expected u64 as a number or a string at line 1 column 5"
+        ],
+    );
+}
+
+#[test]
+fn test_large_as_string() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Vec<NumberAsString<LargeAsString>>")] Vec<i64>);
+
+    // Only integers outside of -(2^53 - 1)..=2^53 - 1 become strings
+    is_equal(
+        S(vec![
+            0,
+            9_007_199_254_740_991,
+            9_007_199_254_740_992,
+            -9_007_199_254_740_991,
+            -9_007_199_254_740_992,
+        ]),
+        expect![[r#"
+            [
+              0,
+              9007199254740991,
+              "9007199254740992",
+              -9007199254740991,
+              "-9007199254740992"
+            ]"#]],
+    );
+    check_deserialization(S(vec![1, 2]), r#"[1, "2"]"#);
+}
+
+#[test]
+fn test_large_as_string_float() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Vec<NumberAsString<LargeAsString>>")] Vec<f64>);
+
+    // Non-finite floats cannot be represented as JSON numbers
+    check_serialization(
+        S(vec![1e20, f64::NAN, f64::NEG_INFINITY]),
+        expect![[r#"
+            [
+              1e20,
+              "NaN",
+              "-inf"
+            ]"#]],
+    );
+    check_deserialization(S(vec![1e20, f64::INFINITY]), r#"[1e20, "inf"]"#);
+    // Strings use the syntax of `FromStr`
+    check_deserialization(
+        S(vec![1000.0, 5.0, f64::NEG_INFINITY]),
+        r#"["1e3", "+5", "-Infinity"]"#,
+    );
+}
+
+#[test]
+fn test_always_number() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "NumberAsString<AlwaysNumber>")] u128,
+        #[serde_as(as = "NumberAsString<AlwaysNumber>")] f32,
+    );
+
+    is_equal(
+        S(u128::from(u64::MAX), 0.5),
+        expect![[r#"
+            [
+              18446744073709551615,
+              0.5
+            ]"#]],
+    );
+    check_deserialization(S(7, 2.0), r#"["7", 2]"#);
+}
+
+#[test]
+fn test_non_zero() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Option<NumberAsString>")] Option<NonZeroU64>);
+
+    is_equal(S(NonZeroU64::new(9)), expect![[r#""9""#]]);
+    is_equal(S(None), expect!["null"]);
+    check_deserialization(S(NonZeroU64::new(9)), "9");
+    check_error_deserialization::<S>("0", expect!["invalid value: integer `0`, expected NonZeroU64 as a number or a string at line 1 column 1"]);
+    check_error_deserialization::<S>(
+        r#""0""#,
+        expect![[
+            r#"invalid value: string "0", expected NonZeroU64 as a number or a string at line 1 column 3"#
+        ]],
+    );
+}
+
+#[test]
+fn test_binary_format() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "NumberAsString")] u64,
+        #[serde_as(as = "NumberAsString<AlwaysNumber>")] i64,
+    );
+
+    let value = S(u64::MAX, i64::MIN);
+    let bytes = rmp_serde::to_vec(&value).unwrap();
+    assert_eq!(value, rmp_serde::from_slice(&bytes).unwrap());
+}
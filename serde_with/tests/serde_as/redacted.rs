@@ -0,0 +1,128 @@
+use super::*;
+use core::hash::Hasher;
+use serde_with::{
+    formats::{CorrelationHash, LastChars},
+    IfIsHumanReadable, Redacted, Same,
+};
+
+#[test]
+fn test_placeholder() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "Redacted")]
+        password: String,
+        #[serde_as(as = "Redacted")]
+        pin: u32,
+        #[serde_as(as = "Option<Redacted>")]
+        token: Option<String>,
+        #[serde_as(as = "Option<Redacted>")]
+        missing: Option<String>,
+    }
+
+    check_serialization(
+        S {
+            password: "hunter2".into(),
+            pin: 1234,
+            token: Some("abc".into()),
+            missing: None,
+        },
+        expect![[r#"
+            {
+              "password": "[REDACTED]",
+              "pin": "[REDACTED]",
+              "token": "[REDACTED]",
+              "missing": null
+            }"#]],
+    );
+    check_deserialization(
+        S {
+            password: "hunter2".into(),
+            pin: 1234,
+            token: Some("abc".into()),
+            missing: None,
+        },
+        r#"{"password": "hunter2", "pin": 1234, "token": "abc", "missing": null}"#,
+    );
+}
+
+#[test]
+fn test_inner_adapter() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Redacted<DisplayFromStr>")] u32);
+
+    check_serialization(S(1234), expect![[r#""[REDACTED]""#]]);
+    check_deserialization(S(1234), r#""1234""#);
+    check_error_deserialization::<S>(
+        "1234",
+        expect!["invalid type: integer `1234`, expected a string at line 1 column 4"],
+    );
+}
+
+#[test]
+fn test_last_chars() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Redacted<_, LastChars<4>>")] String);
+
+    check_serialization(S("4111111111111111".into()), expect![[r#""****1111""#]]);
+    check_serialization(S("12345678".into()), expect![[r#""****5678""#]]);
+    // Multi-byte characters are kept intact
+    check_serialization(S("grüße aus köln".into()), expect![[r#""****köln""#]]);
+    // Strings shorter than `2 * N` are completely hidden
+    check_serialization(S("1234567".into()), expect![[r#""****""#]]);
+    check_serialization(S("1234".into()), expect![[r#""****""#]]);
+    check_serialization(S("12".into()), expect![[r#""****""#]]);
+    check_serialization(S(String::new()), expect![[r#""****""#]]);
+    check_deserialization(S("4111111111111111".into()), r#""4111111111111111""#);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S0(#[serde_as(as = "Redacted<_, LastChars<0>>")] String);
+    check_serialization(S0("secret".into()), expect![[r#""****""#]]);
+}
+
+#[test]
+fn test_correlation_hash() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Redacted<_, CorrelationHash>")] String);
+
+    check_serialization(
+        S("alice@example.com".into()),
+        expect![[r#""67023fc4a7ff2a46""#]],
+    );
+    check_serialization(S(String::new()), expect![[r#""cbf29ce484222325""#]]);
+
+    // The correlation id is the FNV-1a hash
+    let mut hasher = fnv::FnvHasher::default();
+    hasher.write(b"alice@example.com");
+    assert_eq!(
+        format!("\"{:016x}\"", hasher.finish()),
+        serde_json::to_string(&S("alice@example.com".into())).unwrap(),
+    );
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SBytes(#[serde_as(as = "Redacted<_, CorrelationHash>")] Vec<u8>);
+    check_serialization(
+        SBytes(b"alice@example.com".to_vec()),
+        expect![[r#""67023fc4a7ff2a46""#]],
+    );
+}
+
+#[test]
+fn test_only_human_readable() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "IfIsHumanReadable<Redacted, Same>")] String);
+
+    let ser_json = serde_json::to_string(&S("hunter2".into())).unwrap();
+    assert_eq!(r#""[REDACTED]""#, ser_json);
+
+    // Binary formats keep the real value
+    let ser_rmp = rmp_serde::to_vec(&S("hunter2".into())).unwrap();
+    let de_rmp: S = rmp_serde::from_slice(&ser_rmp).unwrap();
+    assert_eq!(S("hunter2".into()), de_rmp);
+}
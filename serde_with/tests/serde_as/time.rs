@@ -3,9 +3,10 @@ use core::time::Duration;
 use serde_with::{
     DurationMicroSeconds, DurationMicroSecondsWithFrac, DurationMilliSeconds,
     DurationMilliSecondsWithFrac, DurationNanoSeconds, DurationNanoSecondsWithFrac,
-    DurationSeconds, DurationSecondsWithFrac, TimestampMicroSeconds, TimestampMicroSecondsWithFrac,
-    TimestampMilliSeconds, TimestampMilliSecondsWithFrac, TimestampNanoSeconds,
-    TimestampNanoSecondsWithFrac, TimestampSeconds, TimestampSecondsWithFrac,
+    DurationSeconds, DurationSecondsWithFrac, HumanDuration, Iso8601Duration,
+    TimestampMicroSeconds, TimestampMicroSecondsWithFrac, TimestampMilliSeconds,
+    TimestampMilliSecondsWithFrac, TimestampNanoSeconds, TimestampNanoSecondsWithFrac,
+    TimestampSeconds, TimestampSecondsWithFrac,
 };
 use std::time::SystemTime;
 
@@ -210,6 +211,144 @@ fn test_duration_seconds_with_frac() {
     );
 }
 
+#[test]
+fn test_iso8601_duration() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Strict(#[serde_as(as = "Iso8601Duration")] Duration);
+
+    is_equal(Strict(Duration::ZERO), expect![[r#""PT0S""#]]);
+    is_equal(
+        Strict(Duration::new(5412, 500_000_000)),
+        expect![[r#""PT1H30M12.5S""#]],
+    );
+    is_equal(Strict(Duration::from_secs(3600)), expect![[r#""PT1H""#]]);
+    is_equal(Strict(Duration::from_secs(90_000)), expect![[r#""PT25H""#]]);
+    is_equal(Strict(Duration::from_secs(3601)), expect![[r#""PT1H1S""#]]);
+    is_equal(
+        Strict(Duration::new(0, 1)),
+        expect![[r#""PT0.000000001S""#]],
+    );
+    is_equal(
+        Strict(Duration::new(1, 20_000_000)),
+        expect![[r#""PT1.02S""#]],
+    );
+    check_deserialization(Strict(Duration::from_secs(93_600)), r#""P1DT2H""#);
+    check_deserialization(Strict(Duration::from_secs(1_209_600)), r#""P2W""#);
+    check_deserialization(Strict(Duration::from_millis(500)), r#""PT0,5S""#);
+    check_deserialization(Strict(Duration::from_secs(1800)), r#""PT0.5H""#);
+    check_deserialization(Strict(Duration::ZERO), r#""-PT0S""#);
+    check_error_deserialization::<Strict>(
+        r#""-PT1S""#,
+        expect![[r#"std::time::Duration cannot be negative"#]],
+    );
+    check_error_deserialization::<Strict>(
+        r#""pt1s""#,
+        expect![[
+            r#"invalid value: string "pt1s", expected an ISO 8601 duration at line 1 column 6"#
+        ]],
+    );
+    check_error_deserialization::<Strict>(
+        r#""P1Y""#,
+        expect![[
+            r#"ISO 8601 durations with years are not supported, since years have no fixed length at line 1 column 5"#
+        ]],
+    );
+    check_error_deserialization::<Strict>(
+        r#"1"#,
+        expect![[r#"invalid type: integer `1`, expected an ISO 8601 duration at line 1 column 1"#]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Flexible(#[serde_as(as = "Iso8601Duration<serde_with::formats::Flexible>")] Duration);
+
+    is_equal(Flexible(Duration::ZERO), expect![[r#""PT0S""#]]);
+    is_equal(
+        Flexible(Duration::new(5412, 500_000_000)),
+        expect![[r#""PT1H30M12.5S""#]],
+    );
+    check_deserialization(Flexible(Duration::from_secs(691_200)), r#""p1w1d""#);
+    check_deserialization(Flexible(Duration::from_secs(30)), r#""PT1M-30S""#);
+    check_deserialization(Flexible(Duration::from_secs(90)), r#"90"#);
+    check_deserialization(Flexible(Duration::from_millis(1500)), r#"1.5"#);
+    check_deserialization(Flexible(Duration::from_millis(1500)), r#""1.5""#);
+    check_error_deserialization::<Flexible>(
+        r#""PT-1M-30S""#,
+        expect![[r#"std::time::Duration cannot be negative"#]],
+    );
+    check_error_deserialization::<Flexible>(
+        r#""1H""#,
+        expect![[
+            r#"invalid value: string "1H", expected an ISO 8601 duration or a number of seconds at line 1 column 4"#
+        ]],
+    );
+}
+
+#[test]
+fn test_human_duration() {
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Strict(#[serde_as(as = "HumanDuration")] Duration);
+
+    is_equal(Strict(Duration::ZERO), expect![[r#""0s""#]]);
+    is_equal(Strict(Duration::from_secs(5405)), expect![[r#""1h30m5s""#]]);
+    is_equal(Strict(Duration::from_millis(250)), expect![[r#""250ms""#]]);
+    is_equal(Strict(Duration::from_nanos(1500)), expect![[r#""1.5us""#]]);
+    is_equal(
+        Strict(Duration::new(86_401, 500_000_000)),
+        expect![[r#""1d1.5s""#]],
+    );
+    is_equal(Strict(Duration::from_secs(1_209_600)), expect![[r#""2w""#]]);
+    check_deserialization(Strict(Duration::from_secs(5405)), r#""1h 30m 5s""#);
+    check_deserialization(Strict(Duration::from_secs(5400)), r#""1.5h""#);
+    check_deserialization(Strict(Duration::from_millis(1250)), r#""1s250ms""#);
+    check_deserialization(Strict(Duration::ZERO), r#""-0s""#);
+    check_error_deserialization::<Strict>(
+        r#""-1s""#,
+        expect![[r#"std::time::Duration cannot be negative"#]],
+    );
+    check_error_deserialization::<Strict>(
+        r#""5s1m""#,
+        expect![[
+            r#"invalid value: string "5s1m", expected a duration like `1h30m5s` or `250ms` at line 1 column 6"#
+        ]],
+    );
+    check_error_deserialization::<Strict>(
+        r#""1H""#,
+        expect![[
+            r#"invalid value: string "1H", expected a duration like `1h30m5s` or `250ms` at line 1 column 4"#
+        ]],
+    );
+    check_error_deserialization::<Strict>(
+        r#"1"#,
+        expect![[
+            r#"invalid type: integer `1`, expected a duration like `1h30m5s` or `250ms` at line 1 column 1"#
+        ]],
+    );
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Flexible(#[serde_as(as = "HumanDuration<serde_with::formats::Flexible>")] Duration);
+
+    is_equal(
+        Flexible(Duration::from_secs(5405)),
+        expect![[r#""1h30m5s""#]],
+    );
+    check_deserialization(Flexible(Duration::from_secs(5405)), r#"" 1H 30M 5S ""#);
+    check_deserialization(Flexible(Duration::from_micros(3)), r#""3µs""#);
+    check_deserialization(Flexible(Duration::from_secs(90)), r#""30s 1m""#);
+    check_deserialization(Flexible(Duration::from_secs(90)), r#"90"#);
+    check_deserialization(Flexible(Duration::from_millis(1500)), r#"1.5"#);
+    check_deserialization(Flexible(Duration::from_millis(1500)), r#""1.5""#);
+    check_error_deserialization::<Flexible>(
+        r#""1 fortnight""#,
+        expect![[
+            r#"invalid value: string "1 fortnight", expected a duration like `1h30m5s` or `250ms` or a number of seconds at line 1 column 13"#
+        ]],
+    );
+}
+
 #[test]
 fn test_timestamp_seconds_systemtime() {
     let zero = SystemTime::UNIX_EPOCH;
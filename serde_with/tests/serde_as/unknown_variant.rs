@@ -0,0 +1,120 @@
+use super::*;
+use serde_with::{value::Value, CatchUnknownVariant, UnknownVariant};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Circle {
+        radius: u32,
+    },
+    Square(u32),
+    Rect(u32, u32),
+    Empty,
+    #[serde(skip)]
+    Unknown(Value<'static>),
+}
+
+impl UnknownVariant for Shape {
+    fn from_unknown(value: Value<'static>) -> Self {
+        Shape::Unknown(value)
+    }
+
+    fn as_unknown(&self) -> Option<&Value<'static>> {
+        match self {
+            Shape::Unknown(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct S(#[serde_as(as = "CatchUnknownVariant")] Shape);
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SVec(#[serde_as(as = "Vec<CatchUnknownVariant>")] Vec<Shape>);
+
+#[test]
+fn test_known_variants() {
+    is_equal(S(Shape::Empty), expect![[r#""Empty""#]]);
+    is_equal(
+        S(Shape::Square(2)),
+        expect![[r#"
+            {
+              "Square": 2
+            }"#]],
+    );
+    is_equal(
+        S(Shape::Circle { radius: 1 }),
+        expect![[r#"
+            {
+              "Circle": {
+                "radius": 1
+              }
+            }"#]],
+    );
+    is_equal(
+        S(Shape::Rect(1, 2)),
+        expect![[r#"
+            {
+              "Rect": [
+                1,
+                2
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn test_unknown_variants() {
+    // Unit variant
+    is_equal(
+        S(Shape::Unknown(Value::String("Point".into()))),
+        expect![[r#""Point""#]],
+    );
+    // Variants with data
+    is_equal(
+        SVec(vec![
+            Shape::Unknown(Value::Map(vec![(
+                Value::String("Triangle".into()),
+                Value::Seq(vec![Value::U64(1), Value::U64(2), Value::U64(3)]),
+            )])),
+            Shape::Empty,
+            Shape::Unknown(Value::Map(vec![(
+                Value::String("Polygon".into()),
+                Value::Map(vec![(Value::String("corners".into()), Value::U64(5))]),
+            )])),
+        ]),
+        expect![[r#"
+            [
+              {
+                "Triangle": [
+                  1,
+                  2,
+                  3
+                ]
+              },
+              "Empty",
+              {
+                "Polygon": {
+                  "corners": 5
+                }
+              }
+            ]"#]],
+    );
+}
+
+#[test]
+fn test_known_variant_errors() {
+    // Invalid data of known variants is not treated as unknown
+    check_error_deserialization::<S>(
+        r#"{"Square": "big"}"#,
+        expect![[r#"invalid type: string "big", expected u32"#]],
+    );
+    check_error_deserialization::<S>(r#"{"Circle": {}}"#, expect!["missing field `radius`"]);
+    // Values which are not an enum at all
+    check_error_deserialization::<S>(
+        "123",
+        expect!["invalid type: integer `123`, expected string or map"],
+    );
+}
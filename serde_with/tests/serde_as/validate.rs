@@ -0,0 +1,250 @@
+use super::*;
+use core::num::NonZeroU16;
+use serde_with::{Bounded, Length, NonEmpty};
+
+#[test]
+fn test_bounded_integer() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Bounded<-10, 10>")] i32);
+
+    is_equal(S(-10), expect!["-10"]);
+    is_equal(S(0), expect!["0"]);
+    is_equal(S(10), expect!["10"]);
+    check_error_deserialization::<S>(
+        "11",
+        expect!["invalid value: `11`, expected a number between -10 and 10"],
+    );
+    check_error_deserialization::<S>(
+        "-11",
+        expect!["invalid value: `-11`, expected a number between -10 and 10"],
+    );
+    // Errors of the inner type are not changed
+    check_error_deserialization::<S>(
+        "\"1\"",
+        expect![[r#"invalid type: string "1", expected i32 at line 1 column 3"#]],
+    );
+
+    // Out of range values are serialized unchanged
+    check_serialization(S(100), expect!["100"]);
+}
+
+#[test]
+fn test_bounded_unbounded_sides() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "Bounded<0, { i128::MAX }>")] i64,
+        #[serde_as(as = "Bounded<{ i128::MIN }, 0>")] i64,
+    );
+
+    is_equal(
+        S(i64::MAX, i64::MIN),
+        expect![[r#"
+        [
+          9223372036854775807,
+          -9223372036854775808
+        ]"#]],
+    );
+    check_error_deserialization::<S>(
+        "[-1, 0]",
+        expect!["invalid value: `-1`, expected a number of at least 0 at line 1 column 5"],
+    );
+    check_error_deserialization::<S>(
+        "[0, 1]",
+        expect!["invalid value: `1`, expected a number of at most 0 at line 1 column 6"],
+    );
+}
+
+#[test]
+fn test_bounded_large_integers() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Bounded<0, { u64::MAX as i128 }>")] u128);
+
+    let value = u128::from(u64::MAX);
+    let bytes = rmp_serde::to_vec(&S(value)).unwrap();
+    assert_eq!(S(value), rmp_serde::from_slice(&bytes).unwrap());
+    let bytes = rmp_serde::to_vec(&(value + 1)).unwrap();
+    let error = rmp_serde::from_slice::<S>(&bytes).unwrap_err().to_string();
+    assert!(error.contains("expected a number between"), "{error}");
+}
+
+#[test]
+fn test_bounded_float() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Bounded<0, 1>")] f64);
+
+    is_equal(S(0.0), expect!["0.0"]);
+    is_equal(S(0.5), expect!["0.5"]);
+    is_equal(S(1.0), expect!["1.0"]);
+    check_error_deserialization::<S>(
+        "1.5",
+        expect!["invalid value: `1.5`, expected a number between 0 and 1"],
+    );
+    check_error_deserialization::<S>(
+        "-0.1",
+        expect!["invalid value: `-0.1`, expected a number between 0 and 1"],
+    );
+
+    // NaN is never within the bounds
+    let bytes = rmp_serde::to_vec(&f64::NAN).unwrap();
+    let error = rmp_serde::from_slice::<S>(&bytes).unwrap_err().to_string();
+    assert!(error.contains("expected a number between"), "{error}");
+}
+
+#[test]
+fn test_bounded_float_unbounded() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "Bounded<0, { i128::MAX }>")] f64,
+        #[serde_as(as = "Bounded<0, { i128::MAX }>")] f32,
+    );
+
+    // Values beyond `i128::MAX` are within a range without upper bound
+    is_equal(
+        S(1e300, f32::MAX),
+        expect![[r#"
+        [
+          1e300,
+          3.4028235e38
+        ]"#]],
+    );
+    check_error_deserialization::<S>(
+        "[-1.5, 0.0]",
+        expect!["invalid value: `-1.5`, expected a number of at least 0 at line 1 column 7"],
+    );
+}
+
+#[test]
+fn test_bounded_inner_adapter() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "Bounded<1, 65535, DisplayFromStr>")] u32,
+        #[serde_as(as = "Option<Bounded<1, 100>>")] Option<NonZeroU16>,
+    );
+
+    is_equal(
+        S(8080, Some(NonZeroU16::new(5).unwrap())),
+        expect![[r#"
+        [
+          "8080",
+          5
+        ]"#]],
+    );
+    is_equal(
+        S(1, None),
+        expect![[r#"
+        [
+          "1",
+          null
+        ]"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"["70000", null]"#,
+        expect![
+            "invalid value: `70000`, expected a number between 1 and 65535 at line 1 column 10"
+        ],
+    );
+    check_error_deserialization::<S>(
+        r#"["1", 101]"#,
+        expect!["invalid value: `101`, expected a number between 1 and 100 at line 1 column 10"],
+    );
+}
+
+#[test]
+fn test_length_string() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(#[serde_as(as = "Length<2, 4>")] String);
+
+    is_equal(S("ab".into()), expect![[r#""ab""#]]);
+    is_equal(S("abcd".into()), expect![[r#""abcd""#]]);
+    // The length counts characters and not bytes
+    is_equal(S("ääää".into()), expect![[r#""ääää""#]]);
+    check_error_deserialization::<S>(
+        r#""a""#,
+        expect!["invalid length 1, expected a length between 2 and 4"],
+    );
+    check_error_deserialization::<S>(
+        r#""abcde""#,
+        expect!["invalid length 5, expected a length between 2 and 4"],
+    );
+}
+
+#[test]
+fn test_length_collections() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S {
+        #[serde_as(as = "Length<0, 2>")]
+        vec: Vec<u8>,
+        #[serde_as(as = "Length<1, { usize::MAX }>")]
+        map: BTreeMap<String, u8>,
+        #[serde_as(as = "Length<2, 2, Vec<DisplayFromStr>>")]
+        pair: Vec<u8>,
+    }
+
+    is_equal(
+        S {
+            vec: vec![],
+            map: BTreeMap::from([("a".into(), 1)]),
+            pair: vec![1, 2],
+        },
+        expect![[r#"
+            {
+              "vec": [],
+              "map": {
+                "a": 1
+              },
+              "pair": [
+                "1",
+                "2"
+              ]
+            }"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"{"vec": [1, 2, 3], "map": {"a": 1}, "pair": ["1", "2"]}"#,
+        expect!["invalid length 3, expected a length of at most 2 at line 1 column 17"],
+    );
+    check_error_deserialization::<S>(
+        r#"{"vec": [], "map": {}, "pair": ["1", "2"]}"#,
+        expect!["invalid length 0, expected a length of at least 1 at line 1 column 21"],
+    );
+    check_error_deserialization::<S>(
+        r#"{"vec": [], "map": {"a": 1}, "pair": ["1"]}"#,
+        expect!["invalid length 1, expected a length of exactly 2 at line 1 column 43"],
+    );
+}
+
+#[test]
+fn test_non_empty() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct S(
+        #[serde_as(as = "NonEmpty")] String,
+        #[serde_as(as = "NonEmpty<BTreeSet<DisplayFromStr>>")] BTreeSet<u8>,
+    );
+
+    is_equal(
+        S("a".into(), BTreeSet::from([1])),
+        expect![[r#"
+        [
+          "a",
+          [
+            "1"
+          ]
+        ]"#]],
+    );
+    check_error_deserialization::<S>(
+        r#"["", ["1"]]"#,
+        expect!["invalid length 0, expected a length of at least 1 at line 1 column 5"],
+    );
+    check_error_deserialization::<S>(
+        r#"["a", []]"#,
+        expect!["invalid length 0, expected a length of at least 1 at line 1 column 9"],
+    );
+}
@@ -0,0 +1,325 @@
+//! Test Cases
+
+mod utils;
+
+use crate::utils::check_serialization;
+use expect_test::expect;
+use serde::{de::value::Error, Deserialize, Serialize};
+use serde_with::value::{Value, ValueSerializer};
+use std::{collections::BTreeMap, net::Ipv4Addr};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Point,
+    Circle(u32),
+    Line(i8, i8),
+    Rect { width: u32, height: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Data {
+    name: String,
+    bytes: Vec<u8>,
+    maybe: Option<char>,
+    length: Meters,
+    shapes: Vec<Shape>,
+    map: BTreeMap<String, bool>,
+    unit: (),
+}
+
+fn data() -> Data {
+    Data {
+        name: "Hello".into(),
+        bytes: vec![1, 2, 3],
+        maybe: Some('x'),
+        length: Meters(1.5),
+        shapes: vec![
+            Shape::Point,
+            Shape::Circle(1),
+            Shape::Line(-1, 1),
+            Shape::Rect {
+                width: 2,
+                height: 3,
+            },
+        ],
+        map: BTreeMap::from([("a".into(), true), ("b".into(), false)]),
+        unit: (),
+    }
+}
+
+#[test]
+fn test_value_serializer() {
+    let value = data()
+        .serialize(ValueSerializer::<Error>::new(true))
+        .unwrap();
+    expect![[r#"
+        Map(
+            [
+                (
+                    Str(
+                        "name",
+                    ),
+                    String(
+                        "Hello",
+                    ),
+                ),
+                (
+                    Str(
+                        "bytes",
+                    ),
+                    Seq(
+                        [
+                            U8(
+                                1,
+                            ),
+                            U8(
+                                2,
+                            ),
+                            U8(
+                                3,
+                            ),
+                        ],
+                    ),
+                ),
+                (
+                    Str(
+                        "maybe",
+                    ),
+                    Some(
+                        Char(
+                            'x',
+                        ),
+                    ),
+                ),
+                (
+                    Str(
+                        "length",
+                    ),
+                    F64(
+                        1.5,
+                    ),
+                ),
+                (
+                    Str(
+                        "shapes",
+                    ),
+                    Seq(
+                        [
+                            Str(
+                                "Point",
+                            ),
+                            Map(
+                                [
+                                    (
+                                        Str(
+                                            "Circle",
+                                        ),
+                                        U32(
+                                            1,
+                                        ),
+                                    ),
+                                ],
+                            ),
+                            Map(
+                                [
+                                    (
+                                        Str(
+                                            "Line",
+                                        ),
+                                        Seq(
+                                            [
+                                                I8(
+                                                    -1,
+                                                ),
+                                                I8(
+                                                    1,
+                                                ),
+                                            ],
+                                        ),
+                                    ),
+                                ],
+                            ),
+                            Map(
+                                [
+                                    (
+                                        Str(
+                                            "Rect",
+                                        ),
+                                        Map(
+                                            [
+                                                (
+                                                    Str(
+                                                        "width",
+                                                    ),
+                                                    U32(
+                                                        2,
+                                                    ),
+                                                ),
+                                                (
+                                                    Str(
+                                                        "height",
+                                                    ),
+                                                    U32(
+                                                        3,
+                                                    ),
+                                                ),
+                                            ],
+                                        ),
+                                    ),
+                                ],
+                            ),
+                        ],
+                    ),
+                ),
+                (
+                    Str(
+                        "map",
+                    ),
+                    Map(
+                        [
+                            (
+                                String(
+                                    "a",
+                                ),
+                                Bool(
+                                    true,
+                                ),
+                            ),
+                            (
+                                String(
+                                    "b",
+                                ),
+                                Bool(
+                                    false,
+                                ),
+                            ),
+                        ],
+                    ),
+                ),
+                (
+                    Str(
+                        "unit",
+                    ),
+                    Unit,
+                ),
+            ],
+        )"#]]
+    .assert_eq(&format!("{value:#?}"));
+
+    // The value can be deserialized again, both owned and borrowed
+    assert_eq!(
+        data(),
+        Data::deserialize(value.as_deserializer::<Error>(true)).unwrap()
+    );
+    assert_eq!(
+        data(),
+        Data::deserialize(value.into_deserializer::<Error>(true)).unwrap()
+    );
+}
+
+#[test]
+fn test_value_json_roundtrip() {
+    let json = serde_json::to_string(&data()).unwrap();
+    let value: Value<'_> = serde_json::from_str(&json).unwrap();
+
+    // Strings without escapes are borrowed from the input
+    let Value::Map(entries) = &value else {
+        panic!("expected a map, got {value:?}")
+    };
+    assert_eq!(Value::Str("name"), entries[0].0);
+    assert_eq!(Value::Str("Hello"), entries[0].1);
+
+    assert_eq!(
+        data(),
+        Data::deserialize(value.as_deserializer::<serde_json::Error>(true)).unwrap()
+    );
+
+    // Serializing the value produces the same JSON again
+    assert_eq!(json, serde_json::to_string(&value).unwrap());
+
+    let owned: Value<'static> = value.clone().into_owned();
+    let Value::Map(entries) = &owned else {
+        panic!("expected a map, got {owned:?}")
+    };
+    assert_eq!(Value::String("name".into()), entries[0].0);
+    assert_eq!(
+        data(),
+        Data::deserialize(owned.into_deserializer::<serde_json::Error>(true)).unwrap()
+    );
+}
+
+#[test]
+fn test_value_serialize() {
+    check_serialization(
+        Value::Seq(vec![
+            Value::Bool(true),
+            Value::I128(-1),
+            Value::Str("borrowed"),
+            Value::String("owned".into()),
+            Value::None,
+            Value::Some(Box::new(Value::U8(1))),
+            Value::Unit,
+            Value::Map(vec![(Value::Str("key"), Value::F64(1.5))]),
+        ]),
+        expect![[r#"
+            [
+              true,
+              -1,
+              "borrowed",
+              "owned",
+              null,
+              1,
+              null,
+              {
+                "key": 1.5
+              }
+            ]"#]],
+    );
+    check_serialization(
+        Value::Newtype(Box::new(Value::ByteBuf(vec![1, 2]))),
+        expect![[r#"
+            [
+              1,
+              2
+            ]"#]],
+    );
+}
+
+#[test]
+fn test_value_is_human_readable() {
+    let addr = Ipv4Addr::new(127, 0, 0, 1);
+
+    let readable = addr.serialize(ValueSerializer::<Error>::new(true)).unwrap();
+    assert_eq!(Value::String("127.0.0.1".into()), readable);
+    assert_eq!(
+        addr,
+        Ipv4Addr::deserialize(readable.as_deserializer::<Error>(true)).unwrap()
+    );
+
+    let compact = addr
+        .serialize(ValueSerializer::<Error>::new(false))
+        .unwrap();
+    assert_eq!(
+        Value::Seq(vec![
+            Value::U8(127),
+            Value::U8(0),
+            Value::U8(0),
+            Value::U8(1)
+        ]),
+        compact
+    );
+    assert_eq!(
+        addr,
+        Ipv4Addr::deserialize(compact.as_deserializer::<Error>(false)).unwrap()
+    );
+
+    // The readable form cannot be deserialized as the compact form
+    expect![[r#"invalid type: string "127.0.0.1", expected an array of length 4"#]].assert_eq(
+        &Ipv4Addr::deserialize(readable.as_deserializer::<Error>(false))
+            .unwrap_err()
+            .to_string(),
+    );
+}
@@ -0,0 +1,126 @@
+use darling::{ast::Data, util::Flag, FromDeriveInput, FromVariant};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, DeriveInput, Error, Generics, Ident, Path};
+
+/// Integer types allowed in the `#[repr(...)]` attribute
+const REPR_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Parsed form of the enum with the `IntEnum` derive.
+#[derive(FromDeriveInput)]
+#[darling(attributes(serde_with), supports(enum_unit), forward_attrs(repr))]
+struct IntEnumInput {
+    ident: Ident,
+    generics: Generics,
+    attrs: Vec<Attribute>,
+    data: Data<IntEnumVariant, ()>,
+    /// Path to the crate
+    #[darling(rename = "crate", default)]
+    alt_crate_path: Option<Path>,
+}
+
+/// Parsed form of a single variant with the `#[serde_with(other)]` attribute.
+#[derive(FromVariant)]
+#[darling(attributes(serde_with))]
+struct IntEnumVariant {
+    ident: Ident,
+    /// The fallback variant for unknown values
+    other: Flag,
+}
+
+pub(crate) fn derive_int_enum(input: DeriveInput) -> TokenStream {
+    match int_enum(&input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.write_errors()),
+    }
+}
+
+fn int_enum(input: &DeriveInput) -> Result<TokenStream2, darling::Error> {
+    let IntEnumInput {
+        ident,
+        generics,
+        attrs,
+        data,
+        alt_crate_path,
+    } = IntEnumInput::from_derive_input(input)?;
+    let serde_with_crate_path =
+        alt_crate_path.unwrap_or_else(|| syn::parse_str("::serde_with").unwrap());
+    let repr = repr_type(&attrs)?;
+    let variants = data
+        .take_enum()
+        .expect("supports(enum_unit) only allows enums");
+
+    let mut fallbacks = variants.iter().filter(|variant| variant.other.is_present());
+    let fallback = fallbacks.next();
+    if let Some(second) = fallbacks.next() {
+        return Err(darling::Error::custom(
+            "Only one variant can be marked with `#[serde_with(other)]`",
+        )
+        .with_span(&second.other.span()));
+    }
+    let has_fallback = fallback.is_some();
+    let fallback = match fallback {
+        Some(IntEnumVariant { ident, .. }) => {
+            quote!(#serde_with_crate_path::__private__::Option::Some(Self::#ident))
+        }
+        None => quote!(#serde_with_crate_path::__private__::Option::None),
+    };
+
+    let idents: Vec<_> = variants.iter().map(|variant| &variant.ident).collect();
+    let names: Vec<_> = idents.iter().map(ToString::to_string).collect();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #serde_with_crate_path::IntEnum for #ident #ty_generics #where_clause {
+            type Repr = #repr;
+
+            const VARIANTS: &'static [(&'static str, Self::Repr)] = &[
+                #( (#names, Self::#idents as #repr), )*
+            ];
+
+            const HAS_FALLBACK: bool = #has_fallback;
+
+            fn to_repr(&self) -> Self::Repr {
+                match self {
+                    #( Self::#idents => Self::#idents as #repr, )*
+                }
+            }
+
+            fn from_repr(value: Self::Repr) -> #serde_with_crate_path::__private__::Option<Self> {
+                #(
+                    if value == Self::#idents as #repr {
+                        return #serde_with_crate_path::__private__::Option::Some(Self::#idents);
+                    }
+                )*
+                #fallback
+            }
+
+            fn from_name(name: &str) -> #serde_with_crate_path::__private__::Option<Self> {
+                match name {
+                    #( #names => #serde_with_crate_path::__private__::Option::Some(Self::#idents), )*
+                    _ => #fallback,
+                }
+            }
+        }
+    })
+}
+
+/// Find the integer type of the `#[repr(...)]` attribute, defaulting to `isize`.
+fn repr_type(attrs: &[Attribute]) -> Result<Ident, Error> {
+    let mut repr = None;
+    for attr in attrs {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if REPR_TYPES.iter().any(|ty| ident == ty) {
+                    repr = Some(ident.clone());
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr.unwrap_or_else(|| Ident::new("isize", proc_macro2::Span::call_site())))
+}
@@ -32,6 +32,7 @@
 //! [`serde_with`]: https://crates.io/crates/serde_with/
 
 mod apply;
+mod int_enum;
 mod lazy_bool;
 mod utils;
 
@@ -1310,6 +1311,60 @@ fn serialize_display(
     }
 }
 
+/// Implement `IntEnum` for enums without fields
+///
+/// The `IntEnum` trait connects each variant with its name and its discriminant.
+/// It is required by the `EnumAsInt` adapter, which de/serializes the enum as an integer.
+/// The discriminant has the type of the `#[repr(...)]` attribute, or `isize` if the attribute is
+/// missing.
+///
+/// # Attributes
+///
+/// Attributes for the derive can be specified via the `#[serde_with(...)]` attribute on the enum
+/// or a variant. Currently, these arguments to the attribute are possible:
+///
+/// * **`#[serde_with(crate = "...")]`**: This allows using `IntEnum` when `serde_with` is not
+///   available from the crate root. This happens while [renaming dependencies in
+///   Cargo.toml][cargo-toml-rename] or when re-exporting the macro from a different crate.
+///
+///   This argument is analogue to [serde's crate argument][serde-crate] and the [crate argument
+///   to `serde_as`][serde-as-crate].
+///
+/// * **`#[serde_with(other)]`**: Placed on a variant, this marks the fallback variant, which is
+///   used for unknown discriminants and names. Only a single variant can be the fallback variant.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(IntEnum)]
+/// #[repr(u8)]
+/// enum Status {
+///     Active = 1,
+///     Suspended = 2,
+///     #[serde_with(other)]
+///     Unknown = 255,
+/// }
+///
+/// #[serde_as]
+/// #[derive(Serialize)]
+/// struct Data {
+///     #[serde_as(as = "EnumAsInt")]
+///     status: Status,
+/// }
+///
+/// let data = Data { status: Status::Suspended };
+/// assert_eq!(r#"{"status":2}"#, serde_json::to_string(&data).unwrap());
+/// ```
+///
+/// [cargo-toml-rename]: https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html#renaming-dependencies-in-cargotoml
+/// [serde-as-crate]: https://docs.rs/serde_with/3.21.0/serde_with/guide/serde_as/index.html#re-exporting-serde_as
+/// [serde-crate]: https://serde.rs/container-attrs.html#crate
+#[proc_macro_derive(IntEnum, attributes(serde_with))]
+pub fn derive_int_enum(item: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(item);
+    int_enum::derive_int_enum(input)
+}
+
 #[doc(hidden)]
 /// Private function. Not part of the public API
 ///